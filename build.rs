@@ -0,0 +1,18 @@
+//! Expose the locked gix version to `git-issue version --verbose`
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+
+    let lock = std::fs::read_to_string("Cargo.lock").unwrap_or_default();
+    let version = lock
+        .split("[[package]]")
+        .find(|package| package.contains("\nname = \"gix\"\n"))
+        .and_then(|package| {
+            package
+                .lines()
+                .find_map(|line| line.strip_prefix("version = "))
+        })
+        .map(|version| version.trim_matches('"'))
+        .unwrap_or("unknown");
+    println!("cargo:rustc-env=GIT_ISSUE_GIX_VERSION={}", version);
+}
@@ -3,41 +3,50 @@ use clap::Args;
 use std::collections::HashSet;
 
 use crate::cli::output::{success_message, warning_message};
-use crate::common::{Identity, IssueId, SystemEnvProvider};
+use crate::common::{Identity, SystemEnvProvider};
 use crate::storage::IssueStore;
 
-use super::get_author_identity;
+use super::{
+    check_contributor, get_author_identity, resolve_assignee, strict_email_enabled, validate_email,
+};
 
 #[derive(Args)]
 pub struct AssignArgs {
-    /// Issue ID to assign
-    pub id: IssueId,
+    /// Issue ID or slug to assign
+    pub id: String,
 
-    /// Assignee emails to add
+    /// Assignee emails to add (`@me` for yourself)
     pub assignees: Vec<String>,
+
+    /// Require a full `local@domain.tld` address instead of anything with an `@`
+    #[arg(long)]
+    pub strict_email: bool,
+
+    /// Assign even if an email is missing from `.git-issue/contributors.toml`
+    #[arg(long)]
+    pub force: bool,
 }
 
 #[derive(Args)]
 pub struct UnassignArgs {
-    /// Issue ID to unassign
-    pub id: IssueId,
+    /// Issue ID or slug to unassign
+    pub id: String,
 
-    /// Assignee emails to remove (if none provided, removes all assignees)
+    /// Assignee emails to remove (`@me` for yourself; if none provided, removes all assignees)
     pub assignees: Vec<String>,
 }
 
 /// Handle assigning users to an issue
 pub fn handle_assign(repo_path: std::path::PathBuf, args: AssignArgs) -> Result<()> {
     let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
+    let issue_id = store.resolve_ref(&args.id)?;
     let author = get_author_identity(None, None, &store, SystemEnvProvider)?;
+    let strict_email = strict_email_enabled(args.strict_email, &store);
+    let contributors = store.contributors()?;
 
     // Get the current issue to check existing assignees
-    let current_issue = store.get_issue(args.id)?;
-    let current_assignees: HashSet<String> = current_issue
-        .assignees
-        .iter()
-        .map(|a| a.email.clone())
-        .collect();
+    let current_issue = store.get_issue(issue_id)?;
 
     let mut warnings = Vec::new();
     let mut new_assignees = current_issue.assignees.clone();
@@ -47,20 +56,21 @@ pub fn handle_assign(repo_path: std::path::PathBuf, args: AssignArgs) -> Result<
     let assignees_to_process = if args.assignees.is_empty() {
         vec![author.email.clone()]
     } else {
-        args.assignees.clone()
+        args.assignees
+            .iter()
+            .map(|email| resolve_assignee(email, &author.email))
+            .collect()
     };
 
     // Process each assignee
     for email in &assignees_to_process {
-        // Basic email validation
-        if !email.contains('@') {
-            return Err(anyhow::anyhow!("Invalid email format: {}", email));
-        }
+        validate_email(email, strict_email)?;
+        check_contributor(contributors.as_ref(), email, args.force)?;
 
-        if current_assignees.contains(email) {
+        if new_assignees.iter().any(|a| a.has_email(email)) {
             warnings.push(format!(
                 "User '{}' is already assigned to issue #{}",
-                email, args.id
+                email, issue_id
             ));
         } else {
             let identity = Identity::new("", email);
@@ -71,19 +81,19 @@ pub fn handle_assign(repo_path: std::path::PathBuf, args: AssignArgs) -> Result<
 
     // Update assignees if there are changes
     if !successfully_added.is_empty() {
-        store.update_assignees(args.id, new_assignees, author.clone())?;
+        store.update_assignees(issue_id, new_assignees, author.clone())?;
 
         let message = if successfully_added.len() == 1 {
-            if args.assignees.is_empty() && successfully_added[0] == author.email {
-                format!("Assigned yourself to issue #{}", args.id)
+            if author.has_email(&successfully_added[0]) {
+                format!("Assigned yourself to issue #{}", issue_id)
             } else {
-                format!("Assigned {} to issue #{}", successfully_added[0], args.id)
+                format!("Assigned {} to issue #{}", successfully_added[0], issue_id)
             }
         } else {
             format!(
                 "Assigned {} users to issue #{}: {}",
                 successfully_added.len(),
-                args.id,
+                issue_id,
                 successfully_added.join(", ")
             )
         };
@@ -101,10 +111,12 @@ pub fn handle_assign(repo_path: std::path::PathBuf, args: AssignArgs) -> Result<
 /// Handle unassigning users from an issue
 pub fn handle_unassign(repo_path: std::path::PathBuf, args: UnassignArgs) -> Result<()> {
     let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
+    let issue_id = store.resolve_ref(&args.id)?;
     let author = get_author_identity(None, None, &store, SystemEnvProvider)?;
 
     // Get the current issue to check existing assignees
-    let current_issue = store.get_issue(args.id)?;
+    let current_issue = store.get_issue(issue_id)?;
     let current_assignees: HashSet<String> = current_issue
         .assignees
         .iter()
@@ -112,7 +124,7 @@ pub fn handle_unassign(repo_path: std::path::PathBuf, args: UnassignArgs) -> Res
         .collect();
 
     if current_assignees.is_empty() {
-        println!("Issue #{} has no assignees to remove", args.id);
+        println!("Issue #{} has no assignees to remove", issue_id);
         return Ok(());
     }
 
@@ -128,13 +140,14 @@ pub fn handle_unassign(repo_path: std::path::PathBuf, args: UnassignArgs) -> Res
         let mut remaining_assignees = current_issue.assignees.clone();
 
         for email in &args.assignees {
-            if current_assignees.contains(email) {
-                remaining_assignees.retain(|a| a.email != *email);
+            let email = &resolve_assignee(email, &author.email);
+            if remaining_assignees.iter().any(|a| a.has_email(email)) {
+                remaining_assignees.retain(|a| !a.has_email(email));
                 successfully_removed.push(email.clone());
             } else {
                 warnings.push(format!(
                     "User '{}' is not assigned to issue #{}",
-                    email, args.id
+                    email, issue_id
                 ));
             }
         }
@@ -144,20 +157,20 @@ pub fn handle_unassign(repo_path: std::path::PathBuf, args: UnassignArgs) -> Res
 
     // Update assignees if there are changes
     if !successfully_removed.is_empty() {
-        store.update_assignees(args.id, new_assignees, author)?;
+        store.update_assignees(issue_id, new_assignees, author)?;
 
         let message = if args.assignees.is_empty() {
-            format!("Unassigned all users from issue #{}", args.id)
+            format!("Unassigned all users from issue #{}", issue_id)
         } else if successfully_removed.len() == 1 {
             format!(
                 "Unassigned {} from issue #{}",
-                successfully_removed[0], args.id
+                successfully_removed[0], issue_id
             )
         } else {
             format!(
                 "Unassigned {} users from issue #{}: {}",
                 successfully_removed.len(),
-                args.id,
+                issue_id,
                 successfully_removed.join(", ")
             )
         };
@@ -171,7 +184,7 @@ pub fn handle_unassign(repo_path: std::path::PathBuf, args: UnassignArgs) -> Res
 
     // If no successful operations occurred, show a message
     if successfully_removed.is_empty() && !args.assignees.is_empty() {
-        println!("No assignments were removed from issue #{}", args.id);
+        println!("No assignments were removed from issue #{}", issue_id);
     }
 
     Ok(())
@@ -180,6 +193,8 @@ pub fn handle_unassign(repo_path: std::path::PathBuf, args: UnassignArgs) -> Res
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::IssueId;
+    use crate::storage::contributors::CONTRIBUTORS_PATH;
     use crate::storage::test_helpers::*;
     use tempfile::TempDir;
 
@@ -206,8 +221,10 @@ mod tests {
         let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
 
         let args = AssignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: vec!["user1@example.com".to_string()],
+            strict_email: false,
+            force: false,
         };
 
         let result = handle_assign(repo_path.clone(), args);
@@ -225,11 +242,13 @@ mod tests {
         let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
 
         let args = AssignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: vec![
                 "user1@example.com".to_string(),
                 "user2@example.com".to_string(),
             ],
+            strict_email: false,
+            force: false,
         };
 
         let result = handle_assign(repo_path.clone(), args);
@@ -250,15 +269,19 @@ mod tests {
 
         // First assignment
         let args1 = AssignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: vec!["user1@example.com".to_string()],
+            strict_email: false,
+            force: false,
         };
         handle_assign(repo_path.clone(), args1).expect("First assign should succeed");
 
         // Try to assign the same user again
         let args2 = AssignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: vec!["user1@example.com".to_string()],
+            strict_email: false,
+            force: false,
         };
         let result = handle_assign(repo_path.clone(), args2);
         assert!(result.is_ok(), "Duplicate assign should succeed but warn");
@@ -275,17 +298,19 @@ mod tests {
 
         // First assign some users
         let assign_args = AssignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: vec![
                 "user1@example.com".to_string(),
                 "user2@example.com".to_string(),
             ],
+            strict_email: false,
+            force: false,
         };
         handle_assign(repo_path.clone(), assign_args).expect("Assign should succeed");
 
         // Unassign all
         let unassign_args = UnassignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: Vec::new(), // Empty means unassign all
         };
         let result = handle_unassign(repo_path.clone(), unassign_args);
@@ -303,17 +328,19 @@ mod tests {
 
         // First assign some users
         let assign_args = AssignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: vec![
                 "user1@example.com".to_string(),
                 "user2@example.com".to_string(),
             ],
+            strict_email: false,
+            force: false,
         };
         handle_assign(repo_path.clone(), assign_args).expect("Assign should succeed");
 
         // Unassign one specific user
         let unassign_args = UnassignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: vec!["user1@example.com".to_string()],
         };
         let result = handle_unassign(repo_path.clone(), unassign_args);
@@ -331,8 +358,10 @@ mod tests {
         let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
 
         let args = AssignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: vec!["invalid-email".to_string()],
+            strict_email: false,
+            force: false,
         };
 
         let result = handle_assign(repo_path, args);
@@ -345,6 +374,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_assign_strict_email() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
+
+        let args = AssignArgs {
+            id: issue_id.to_string(),
+            assignees: vec!["user@localhost".to_string()],
+            strict_email: true,
+            force: false,
+        };
+        let err = handle_assign(repo_path.clone(), args).expect_err("Strict mode should reject");
+        assert!(err.to_string().contains("Invalid email format"));
+
+        let args = AssignArgs {
+            id: issue_id.to_string(),
+            assignees: vec!["user@localhost".to_string()],
+            strict_email: false,
+            force: false,
+        };
+        handle_assign(repo_path, args).expect("Lenient mode should accept");
+    }
+
     #[test]
     fn test_assign_no_assignees_assigns_self() {
         let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
@@ -360,8 +411,10 @@ mod tests {
         .expect("Should get author");
 
         let args = AssignArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             assignees: Vec::new(),
+            strict_email: false,
+            force: false,
         };
 
         let result = handle_assign(repo_path.clone(), args);
@@ -373,4 +426,129 @@ mod tests {
         assert_eq!(issue.assignees.len(), 1);
         assert_eq!(issue.assignees[0].email, expected_author.email);
     }
+
+    #[test]
+    fn test_assign_self_keyword_with_others() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
+
+        let args = AssignArgs {
+            id: issue_id.to_string(),
+            assignees: vec!["@me".to_string(), "teammate@example.com".to_string()],
+            strict_email: false,
+            force: false,
+        };
+
+        let result = handle_assign(repo_path.clone(), args);
+        assert!(result.is_ok(), "Assign with @me should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let me = get_author_identity(None, None, &store, SystemEnvProvider)
+            .expect("Should resolve identity");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        let emails: Vec<_> = issue.assignees.iter().map(|a| a.email.as_str()).collect();
+        assert_eq!(emails, vec![me.email.as_str(), "teammate@example.com"]);
+    }
+
+    #[test]
+    fn test_unassign_self_keyword() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
+
+        let assign_args = AssignArgs {
+            id: issue_id.to_string(),
+            assignees: vec!["@me".to_string(), "teammate@example.com".to_string()],
+            strict_email: false,
+            force: false,
+        };
+        handle_assign(repo_path.clone(), assign_args).expect("Assign should succeed");
+
+        let unassign_args = UnassignArgs {
+            id: issue_id.to_string(),
+            assignees: vec!["@me".to_string()],
+        };
+        handle_unassign(repo_path.clone(), unassign_args).expect("Unassign should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(issue.assignees.len(), 1);
+        assert_eq!(issue.assignees[0].email, "teammate@example.com");
+    }
+
+    #[test]
+    fn test_assign_duplicate_detection_ignores_case() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
+
+        let args = AssignArgs {
+            id: issue_id.to_string(),
+            assignees: vec!["User@Example.com".to_string()],
+            strict_email: false,
+            force: false,
+        };
+        handle_assign(repo_path.clone(), args).expect("Assign should succeed");
+
+        let args = AssignArgs {
+            id: issue_id.to_string(),
+            assignees: vec![
+                "user@example.com".to_string(),
+                "USER@example.COM".to_string(),
+            ],
+            strict_email: false,
+            force: false,
+        };
+        handle_assign(repo_path.clone(), args).expect("Duplicate assign should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(issue.assignees.len(), 1);
+        assert_eq!(issue.assignees[0].email, "User@Example.com");
+
+        let args = UnassignArgs {
+            id: issue_id.to_string(),
+            assignees: vec!["user@EXAMPLE.com".to_string()],
+        };
+        handle_unassign(repo_path.clone(), args).expect("Unassign should succeed");
+
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert!(issue.assignees.is_empty());
+    }
+
+    #[test]
+    fn test_assign_checks_contributors_allowlist() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_assign_repo();
+        std::fs::create_dir_all(repo_path.join(".git-issue")).unwrap();
+        std::fs::write(
+            repo_path.join(CONTRIBUTORS_PATH),
+            "emails = [\"alice@example.com\", \"bob@example.com\"]\n",
+        )
+        .unwrap();
+        let args = |email: &str, force: bool| AssignArgs {
+            id: issue_id.to_string(),
+            assignees: vec![email.to_string()],
+            strict_email: false,
+            force,
+        };
+
+        handle_assign(repo_path.clone(), args("Alice@Example.com", false))
+            .expect("Known contributor should be assigned");
+
+        let err = handle_assign(repo_path.clone(), args("alcie@example.com", false))
+            .expect_err("Unknown contributor should be rejected");
+        assert!(
+            err.to_string()
+                .contains("Unknown contributor 'alcie@example.com'")
+        );
+        assert!(err.to_string().contains("did you mean 'alice@example.com'"));
+
+        handle_assign(repo_path.clone(), args("carol@example.com", true))
+            .expect("--force should allow an unknown contributor");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let emails: Vec<_> = store
+            .get_issue(issue_id)
+            .expect("Should get issue")
+            .assignees
+            .into_iter()
+            .map(|assignee| assignee.email)
+            .collect();
+        assert_eq!(emails, vec!["Alice@Example.com", "carol@example.com"]);
+    }
 }
@@ -0,0 +1,391 @@
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::{ArgGroup, Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::cli::output::success_message;
+use crate::common::{Issue, IssueId};
+use crate::storage::IssueStore;
+use crate::storage::issue_store::IssueDump;
+
+/// Export format for `export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ExportFormat {
+    /// A git bundle, readable by `import --bundle` and `git fetch`
+    #[default]
+    #[value(alias = "git-bundle")]
+    Bundle,
+    /// A JSON dump of every issue's events, readable by `import --native`
+    Native,
+}
+
+/// Version of the native export layout
+const NATIVE_SCHEMA: u32 = 1;
+
+/// Top-level document of `export --format native`
+#[derive(Serialize, Deserialize)]
+struct NativeExport {
+    schema: u32,
+    issues: Vec<IssueDump>,
+}
+
+/// Starting point of an incremental export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportSince {
+    /// Issues updated after this time
+    Time(DateTime<Utc>),
+    /// Issues updated after the previous full or `last` native export
+    Last,
+}
+
+impl FromStr for ExportSince {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("last") {
+            return Ok(ExportSince::Last);
+        }
+        if let Ok(time) = DateTime::parse_from_rfc3339(s) {
+            return Ok(ExportSince::Time(time.with_timezone(&Utc)));
+        }
+        NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .map(|date| ExportSince::Time(date.and_time(Default::default()).and_utc()))
+            .map_err(|_| {
+                format!(
+                    "Invalid --since '{}'. Use `last`, a date (YYYY-MM-DD) or an RFC 3339 timestamp",
+                    s
+                )
+            })
+    }
+}
+
+#[derive(Args)]
+pub struct ExportArgs {
+    /// Export format
+    #[arg(long, value_enum, default_value_t = ExportFormat::Bundle)]
+    pub format: ExportFormat,
+
+    /// File to write the export to
+    #[arg(short, long)]
+    pub output: PathBuf,
+
+    /// Only export issues updated after this date or timestamp, or after the
+    /// previous native export with `last` (native format only)
+    ///
+    /// Only full exports and `last` move the point `last` continues from; an
+    /// export since a given date leaves it alone.
+    #[arg(long, value_name = "TIMESTAMP|last")]
+    pub since: Option<ExportSince>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("source").required(true)))]
+pub struct ImportArgs {
+    /// Git bundle to import issues from
+    #[arg(long, group = "source")]
+    pub bundle: Option<PathBuf>,
+
+    /// Native JSON export to recreate issues from
+    #[arg(long, group = "source")]
+    pub native: Option<PathBuf>,
+}
+
+pub fn handle_export(repo_path: PathBuf, args: ExportArgs) -> Result<()> {
+    let store = IssueStore::open(&repo_path)?;
+
+    if store.list_issue_ids()?.is_empty() {
+        return Err(anyhow::anyhow!("no issues to export"));
+    }
+    if args.since.is_some() && args.format != ExportFormat::Native {
+        anyhow::bail!("--since is only supported with --format native");
+    }
+
+    let exported = match args.format {
+        ExportFormat::Bundle => format!("{} refs", store.export_bundle(&args.output)?),
+        ExportFormat::Native => {
+            // A delta since an arbitrary date must not make `last` skip anything
+            let advances_marker = !matches!(args.since, Some(ExportSince::Time(_)));
+            let _lock = if advances_marker {
+                Some(store.lock_for_writing()?)
+            } else {
+                None
+            };
+
+            // Taken before reading, so changes made meanwhile are in the next delta
+            let started_at = Utc::now();
+            let since = match args.since {
+                Some(ExportSince::Time(time)) => Some(time),
+                Some(ExportSince::Last) => store.last_export()?,
+                None => None,
+            };
+
+            let export = NativeExport {
+                schema: NATIVE_SCHEMA,
+                issues: updated_since(store.dump_issues()?, since)?,
+            };
+            std::fs::write(&args.output, serde_json::to_string_pretty(&export)?)?;
+            if advances_marker {
+                store.record_export(started_at)?;
+            }
+            format!("{} issues", export.issues.len())
+        }
+    };
+
+    println!(
+        "{}",
+        success_message(&format!(
+            "Exported {} to {}",
+            exported,
+            args.output.display()
+        ))
+    );
+    Ok(())
+}
+
+/// Keep the dumps of issues updated after `since`, or all of them without it
+fn updated_since(dumps: Vec<IssueDump>, since: Option<DateTime<Utc>>) -> Result<Vec<IssueDump>> {
+    let Some(since) = since else {
+        return Ok(dumps);
+    };
+
+    let mut updated = Vec::new();
+    for dump in dumps {
+        if Issue::from_events(dump.id, &dump.events)?.updated_at > since {
+            updated.push(dump);
+        }
+    }
+    Ok(updated)
+}
+
+pub fn handle_import(repo_path: PathBuf, args: ImportArgs) -> Result<()> {
+    let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
+
+    let (changed, source) = match (args.bundle, args.native) {
+        (Some(bundle), _) => (store.import_bundle(&bundle)?, bundle),
+        (None, Some(native)) => (import_native(&mut store, &native)?, native),
+        (None, None) => unreachable!("clap requires an import source"),
+    };
+
+    println!(
+        "{}",
+        success_message(&format!(
+            "Imported {} issues from {}",
+            changed.len(),
+            source.display()
+        ))
+    );
+    Ok(())
+}
+
+/// Recreate the issues of a native export in `store`
+fn import_native(store: &mut IssueStore, path: &std::path::Path) -> Result<Vec<IssueId>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))?;
+    let export: NativeExport = serde_json::from_str(&content)?;
+    if export.schema > NATIVE_SCHEMA {
+        anyhow::bail!(
+            "'{}' uses native export schema {}, but this version only reads up to {}",
+            path.display(),
+            export.schema,
+            NATIVE_SCHEMA
+        );
+    }
+    Ok(store.restore_issues(&export.issues)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::IssueStatus;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    fn init_temp_repo() -> (TempDir, PathBuf) {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        IssueStore::init(&repo_path).expect("Failed to initialize store");
+        (temp_dir, repo_path)
+    }
+
+    #[test]
+    fn test_export_import_bundle_roundtrip() {
+        let (_source_dir, source_path) = init_temp_repo();
+        let mut source = IssueStore::open(&source_path).expect("Failed to open store");
+        let author = create_test_identity();
+        let first = source
+            .create_issue("First".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        let second = source
+            .create_issue("Second".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        source
+            .add_comment(second, "Shared offline".to_string(), author.clone())
+            .expect("Failed to add comment");
+
+        let bundle_dir = TempDir::new().expect("Failed to create temporary directory");
+        let bundle_path = bundle_dir.path().join("issues.bundle");
+        handle_export(
+            source_path.clone(),
+            ExportArgs {
+                format: ExportFormat::Bundle,
+                output: bundle_path.clone(),
+                since: None,
+            },
+        )
+        .expect("Export should succeed");
+
+        let (_target_dir, target_path) = init_temp_repo();
+        let mut target = IssueStore::open(&target_path).expect("Failed to open store");
+        let changed = target
+            .import_bundle(&bundle_path)
+            .expect("Import should succeed");
+        assert_eq!(changed, vec![first, second]);
+
+        let imported = target.get_issue(second).expect("Issue should be imported");
+        assert_eq!(imported.title, "Second");
+        assert_eq!(imported.comments[0].content, "Shared offline");
+
+        // New issues continue after the imported ones
+        let next_id = target
+            .create_issue("Local".to_string(), "".to_string(), author)
+            .expect("Failed to create issue");
+        assert_eq!(next_id, 3);
+
+        // Importing the same bundle again changes nothing
+        let changed = target
+            .import_bundle(&bundle_path)
+            .expect("Re-import should succeed");
+        assert!(changed.is_empty());
+    }
+
+    #[test]
+    fn test_export_without_issues_fails() {
+        let (_temp_dir, repo_path) = init_temp_repo();
+        let result = handle_export(
+            repo_path.clone(),
+            ExportArgs {
+                format: ExportFormat::Bundle,
+                output: repo_path.join("issues.bundle"),
+                since: None,
+            },
+        );
+        assert_eq!(result.unwrap_err().to_string(), "no issues to export");
+    }
+
+    #[test]
+    fn test_export_import_native_roundtrip() {
+        let (_source_dir, source_path) = init_temp_repo();
+        let mut source = IssueStore::open(&source_path).expect("Failed to open store");
+        let author = create_test_identity();
+        let first = source
+            .create_issue("First".to_string(), "Details".to_string(), author.clone())
+            .expect("Failed to create issue");
+        source
+            .add_label(first, "bug".to_string(), author.clone())
+            .expect("Failed to add label");
+        source
+            .update_issue_status(first, IssueStatus::Done, author.clone())
+            .expect("Failed to update status");
+        let second = source
+            .create_issue("Second".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        source
+            .add_comment(second, "Backed up".to_string(), author)
+            .expect("Failed to add comment");
+
+        let dump_dir = TempDir::new().expect("Failed to create temporary directory");
+        let dump_path = dump_dir.path().join("dump.json");
+        handle_export(
+            source_path.clone(),
+            ExportArgs {
+                format: ExportFormat::Native,
+                output: dump_path.clone(),
+                since: None,
+            },
+        )
+        .expect("Export should succeed");
+
+        let (_target_dir, target_path) = init_temp_repo();
+        handle_import(
+            target_path.clone(),
+            ImportArgs {
+                bundle: None,
+                native: Some(dump_path.clone()),
+            },
+        )
+        .expect("Import should succeed");
+
+        let mut target = IssueStore::open(&target_path).expect("Failed to open store");
+        assert_eq!(target.list_issues().unwrap(), source.list_issues().unwrap());
+        assert_eq!(target.next_issue_id().unwrap(), 3);
+
+        // Importing again would clobber existing issues
+        let err = import_native(&mut target, &dump_path).expect_err("Re-import should fail");
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_export_since_last_only_has_changed_issues() {
+        let (_source_dir, source_path) = init_temp_repo();
+        let mut source = IssueStore::open(&source_path).expect("Failed to open store");
+        let author = create_test_identity();
+        for title in ["First", "Second", "Third"] {
+            source
+                .create_issue(title.to_string(), "".to_string(), author.clone())
+                .expect("Failed to create issue");
+        }
+
+        let dump_dir = TempDir::new().expect("Failed to create temporary directory");
+        let export = |since: Option<&str>| -> Vec<IssueId> {
+            let dump_path = dump_dir.path().join("dump.json");
+            handle_export(
+                source_path.clone(),
+                ExportArgs {
+                    format: ExportFormat::Native,
+                    output: dump_path.clone(),
+                    since: since.map(|since| since.parse().expect("Should parse --since")),
+                },
+            )
+            .expect("Export should succeed");
+            let content = std::fs::read_to_string(&dump_path).unwrap();
+            let export: NativeExport = serde_json::from_str(&content).unwrap();
+            export.issues.iter().map(|dump| dump.id).collect()
+        };
+
+        // Without a marker, `last` exports everything
+        assert_eq!(export(Some("last")), vec![1, 2, 3]);
+        assert_eq!(export(Some("last")), Vec::<IssueId>::new());
+
+        source
+            .add_comment(2, "Changed".to_string(), author.clone())
+            .expect("Failed to add comment");
+        assert_eq!(export(Some("last")), vec![2]);
+
+        // An export since a date leaves the marker where it was
+        source
+            .add_comment(3, "Changed too".to_string(), author.clone())
+            .expect("Failed to add comment");
+        assert_eq!(export(Some("2000-01-01")), vec![1, 2, 3]);
+        assert_eq!(export(Some("last")), vec![3]);
+
+        // A full export moves it
+        source
+            .add_comment(1, "Changed as well".to_string(), author)
+            .expect("Failed to add comment");
+        assert_eq!(export(None), vec![1, 2, 3]);
+        assert_eq!(export(Some("last")), Vec::<IssueId>::new());
+
+        // The marker is local state and never synced
+        assert!(source.last_export().unwrap().is_some());
+        assert!(
+            !source
+                .sync_refs(None)
+                .unwrap()
+                .contains(&crate::storage::issue_store::EXPORT_MARKER_REF.to_string())
+        );
+        assert!("yesterday".parse::<ExportSince>().is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use clap::Args;
+
+use crate::cli::output::{success_message, warning_message};
+use crate::common::{Identity, IssueId, IssueStatus, SystemEnvProvider};
+use crate::storage::IssueStore;
+
+use super::{add_trailing_comment, get_author_identity};
+
+#[derive(Args)]
+pub struct CloseArgs {
+    /// Issue ID or slug to close
+    pub id: String,
+
+    /// Comment to add when closing the issue
+    #[arg(short, long)]
+    pub comment: Option<String>,
+
+    /// Author name (defaults to git config)
+    #[arg(long)]
+    pub author_name: Option<String>,
+
+    /// Author email (defaults to git config)
+    #[arg(long)]
+    pub author_email: Option<String>,
+}
+
+pub fn handle_close(repo_path: std::path::PathBuf, args: CloseArgs) -> Result<()> {
+    let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
+    let issue_id = store.resolve_ref(&args.id)?;
+    let author = get_author_identity(
+        args.author_name,
+        args.author_email,
+        &store,
+        SystemEnvProvider,
+    )?;
+
+    if close(&mut store, issue_id, author.clone())? {
+        println!(
+            "{}",
+            success_message(&format!("Closed issue #{}", issue_id))
+        );
+        add_trailing_comment(&mut store, issue_id, args.comment.as_slice(), author)
+    } else {
+        println!(
+            "{}",
+            warning_message(&format!("Issue #{} is already closed", issue_id))
+        );
+        Ok(())
+    }
+}
+
+/// Mark an issue as done, returning whether it was closed
+///
+/// Issues that are already done are left untouched.
+fn close(store: &mut IssueStore, issue_id: IssueId, author: Identity) -> Result<bool> {
+    let issue = store.get_issue(issue_id)?;
+    if issue.status == IssueStatus::Done {
+        return Ok(false);
+    }
+
+    store.update_issue_status(issue_id, IssueStatus::Done, author)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::IssueEvent;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_handle_close_with_comment() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to init store");
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), String::new(), author.clone())
+            .unwrap();
+        let before = store.get_issue_events(issue_id).unwrap().len();
+
+        let args = |comment: &str| CloseArgs {
+            id: issue_id.to_string(),
+            comment: Some(comment.to_string()),
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+        };
+        handle_close(repo_path.clone(), args("Fixed in main")).expect("Close should succeed");
+
+        let events = store.get_issue_events(issue_id).unwrap();
+        assert_eq!(events.len(), before + 2);
+        assert!(matches!(
+            &events[before],
+            IssueEvent::StatusChanged {
+                to: IssueStatus::Done,
+                ..
+            }
+        ));
+        assert!(matches!(
+            &events[before + 1],
+            IssueEvent::CommentAdded { content, .. } if content == "Fixed in main"
+        ));
+
+        // Closing a done issue records nothing, not even the comment
+        handle_close(repo_path, args("Again")).expect("Close should succeed");
+        assert_eq!(
+            store.get_issue_events(issue_id).unwrap().len(),
+            events.len()
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use anyhow::Result;
+use clap::Args;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use crate::cli::output::success_message;
+use crate::common::{Identity, SystemEnvProvider};
+use crate::storage::IssueStore;
+
+use super::get_author_identity;
+
+/// Location of the optional comment template, relative to the work tree root
+const COMMENT_TEMPLATE_PATH: &str = ".git-issue/templates/comment.md";
+
+/// Instructions appended to the editor buffer; stripped along with other `#` lines
+const EDITOR_INSTRUCTIONS: &str = "\
+# Write your comment above. Lines starting with '#' will be ignored,
+# and an empty comment aborts.";
+
+#[derive(Args)]
+pub struct CommentArgs {
+    /// Issue ID or slug to comment on
+    pub id: String,
+
+    /// Comment text (opens the editor if omitted)
+    pub body: Option<String>,
+
+    /// Always open the editor, pre-filled with the body or the comment template
+    #[arg(short, long)]
+    pub editor: bool,
+
+    /// Author name (defaults to git config)
+    #[arg(long)]
+    pub author_name: Option<String>,
+
+    /// Author email (defaults to git config)
+    #[arg(long)]
+    pub author_email: Option<String>,
+
+    /// Person a bot author is acting for, as `Name <email>` or an email
+    #[arg(long, value_name = "IDENTITY")]
+    pub on_behalf_of: Option<Identity>,
+}
+
+pub fn handle_comment(repo_path: std::path::PathBuf, args: CommentArgs) -> Result<()> {
+    let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
+    let issue_id = store.resolve_ref(&args.id)?;
+    let author = get_author_identity(
+        args.author_name,
+        args.author_email,
+        &store,
+        SystemEnvProvider,
+    )?;
+    store.set_on_behalf_of(args.on_behalf_of);
+
+    // Make sure the issue exists before opening an editor
+    store.get_issue(issue_id)?;
+
+    let content = match args.body {
+        Some(body) if !args.editor => body,
+        body => {
+            let initial = match body {
+                Some(body) => body,
+                None => store
+                    .workdir()
+                    .map(read_comment_template)
+                    .transpose()?
+                    .unwrap_or_default(),
+            };
+            compose_comment(&initial, open_editor)?
+        }
+    };
+
+    if content.trim().is_empty() {
+        return Err(anyhow::anyhow!("empty comment, aborting"));
+    }
+
+    let comment_id = store.add_comment(issue_id, content, author)?;
+
+    println!(
+        "{}",
+        success_message(&format!(
+            "Added comment {} to issue #{}",
+            comment_id, issue_id
+        ))
+    );
+    Ok(())
+}
+
+/// Read the comment template of the work tree at `workdir`, or an empty string if there is none
+///
+/// Only a missing template means there is none; any other read error is reported.
+fn read_comment_template(workdir: &Path) -> Result<String> {
+    let path = workdir.join(COMMENT_TEMPLATE_PATH);
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(err) => Err(anyhow::anyhow!(
+            "Failed to read '{}': {}",
+            path.display(),
+            err
+        )),
+    }
+}
+
+/// Let the user edit a comment and return it with `#` lines stripped
+///
+/// `editor` receives the initial buffer and returns the edited one.
+fn compose_comment(initial: &str, editor: impl FnOnce(&str) -> Result<String>) -> Result<String> {
+    let buffer = format!("{}\n\n{}\n", initial.trim_end(), EDITOR_INSTRUCTIONS);
+    let edited = editor(&buffer)?;
+
+    let content = strip_comment_lines(&edited);
+    if content.is_empty() {
+        return Err(anyhow::anyhow!("empty comment, aborting"));
+    }
+    Ok(content)
+}
+
+/// Remove lines starting with `#`, like `git commit` does, and trim the result
+fn strip_comment_lines(text: &str) -> String {
+    text.lines()
+        .filter(|line| !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Open the user's editor on a temporary markdown file
+fn open_editor(initial: &str) -> Result<String> {
+    let mut temp_file = tempfile::Builder::new().suffix(".md").tempfile()?;
+    temp_file.write_all(initial.as_bytes())?;
+    temp_file.flush()?;
+
+    edit::edit_file(temp_file.path())?;
+
+    Ok(fs::read_to_string(temp_file.path())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_strip_comment_lines() {
+        let text = "# heading-like comment\nKeep this\n  # indented stays\n\n# trailing\n";
+        assert_eq!(strip_comment_lines(text), "Keep this\n  # indented stays");
+    }
+
+    #[test]
+    fn test_compose_comment_with_fake_editor() {
+        let template = "## Steps to reproduce\n# Describe the steps";
+
+        let content = compose_comment(template, |buffer| {
+            assert!(buffer.starts_with(template));
+            assert!(buffer.contains(EDITOR_INSTRUCTIONS));
+            Ok(buffer.replace("# Describe the steps", "1. Run it"))
+        })
+        .expect("Should compose comment");
+
+        // Like `git commit`, every line starting with '#' is dropped, headings included
+        assert_eq!(content, "1. Run it");
+    }
+
+    #[test]
+    fn test_compose_comment_empty_aborts() {
+        let result = compose_comment("", |buffer| Ok(buffer.to_string()));
+        assert_eq!(result.unwrap_err().to_string(), "empty comment, aborting");
+    }
+
+    #[test]
+    fn test_read_comment_template() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        assert_eq!(read_comment_template(temp_dir.path()).unwrap(), "");
+
+        let template_path = temp_dir.path().join(COMMENT_TEMPLATE_PATH);
+        fs::create_dir_all(template_path.parent().unwrap()).unwrap();
+        fs::write(&template_path, "Template body\n").unwrap();
+        assert_eq!(
+            read_comment_template(temp_dir.path()).unwrap(),
+            "Template body\n"
+        );
+
+        // A template that cannot be read is an error, not an empty template
+        fs::remove_file(&template_path).unwrap();
+        fs::create_dir(&template_path).unwrap();
+        assert!(read_comment_template(temp_dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_handle_comment_with_body() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        let args = CommentArgs {
+            id: issue_id.to_string(),
+            body: Some("Looks good".to_string()),
+            editor: false,
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            on_behalf_of: None,
+        };
+        handle_comment(repo_path.clone(), args).expect("Comment should succeed");
+
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(issue.comments.len(), 1);
+        assert_eq!(issue.comments[0].content, "Looks good");
+        assert_eq!(issue.comments[0].author, author);
+    }
+
+    #[test]
+    fn test_handle_comment_on_behalf_of() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let bot = Identity::new("Triage Bot", "bot@example.com");
+        let alice = Identity::new("Alice", "alice@example.com");
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), bot.clone())
+            .expect("Failed to create issue");
+
+        let args = CommentArgs {
+            id: issue_id.to_string(),
+            body: Some("Reported via the form".to_string()),
+            editor: false,
+            author_name: Some(bot.name.clone()),
+            author_email: Some(bot.email.clone()),
+            on_behalf_of: Some(alice.clone()),
+        };
+        handle_comment(repo_path.clone(), args).expect("Comment should succeed");
+
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(issue.comments[0].author, bot);
+        assert_eq!(issue.comments[0].on_behalf_of, Some(alice));
+    }
+}
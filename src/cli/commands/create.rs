@@ -1,11 +1,16 @@
 use anyhow::Result;
 use clap::Args;
+use std::io::Read;
 
 use crate::cli::output::success_message;
-use crate::common::{EnvProvider, Priority, SystemEnvProvider};
+use crate::common::{EnvProvider, Identity, IssueId, Priority, SystemEnvProvider};
 use crate::storage::IssueStore;
+use crate::storage::errors::{GitError, StorageError};
 
-use super::get_author_identity;
+use super::{
+    get_author_identity, lint_description_enabled, read_description_from, warn_description_lint,
+    warn_duplicate_titles,
+};
 
 #[derive(Args)]
 pub struct CreateArgs {
@@ -16,6 +21,14 @@ pub struct CreateArgs {
     #[arg(short, long)]
     pub description: Option<String>,
 
+    /// Read the description from a markdown file, optionally limited to a section (`file.md#Heading`)
+    #[arg(long, value_name = "FILE[#HEADING]", conflicts_with = "description")]
+    pub description_from: Option<String>,
+
+    /// Read the description from stdin until EOF
+    #[arg(long, conflicts_with_all = ["description", "description_from"])]
+    pub stdin: bool,
+
     /// Author name (defaults to git config)
     #[arg(long)]
     pub author_name: Option<String>,
@@ -24,31 +37,113 @@ pub struct CreateArgs {
     #[arg(long)]
     pub author_email: Option<String>,
 
+    /// Person a bot author is acting for, as `Name <email>` or an email
+    #[arg(long, value_name = "IDENTITY")]
+    pub on_behalf_of: Option<Identity>,
+
     /// Priority level (none, urgent, high, medium, low, or 0-4)
     #[arg(short, long)]
     pub priority: Option<Priority>,
+
+    /// Create the issue at this ID instead of the next free one (for imports)
+    #[arg(long)]
+    pub id: Option<IssueId>,
+
+    /// Overwrite an existing issue when used with --id
+    #[arg(long, requires = "id")]
+    pub force: bool,
+
+    /// Leave the issue unassigned even when `create.assignSelf` is set
+    #[arg(long)]
+    pub no_assign_self: bool,
+
+    /// Warn about unbalanced code fences and broken reference links in the description
+    #[arg(long)]
+    pub lint_description: bool,
+
+    /// Skip the warning about open issues with the same title
+    #[arg(long)]
+    pub no_dup_check: bool,
+
+    /// Initialize a git repository at the repo path if there is none yet
+    #[arg(long)]
+    pub create_repo: bool,
 }
 
 pub fn handle_create(repo_path: std::path::PathBuf, args: CreateArgs) -> Result<()> {
-    handle_create_with_env(repo_path, args, SystemEnvProvider)
+    handle_create_with_env(repo_path, args, SystemEnvProvider, std::io::stdin())
 }
 
+/// Create an issue with the environment and stdin supplied by the caller
 pub fn handle_create_with_env(
     repo_path: std::path::PathBuf,
     args: CreateArgs,
     env_provider: impl EnvProvider,
+    mut stdin: impl Read,
 ) -> Result<()> {
-    let mut store = IssueStore::open(&repo_path).or_else(|_| IssueStore::init(&repo_path))?;
+    // Only initialize on request, so a mistyped --repo does not grow a new store
+    let mut store = match IssueStore::open(&repo_path) {
+        Ok(store) => store,
+        Err(StorageError::Git(GitError::RepositoryNotFound { .. })) if args.create_repo => {
+            IssueStore::init(&repo_path)?
+        }
+        Err(StorageError::Git(GitError::RepositoryNotFound { .. })) => anyhow::bail!(
+            "no git-issue store in '{}'; pass --create-repo to initialize",
+            repo_path.display()
+        ),
+        Err(err) => return Err(err.into()),
+    };
+    let _lock = store.lock_for_writing()?;
 
     let author = get_author_identity(args.author_name, args.author_email, &store, env_provider)?;
-    let description = args.description.unwrap_or_else(|| "".to_string());
+    store.set_on_behalf_of(args.on_behalf_of);
+    let description = if args.stdin {
+        let mut description = String::new();
+        stdin.read_to_string(&mut description)?;
+        // Keep indentation, such as a pasted stack trace, and drop only the final newline
+        let len = description
+            .strip_suffix('\n')
+            .map_or(description.len(), |rest| {
+                rest.strip_suffix('\r').unwrap_or(rest).len()
+            });
+        description.truncate(len);
+        description
+    } else {
+        match args.description_from {
+            Some(spec) => read_description_from(&spec)?,
+            None => args.description.unwrap_or_default(),
+        }
+    };
+    if lint_description_enabled(args.lint_description, &store) {
+        warn_description_lint(&description);
+    }
     let priority = args.priority.unwrap_or_default();
+    if !args.no_dup_check {
+        warn_duplicate_titles(&store, &args.title, args.id)?;
+    }
 
-    let issue_id = store.create_issue(args.title, description, author.clone())?;
+    let issue_id = match args.id {
+        Some(issue_id) => {
+            store.create_issue_with_id(
+                issue_id,
+                args.title,
+                description,
+                author.clone(),
+                args.force,
+            )?;
+            issue_id
+        }
+        None => store.create_issue(args.title, description, author.clone())?,
+    };
 
     // Set priority if specified
     if priority != Priority::default() {
-        store.update_priority(issue_id, priority, author)?;
+        store.update_priority(issue_id, priority, author.clone())?;
+    }
+
+    let assign_self = store.get_config_bool("create.assignSelf").unwrap_or(false);
+    if assign_self && !args.no_assign_self {
+        store.update_assignees(issue_id, vec![author.clone()], author)?;
     }
 
     println!(
@@ -68,6 +163,7 @@ mod tests {
     fn setup_temp_cli_repo() -> (TempDir, std::path::PathBuf) {
         let temp_dir = TempDir::new().expect("Failed to create temporary directory");
         let repo_path = temp_dir.path().to_path_buf();
+        IssueStore::init(&repo_path).expect("Failed to initialize store");
         (temp_dir, repo_path)
     }
 
@@ -80,9 +176,18 @@ mod tests {
         let args = CreateArgs {
             title: "Test Issue".to_string(),
             description: Some("This is a test issue".to_string()),
+            description_from: None,
+            stdin: false,
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
             priority: None,
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
         };
 
         let result = handle_create(repo_path.clone(), args);
@@ -114,9 +219,18 @@ mod tests {
         let args = CreateArgs {
             title: "Issue Without Description".to_string(),
             description: None,
+            description_from: None,
+            stdin: false,
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
             priority: None,
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
         };
 
         let result = handle_create(repo_path.clone(), args);
@@ -150,12 +264,21 @@ mod tests {
         let args = CreateArgs {
             title: "Issue With Default Author".to_string(),
             description: None,
+            description_from: None,
+            stdin: false,
             author_name: None,
             author_email: None,
             priority: None,
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
         };
 
-        let result = handle_create_with_env(repo_path.clone(), args, mock_env);
+        let result = handle_create_with_env(repo_path.clone(), args, mock_env, std::io::empty());
         assert!(
             result.is_ok(),
             "Create command should succeed with default author"
@@ -186,9 +309,18 @@ mod tests {
             let args = CreateArgs {
                 title: format!("Issue {}", i),
                 description: Some(format!("Description for issue {}", i)),
+                description_from: None,
+                stdin: false,
                 author_name: Some(author.name.clone()),
                 author_email: Some(author.email.clone()),
                 priority: None,
+                id: None,
+                force: false,
+                no_assign_self: false,
+                lint_description: false,
+                no_dup_check: false,
+                create_repo: false,
+                on_behalf_of: None,
             };
 
             let result = handle_create(repo_path.clone(), args);
@@ -227,9 +359,18 @@ mod tests {
         let args = CreateArgs {
             title: "High Priority Issue".to_string(),
             description: Some("This is urgent".to_string()),
+            description_from: None,
+            stdin: false,
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
             priority: Some(Priority::High),
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
         };
 
         let result = handle_create(repo_path.clone(), args);
@@ -262,9 +403,18 @@ mod tests {
         let args = CreateArgs {
             title: "Normal Issue".to_string(),
             description: Some("No priority set".to_string()),
+            description_from: None,
+            stdin: false,
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
             priority: None,
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
         };
 
         let result = handle_create(repo_path.clone(), args);
@@ -283,4 +433,219 @@ mod tests {
         // assert_eq!(issue.title, "Normal Issue");
         // assert_eq!(issue.priority, Priority::None);
     }
+
+    #[test]
+    fn test_create_command_description_from_section() {
+        let (_temp_dir, repo_path) = setup_temp_cli_repo();
+        let author = create_test_identity();
+
+        let doc_path = repo_path.join("design.md");
+        std::fs::write(
+            &doc_path,
+            "# Design\n\n## Problem\n\nLogin fails.\n\n## Fix\n\nRetry.\n",
+        )
+        .expect("Failed to write markdown file");
+
+        let args = CreateArgs {
+            title: "Imported Issue".to_string(),
+            description: None,
+            description_from: Some(format!("{}#Problem", doc_path.display())),
+            stdin: false,
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            priority: None,
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
+        };
+
+        handle_create(repo_path.clone(), args).expect("Create command should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should be able to open store");
+        let issues = store.list_issues().expect("Should be able to list issues");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].description, "Login fails.");
+    }
+
+    #[test]
+    fn test_create_command_description_from_missing_heading() {
+        let (_temp_dir, repo_path) = setup_temp_cli_repo();
+
+        let doc_path = repo_path.join("design.md");
+        std::fs::write(&doc_path, "# Design\n\nText.\n").expect("Failed to write file");
+
+        let args = CreateArgs {
+            title: "Imported Issue".to_string(),
+            description: None,
+            description_from: Some(format!("{}#Missing", doc_path.display())),
+            stdin: false,
+            author_name: None,
+            author_email: None,
+            priority: None,
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
+        };
+
+        assert!(handle_create(repo_path, args).is_err());
+    }
+
+    #[test]
+    fn test_create_command_at_specific_id() {
+        let (_temp_dir, repo_path) = setup_temp_cli_repo();
+        let author = create_test_identity();
+
+        let make_args = |title: &str, force: bool| CreateArgs {
+            title: title.to_string(),
+            description: None,
+            description_from: None,
+            stdin: false,
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            priority: None,
+            id: Some(42),
+            force,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
+        };
+
+        handle_create(repo_path.clone(), make_args("Imported", false))
+            .expect("Create at id should succeed");
+        let result = handle_create(repo_path.clone(), make_args("Clash", false));
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Issue already exists: 42")
+        );
+
+        handle_create(repo_path.clone(), make_args("Forced", true))
+            .expect("Forced create should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should be able to open store");
+        assert_eq!(store.get_issue(42).unwrap().title, "Forced");
+    }
+
+    #[test]
+    fn test_create_command_description_from_stdin() {
+        let (_temp_dir, repo_path) = setup_temp_cli_repo();
+        let author = create_test_identity();
+
+        let args = CreateArgs {
+            title: "Crash report".to_string(),
+            description: None,
+            description_from: None,
+            stdin: true,
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            priority: None,
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
+        };
+        let input = "    thread 'main' panicked\n\n    at src/main.rs:12\n\n";
+        handle_create_with_env(
+            repo_path.clone(),
+            args,
+            MockEnvProvider::new(),
+            input.as_bytes(),
+        )
+        .expect("Create command should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let issue = store.get_issue(1).expect("Issue should exist");
+        assert_eq!(
+            issue.description,
+            "    thread 'main' panicked\n\n    at src/main.rs:12\n"
+        );
+    }
+
+    #[test]
+    fn test_create_command_assign_self_config() {
+        let (_temp_dir, repo_path) = setup_temp_cli_repo();
+        let status = std::process::Command::new("git")
+            .args(["config", "create.assignSelf", "true"])
+            .current_dir(&repo_path)
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+
+        let author = create_test_identity();
+        let make_args = |title: &str, no_assign_self: bool| CreateArgs {
+            title: title.to_string(),
+            description: None,
+            description_from: None,
+            stdin: false,
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            priority: None,
+            id: None,
+            force: false,
+            no_assign_self,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo: false,
+            on_behalf_of: None,
+        };
+
+        handle_create(repo_path.clone(), make_args("Mine", false)).unwrap();
+        handle_create(repo_path.clone(), make_args("Nobody's", true)).unwrap();
+
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        assert_eq!(store.get_issue(1).unwrap().assignees, vec![author.clone()]);
+        assert!(store.get_issue(2).unwrap().assignees.is_empty());
+    }
+
+    #[test]
+    fn test_create_command_requires_create_repo_outside_a_repository() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().join("typo");
+        let author = create_test_identity();
+        let args = |create_repo: bool| CreateArgs {
+            title: "First".to_string(),
+            description: None,
+            description_from: None,
+            stdin: false,
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            on_behalf_of: None,
+            priority: None,
+            id: None,
+            force: false,
+            no_assign_self: false,
+            lint_description: false,
+            no_dup_check: false,
+            create_repo,
+        };
+
+        let err = handle_create(repo_path.clone(), args(false))
+            .expect_err("Create should not initialize a store implicitly");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "no git-issue store in '{}'; pass --create-repo to initialize",
+                repo_path.display()
+            )
+        );
+        assert!(!repo_path.exists());
+
+        handle_create(repo_path.clone(), args(true)).expect("--create-repo should initialize");
+        let store = IssueStore::open(&repo_path).expect("Store should exist now");
+        assert_eq!(store.get_issue(1).unwrap().title, "First");
+    }
 }
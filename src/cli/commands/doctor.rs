@@ -0,0 +1,434 @@
+use anyhow::Result;
+use clap::Args;
+
+use super::confirm;
+use crate::cli::output::{error_message, success_message, warning_message};
+use crate::common::IssueId;
+use crate::storage::IssueStore;
+use crate::storage::repo::REFS_NAMESPACE;
+
+#[derive(Args)]
+pub struct DoctorArgs {
+    /// Repair the problems that can be fixed safely
+    #[arg(long)]
+    pub fix: bool,
+}
+
+/// A problem found in the issue storage
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problem {
+    /// A ref was written under the legacy namespace by an older version
+    LegacyRef { ref_name: String },
+    /// The issue counter would hand out an ID that is already taken
+    CounterBehind { next_id: IssueId, max_id: IssueId },
+    /// The index has entries for issues that no longer exist
+    DanglingIndexEntries { issue_ids: Vec<IssueId> },
+    /// An issue's event chain cannot be replayed
+    BrokenIssue { issue_id: IssueId, error: String },
+    /// The index is missing or out of date for issues changed outside git-issue
+    StaleIndexEntries { issue_ids: Vec<IssueId> },
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::LegacyRef { ref_name } => write!(
+                f,
+                "{} is outside the {} namespace",
+                ref_name, REFS_NAMESPACE
+            ),
+            Problem::CounterBehind { next_id, max_id } => write!(
+                f,
+                "next issue ID is {} but issue #{} already exists",
+                next_id, max_id
+            ),
+            Problem::DanglingIndexEntries { issue_ids } => write!(
+                f,
+                "index has entries for missing issues: {}",
+                format_ids(issue_ids)
+            ),
+            Problem::BrokenIssue { issue_id, error } => {
+                write!(f, "issue #{} cannot be read: {}", issue_id, error)
+            }
+            Problem::StaleIndexEntries { issue_ids } => write!(
+                f,
+                "index is out of date for issues: {}",
+                format_ids(issue_ids)
+            ),
+        }
+    }
+}
+
+pub fn handle_doctor(
+    repo_path: std::path::PathBuf,
+    args: DoctorArgs,
+    assume_yes: bool,
+) -> Result<()> {
+    let mut store = IssueStore::open(&repo_path)?;
+    let problems = diagnose(&store)?;
+
+    if problems.is_empty() {
+        println!("{}", success_message("No problems found"));
+        return Ok(());
+    }
+
+    if !args.fix {
+        for problem in &problems {
+            println!("{}", warning_message(&problem.to_string()));
+        }
+        return Err(anyhow::anyhow!(
+            "Found {} problems. Run `git issue doctor --fix` to repair them",
+            problems.len()
+        ));
+    }
+
+    let _lock = store.lock_for_writing()?;
+    let mut unfixed = 0;
+    for problem in &problems {
+        match fix_problem(&mut store, problem, |question| {
+            confirm(question, assume_yes)
+        })? {
+            Some(action) => println!("{}", success_message(&action)),
+            None => {
+                unfixed += 1;
+                println!(
+                    "{}",
+                    error_message(&format!("Not fixed: {} (needs manual action)", problem))
+                );
+            }
+        }
+    }
+
+    if unfixed > 0 {
+        return Err(anyhow::anyhow!("{} problems were left unfixed", unfixed));
+    }
+    Ok(())
+}
+
+/// Check the issue storage for problems
+fn diagnose(store: &IssueStore) -> Result<Vec<Problem>> {
+    let mut problems: Vec<Problem> = store
+        .legacy_refs()?
+        .into_iter()
+        .map(|ref_name| Problem::LegacyRef { ref_name })
+        .collect();
+    let issue_ids = store.list_issue_ids()?;
+
+    if let Some(&max_id) = issue_ids.last() {
+        let next_id = store.next_issue_id()?;
+        if next_id <= max_id {
+            problems.push(Problem::CounterBehind { next_id, max_id });
+        }
+    }
+
+    let dangling: Vec<IssueId> = store
+        .read_index()?
+        .into_keys()
+        .filter(|issue_id| !issue_ids.contains(issue_id))
+        .collect();
+    if !dangling.is_empty() {
+        problems.push(Problem::DanglingIndexEntries {
+            issue_ids: dangling,
+        });
+    }
+
+    let mut broken = false;
+    for &issue_id in &issue_ids {
+        if let Err(e) = store.get_issue(issue_id) {
+            problems.push(Problem::BrokenIssue {
+                issue_id,
+                error: e.to_string(),
+            });
+            broken = true;
+        }
+    }
+
+    // Rebuilding the index replays every stale issue, so it waits for broken ones to be fixed
+    if !broken {
+        let stored = store.read_index()?;
+        let stale: Vec<IssueId> = store
+            .current_index()?
+            .into_iter()
+            .filter(|(issue_id, entry)| stored.get(issue_id) != Some(entry))
+            .map(|(issue_id, _)| issue_id)
+            .collect();
+        if !stale.is_empty() {
+            problems.push(Problem::StaleIndexEntries { issue_ids: stale });
+        }
+    }
+
+    Ok(problems)
+}
+
+/// Repair a single problem, returning a description of what was done
+///
+/// Returns `None` when the problem was left alone. Dropping an issue is only
+/// done when `confirm` agrees.
+fn fix_problem(
+    store: &mut IssueStore,
+    problem: &Problem,
+    mut confirm: impl FnMut(&str) -> bool,
+) -> Result<Option<String>> {
+    match problem {
+        Problem::LegacyRef { ref_name } => Ok(store
+            .migrate_legacy_ref(ref_name)?
+            .map(|target| format!("Moved {} to {}", ref_name, target))),
+        Problem::CounterBehind { max_id, .. } => {
+            store.advance_issue_id_past(*max_id)?;
+            Ok(Some(format!("Reset next issue ID to {}", max_id + 1)))
+        }
+        Problem::DanglingIndexEntries { .. } => {
+            let removed = store.prune_index()?;
+            Ok(Some(format!(
+                "Removed index entries for {}",
+                format_ids(&removed)
+            )))
+        }
+        Problem::StaleIndexEntries { issue_ids } => {
+            store.refresh_index()?;
+            Ok(Some(format!(
+                "Rebuilt index entries for {}",
+                format_ids(issue_ids)
+            )))
+        }
+        Problem::BrokenIssue { issue_id, .. } => {
+            if !confirm(&format!("Drop unreadable issue #{}?", issue_id)) {
+                return Ok(None);
+            }
+            store.delete_issue(*issue_id)?;
+            Ok(Some(format!("Dropped issue #{}", issue_id)))
+        }
+    }
+}
+
+fn format_ids(issue_ids: &[IssueId]) -> String {
+    issue_ids
+        .iter()
+        .map(|issue_id| format!("#{}", issue_id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::test_helpers::*;
+    use clap::Parser;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn setup_doctor_repo() -> (TempDir, std::path::PathBuf, IssueStore) {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let author = create_test_identity();
+        for title in ["First", "Second", "Third"] {
+            store
+                .create_issue(title.to_string(), "".to_string(), author.clone())
+                .expect("Failed to create issue");
+        }
+        (temp_dir, repo_path, store)
+    }
+
+    fn git(repo_path: &std::path::Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .env("GIT_AUTHOR_NAME", "Test User")
+            .env("GIT_AUTHOR_EMAIL", "test@example.com")
+            .env("GIT_COMMITTER_NAME", "Test User")
+            .env("GIT_COMMITTER_EMAIL", "test@example.com")
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    #[test]
+    fn test_healthy_repo_has_no_problems() {
+        let (_temp_dir, _repo_path, store) = setup_doctor_repo();
+        assert!(diagnose(&store).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_fix_rebuilds_counter() {
+        let (_temp_dir, repo_path, mut store) = setup_doctor_repo();
+
+        // Roll the counter back as if it had been clobbered
+        let counter = repo_path.join("counter");
+        std::fs::write(&counter, "2").unwrap();
+        let blob = git(
+            &repo_path,
+            &["hash-object", "-w", counter.to_str().unwrap()],
+        );
+        git(
+            &repo_path,
+            &["update-ref", "refs/git-issue/meta/next-issue-id", &blob],
+        );
+
+        let problems = diagnose(&store).unwrap();
+        assert_eq!(
+            problems,
+            vec![Problem::CounterBehind {
+                next_id: 2,
+                max_id: 3
+            }]
+        );
+
+        let action = fix_problem(&mut store, &problems[0], |_| false).unwrap();
+        assert_eq!(action.as_deref(), Some("Reset next issue ID to 4"));
+        assert_eq!(store.next_issue_id().unwrap(), 4);
+        assert!(diagnose(&store).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_fix_removes_dangling_index_entries() {
+        let (_temp_dir, repo_path, mut store) = setup_doctor_repo();
+
+        // Delete an issue ref behind the index's back
+        git(&repo_path, &["update-ref", "-d", "refs/git-issue/issues/2"]);
+
+        let problems = diagnose(&store).unwrap();
+        assert_eq!(
+            problems,
+            vec![Problem::DanglingIndexEntries { issue_ids: vec![2] }]
+        );
+
+        let action = fix_problem(&mut store, &problems[0], |_| false).unwrap();
+        assert_eq!(action.as_deref(), Some("Removed index entries for #2"));
+        assert_eq!(
+            store.read_index().unwrap().into_keys().collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert!(diagnose(&store).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_fix_rebuilds_stale_index_entries() {
+        let (_temp_dir, repo_path, mut store) = setup_doctor_repo();
+
+        // Drop the index as if it had never been written
+        git(
+            &repo_path,
+            &["update-ref", "-d", "refs/git-issue/meta/index"],
+        );
+
+        let problems = diagnose(&store).unwrap();
+        assert_eq!(
+            problems,
+            vec![Problem::StaleIndexEntries {
+                issue_ids: vec![1, 2, 3]
+            }]
+        );
+
+        let action = fix_problem(&mut store, &problems[0], |_| false).unwrap();
+        assert_eq!(
+            action.as_deref(),
+            Some("Rebuilt index entries for #1, #2, #3")
+        );
+        assert_eq!(store.read_index().unwrap(), store.current_index().unwrap());
+        assert!(diagnose(&store).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_broken_issue_is_only_dropped_when_confirmed() {
+        let (_temp_dir, repo_path, mut store) = setup_doctor_repo();
+
+        // Point an issue ref at a commit whose tree has no event
+        let tree = git(&repo_path, &["mktree"]);
+        let commit = git(&repo_path, &["commit-tree", &tree, "-m", "broken"]);
+        git(
+            &repo_path,
+            &["update-ref", "refs/git-issue/issues/3", &commit],
+        );
+
+        let problems = diagnose(&store).unwrap();
+        assert!(matches!(
+            problems.as_slice(),
+            [Problem::BrokenIssue { issue_id: 3, .. }]
+        ));
+
+        assert_eq!(
+            fix_problem(&mut store, &problems[0], |_| false).unwrap(),
+            None
+        );
+        assert!(store.issue_exists(3).unwrap());
+
+        let action = fix_problem(&mut store, &problems[0], |_| true).unwrap();
+        assert_eq!(action.as_deref(), Some("Dropped issue #3"));
+        assert!(!store.issue_exists(3).unwrap());
+    }
+
+    #[test]
+    fn test_yes_drops_broken_issue_without_prompting() {
+        let (_temp_dir, repo_path, _store) = setup_doctor_repo();
+
+        let tree = git(&repo_path, &["mktree"]);
+        let commit = git(&repo_path, &["commit-tree", &tree, "-m", "broken"]);
+        git(
+            &repo_path,
+            &["update-ref", "refs/git-issue/issues/3", &commit],
+        );
+
+        let repo = repo_path.to_str().unwrap();
+        let cli =
+            crate::cli::Cli::parse_from(["git-issue", "--repo", repo, "--yes", "doctor", "--fix"]);
+        crate::cli::run_command(cli).expect("Doctor should fix without prompting");
+
+        let store = IssueStore::open(&repo_path).unwrap();
+        assert!(!store.issue_exists(3).unwrap());
+        assert!(diagnose(&store).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_fix_migrates_legacy_refs() {
+        let (_temp_dir, repo_path, mut store) = setup_doctor_repo();
+
+        // Move issue 3 under the legacy namespace, as older versions wrote it
+        let commit = git(&repo_path, &["rev-parse", "refs/git-issue/issues/3"]);
+        git(
+            &repo_path,
+            &["update-ref", "refs/git-tracker/issues/3", &commit],
+        );
+        git(&repo_path, &["update-ref", "-d", "refs/git-issue/issues/3"]);
+
+        // A legacy ref clashing with an existing issue cannot be moved
+        let other = git(&repo_path, &["rev-parse", "refs/git-issue/issues/2"]);
+        git(
+            &repo_path,
+            &["update-ref", "refs/git-tracker/issues/1", &other],
+        );
+
+        let problems = diagnose(&store).unwrap();
+        assert_eq!(
+            problems[..2],
+            [
+                Problem::LegacyRef {
+                    ref_name: "refs/git-tracker/issues/1".to_string()
+                },
+                Problem::LegacyRef {
+                    ref_name: "refs/git-tracker/issues/3".to_string()
+                },
+            ]
+        );
+
+        assert_eq!(
+            fix_problem(&mut store, &problems[0], |_| false).unwrap(),
+            None
+        );
+        let action = fix_problem(&mut store, &problems[1], |_| false).unwrap();
+        assert_eq!(
+            action.as_deref(),
+            Some("Moved refs/git-tracker/issues/3 to refs/git-issue/issues/3")
+        );
+        assert_eq!(store.get_issue(3).unwrap().title, "Third");
+
+        // Only the clashing ref is left for manual action
+        assert_eq!(
+            diagnose(&store).unwrap(),
+            vec![Problem::LegacyRef {
+                ref_name: "refs/git-tracker/issues/1".to_string()
+            }]
+        );
+    }
+}
@@ -3,27 +3,52 @@ use clap::Args;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::fs;
-use std::io::Write;
+use std::io::{BufRead, Read, Write};
 
+use crate::cli::output::warning_message;
 use crate::common::{Identity, IssueId, IssueStatus, Priority, SystemEnvProvider};
 use crate::storage::IssueStore;
 
-use super::get_author_identity;
-
-#[derive(Debug, Serialize, Deserialize)]
+use super::{
+    add_trailing_comment, check_contributor, get_author_identity, label_case_collisions,
+    labels_normalized, lint_description_enabled, normalize_label, normalize_new_labels,
+    read_description_from, resolve_assignee, strict_email_enabled, validate_email,
+    warn_description_lint, warn_duplicate_titles,
+};
+
+/// The fields of an issue as edited in YAML
+///
+/// Every key may be left out so that `validate_editable_issue` can say which
+/// required one is missing, rather than serde failing on the first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 struct EditableIssue {
-    title: String,
-    status: String,
-    priority: String,
+    title: Option<String>,
+    status: Option<String>,
+    priority: Option<String>,
     labels: Vec<String>,
     assignee: Option<String>,
     description: String,
 }
 
+impl EditableIssue {
+    fn title(&self) -> &str {
+        self.title.as_deref().unwrap_or_default()
+    }
+
+    fn status(&self) -> &str {
+        self.status.as_deref().unwrap_or_default()
+    }
+
+    fn priority(&self) -> &str {
+        self.priority.as_deref().unwrap_or_default()
+    }
+}
+
 #[derive(Args)]
 pub struct EditArgs {
-    /// Issue ID to edit
-    pub id: IssueId,
+    /// Issue ID or slug to edit
+    pub id: String,
 
     /// Set title directly (for programmatic access)
     #[arg(short = 't', long)]
@@ -33,6 +58,10 @@ pub struct EditArgs {
     #[arg(short = 'd', long)]
     pub description: Option<String>,
 
+    /// Read the description from a markdown file, optionally limited to a section (`file.md#Heading`)
+    #[arg(long, value_name = "FILE[#HEADING]", conflicts_with = "description")]
+    pub description_from: Option<String>,
+
     /// Set status directly (for programmatic access)
     #[arg(short = 's', long)]
     pub status: Option<String>,
@@ -53,6 +82,15 @@ pub struct EditArgs {
     #[arg(long)]
     pub no_editor: bool,
 
+    /// Apply a JSON object of fields to change (title, status, priority, labels,
+    /// assignee, description) from a file, or `-` for stdin
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["title", "description", "description_from", "status", "add_label", "remove_label", "assignee", "priority", "no_editor"]
+    )]
+    pub from_json: Option<String>,
+
     /// Set creator directly (for programmatic access)
     #[arg(short = 'c', long)]
     pub creator: Option<String>,
@@ -60,65 +98,238 @@ pub struct EditArgs {
     /// Set priority directly (for programmatic access)
     #[arg(short = 'p', long)]
     pub priority: Option<Priority>,
+
+    /// Fail instead of warning when an added label differs only in case from another label
+    #[arg(long)]
+    pub strict_labels: bool,
+
+    /// Require a full `local@domain.tld` assignee address instead of anything with an `@`
+    #[arg(long)]
+    pub strict_email: bool,
+
+    /// Warn about unbalanced code fences and broken reference links in the description
+    #[arg(long)]
+    pub lint_description: bool,
+
+    /// Assign even if the assignee is missing from `.git-issue/contributors.toml`
+    #[arg(long)]
+    pub force: bool,
+
+    /// Skip the warning about open issues with the same title as a changed title
+    #[arg(long)]
+    pub no_dup_check: bool,
+
+    /// Comment to add alongside the change, given after `--`
+    #[arg(last = true, value_name = "COMMENT")]
+    pub comment: Vec<String>,
 }
 
-pub fn handle_edit(repo_path: std::path::PathBuf, args: EditArgs) -> Result<()> {
+pub fn handle_edit(repo_path: std::path::PathBuf, mut args: EditArgs) -> Result<()> {
     let mut store = IssueStore::open(&repo_path)?;
+    let issue_id = store.resolve_ref(&args.id)?;
+
+    if let Some(spec) = args.description_from.take() {
+        args.description = Some(read_description_from(&spec)?);
+    }
     let author = get_author_identity(None, None, &store, SystemEnvProvider)?;
 
-    // Get the current issue
-    let current_issue = store.get_issue(args.id)?;
+    // The editor may stay open for a while, so it runs without the lock; the
+    // other paths lock before reading, so nothing can change under them
+    let interactive = args.from_json.is_none() && !args.no_editor;
+    let early_lock = if interactive {
+        None
+    } else {
+        Some(store.lock_for_writing()?)
+    };
 
-    let editable_issue = if args.no_editor {
+    // Get the current issue
+    let current_issue = store.get_issue(issue_id)?;
+    let current_head = store.get_issue_head_commit(issue_id)?;
+    let normalize = labels_normalized(&store);
+
+    let editable_issue = if let Some(source) = &args.from_json {
+        // Programmatic mode - apply a JSON patch to the current fields
+        let patch = read_json_patch(source)?;
+        apply_json_patch(&current_issue, &patch, &author.email, normalize)?
+    } else if args.no_editor {
         // Programmatic mode - apply CLI arguments directly
-        apply_cli_edits(&current_issue, &args, &author.email)?
+        apply_cli_edits(&current_issue, &args, &author.email, normalize)?
     } else {
-        // Interactive editor mode
-        edit_with_editor(&current_issue, &args, &author.email)?
+        // Interactive editor mode, falling back to prompts when no editor is available
+        match edit_with_editor(&current_issue, &author.email, normalize) {
+            Err(err) if is_missing_editor(&err) => {
+                eprintln!("No editor found; prompting for each field instead.");
+                prompt_for_fields(
+                    &current_issue,
+                    &author.email,
+                    normalize,
+                    &mut std::io::stdin().lock(),
+                    &mut std::io::stderr(),
+                )?
+            }
+            result => result?,
+        }
+    };
+
+    if let Some(email) = &editable_issue.assignee
+        && !email.is_empty()
+    {
+        validate_email(email, strict_email_enabled(args.strict_email, &store))?;
+
+        // Only a changed assignee is checked, so older assignees do not block edits
+        let unchanged = current_issue
+            .assignees
+            .first()
+            .is_some_and(|current| current.has_email(email));
+        if !unchanged {
+            let contributors = store.contributors()?;
+            check_contributor(contributors.as_ref(), email, args.force)?;
+        }
+    }
+
+    if !args.no_dup_check && editable_issue.title().trim() != current_issue.title {
+        warn_duplicate_titles(&store, editable_issue.title(), Some(issue_id))?;
+    }
+
+    if editable_issue.description != current_issue.description
+        && lint_description_enabled(args.lint_description, &store)
+    {
+        warn_description_lint(&editable_issue.description);
+    }
+
+    let added_labels: Vec<String> = editable_issue
+        .labels
+        .iter()
+        .filter(|label| !current_issue.labels.contains(label))
+        .cloned()
+        .collect();
+    let collisions = label_case_collisions(&added_labels, &editable_issue.labels);
+    if args.strict_labels && !collisions.is_empty() {
+        return Err(anyhow::anyhow!("{}", collisions.join("; ")));
+    }
+    for collision in &collisions {
+        println!("{}", warning_message(collision));
+    }
+
+    let _lock = match early_lock {
+        Some(lock) => lock,
+        None => {
+            let lock = store.lock_for_writing()?;
+            // Applying edits made to an outdated issue would revert the newer changes
+            if store.get_issue_head_commit(issue_id)? != current_head {
+                anyhow::bail!(
+                    "Issue #{} changed while it was being edited; run edit again to start from the latest version",
+                    issue_id
+                );
+            }
+            lock
+        }
     };
 
     // Apply changes with change detection
     apply_changes(
         &mut store,
-        args.id,
+        issue_id,
         &current_issue,
         &editable_issue,
-        author,
+        author.clone(),
         &args,
     )?;
 
-    Ok(())
+    add_trailing_comment(&mut store, issue_id, &args.comment, author)
 }
 
-fn apply_cli_edits(
-    current_issue: &crate::common::Issue,
-    args: &EditArgs,
-    _author_email: &str,
-) -> Result<EditableIssue> {
-    let mut editable = EditableIssue {
-        title: current_issue.title.clone(),
-        status: current_issue.status.to_string(),
-        priority: current_issue.priority.to_string(),
+/// The current fields of an issue, before any edits
+fn current_editable(current_issue: &crate::common::Issue) -> EditableIssue {
+    EditableIssue {
+        title: Some(current_issue.title.clone()),
+        status: Some(current_issue.status.to_string()),
+        priority: Some(current_issue.priority.to_string()),
         labels: current_issue.labels.clone(),
         assignee: current_issue.assignees.first().map(|a| a.email.clone()),
         description: current_issue.description.clone(),
+    }
+}
+
+/// Read a JSON patch from a file, or from stdin when `source` is `-`
+fn read_json_patch(source: &str) -> Result<String> {
+    if source == "-" {
+        let mut patch = String::new();
+        std::io::stdin().read_to_string(&mut patch)?;
+        return Ok(patch);
+    }
+    fs::read_to_string(source).map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", source, e))
+}
+
+/// Overlay a JSON object of editable fields onto the current issue
+///
+/// Fields left out of the patch keep their current value; unknown fields are rejected.
+fn apply_json_patch(
+    current_issue: &crate::common::Issue,
+    patch: &str,
+    author_email: &str,
+    normalize_labels: bool,
+) -> Result<EditableIssue> {
+    let patch: serde_json::Value = serde_json::from_str(patch)
+        .map_err(|e| anyhow::anyhow!("Failed to parse JSON patch: {}", e))?;
+    let serde_json::Value::Object(patch) = patch else {
+        anyhow::bail!("JSON patch must be an object of fields to change");
+    };
+    let serde_json::Value::Object(mut fields) =
+        serde_json::to_value(current_editable(current_issue))?
+    else {
+        anyhow::bail!("Issue #{} did not serialize to an object", current_issue.id);
     };
 
+    for (key, value) in patch {
+        if !fields.contains_key(&key) {
+            anyhow::bail!(
+                "Unknown field '{}' in JSON patch. Valid fields: {}",
+                key,
+                fields.keys().cloned().collect::<Vec<_>>().join(", ")
+            );
+        }
+        fields.insert(key, value);
+    }
+
+    let mut editable: EditableIssue = serde_json::from_value(serde_json::Value::Object(fields))
+        .map_err(|e| anyhow::anyhow!("Invalid JSON patch: {}", e))?;
+    editable.assignee = editable
+        .assignee
+        .map(|assignee| resolve_assignee(&assignee, author_email));
+    normalize_new_labels(
+        &mut editable.labels,
+        &current_issue.labels,
+        normalize_labels,
+    );
+
+    validate_editable_issue(&editable)?;
+    Ok(editable)
+}
+
+fn apply_cli_edits(
+    current_issue: &crate::common::Issue,
+    args: &EditArgs,
+    author_email: &str,
+    normalize_labels: bool,
+) -> Result<EditableIssue> {
+    let mut editable = current_editable(current_issue);
+
     // Apply CLI overrides
     if let Some(ref title) = args.title {
-        editable.title = title.clone();
+        editable.title = Some(title.clone());
     }
     if let Some(ref description) = args.description {
         editable.description = description.clone();
     }
     if let Some(ref status) = args.status {
-        editable.status = status.clone();
+        editable.status = Some(status.clone());
     }
     if let Some(ref assignee) = args.assignee {
-        editable.assignee = Some(assignee.clone());
+        editable.assignee = Some(resolve_assignee(assignee, author_email));
     }
     if let Some(priority) = args.priority {
-        editable.priority = priority.to_string();
+        editable.priority = Some(priority.to_string());
     }
 
     // Handle labels
@@ -128,8 +339,16 @@ fn apply_cli_edits(
     }
     for label in &args.remove_label {
         labels_set.remove(label.trim());
+        if normalize_labels {
+            labels_set.remove(&normalize_label(label));
+        }
     }
     editable.labels = labels_set.into_iter().collect();
+    normalize_new_labels(
+        &mut editable.labels,
+        &current_issue.labels,
+        normalize_labels,
+    );
     editable.labels.sort();
 
     validate_editable_issue(&editable)?;
@@ -138,8 +357,8 @@ fn apply_cli_edits(
 
 fn edit_with_editor(
     current_issue: &crate::common::Issue,
-    _args: &EditArgs,
-    _author_email: &str,
+    author_email: &str,
+    normalize_labels: bool,
 ) -> Result<EditableIssue> {
     // Create default template with current issue or template values
     let template = create_template(current_issue);
@@ -155,16 +374,124 @@ fn edit_with_editor(
     edit::edit_file(temp_file.path())?;
 
     // Read edited content
-    let edited_content = fs::read_to_string(temp_file.path())?;
+    let edited_content = read_edited_file(temp_file)?;
 
     // Parse YAML
-    let editable: EditableIssue = serde_yaml::from_str(&edited_content)
+    let mut editable: EditableIssue = serde_yaml::from_str(&edited_content)
         .map_err(|e| anyhow::anyhow!("Failed to parse YAML: {}", e))?;
+    editable.assignee = editable
+        .assignee
+        .map(|assignee| resolve_assignee(&assignee, author_email));
+    normalize_new_labels(
+        &mut editable.labels,
+        &current_issue.labels,
+        normalize_labels,
+    );
 
     validate_editable_issue(&editable)?;
     Ok(editable)
 }
 
+/// Read back the file the editor saved
+///
+/// If it is not valid UTF-8 the file is kept rather than deleted, and the error
+/// names it so the edits can be recovered.
+fn read_edited_file(temp_file: tempfile::NamedTempFile) -> Result<String> {
+    let bytes = fs::read(temp_file.path())?;
+    match String::from_utf8(bytes) {
+        Ok(content) => Ok(content),
+        Err(err) => {
+            let offset = err.utf8_error().valid_up_to();
+            let (_, path) = temp_file.keep()?;
+            Err(anyhow::anyhow!(
+                "Edited file was not valid UTF-8 (at byte {}); your edits were kept in {}",
+                offset,
+                path.display()
+            ))
+        }
+    }
+}
+
+/// Whether an editor error means no editor could be found or started
+fn is_missing_editor(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<std::io::Error>()
+        .is_some_and(|e| e.kind() == std::io::ErrorKind::NotFound)
+}
+
+/// Ask for each field on `output`, reading answers line by line from `input`
+///
+/// An empty answer keeps the current value. Labels are comma-separated, and
+/// `-` clears the labels or the assignee.
+fn prompt_for_fields(
+    issue: &crate::common::Issue,
+    author_email: &str,
+    normalize_labels: bool,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<EditableIssue> {
+    let current_labels = issue.labels.join(",");
+    let current_assignee = issue
+        .assignees
+        .first()
+        .map(|a| a.email.clone())
+        .unwrap_or_default();
+
+    let title = prompt(input, output, "Title", &issue.title)?;
+    let status = prompt(input, output, "Status", &issue.status.to_string())?;
+    let priority = prompt(input, output, "Priority", &issue.priority.to_string())?;
+    let labels = prompt(input, output, "Labels", &current_labels)?;
+    let assignee = prompt(input, output, "Assignee", &current_assignee)?;
+    let description = prompt(input, output, "Description", &issue.description)?;
+
+    let labels = if labels == "-" {
+        Vec::new()
+    } else {
+        labels
+            .split(',')
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect()
+    };
+    let assignee = match assignee.as_str() {
+        "-" | "" => None,
+        assignee => Some(resolve_assignee(assignee, author_email)),
+    };
+
+    let mut editable = EditableIssue {
+        title: Some(title),
+        status: Some(status),
+        priority: Some(priority),
+        labels,
+        assignee,
+        description,
+    };
+    normalize_new_labels(&mut editable.labels, &issue.labels, normalize_labels);
+    validate_editable_issue(&editable)?;
+    Ok(editable)
+}
+
+/// Prompt for a single field, returning `current` when the answer is empty
+fn prompt(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    field: &str,
+    current: &str,
+) -> Result<String> {
+    write!(output, "{} [{}]: ", field, current)?;
+    output.flush()?;
+
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    let answer = answer.trim();
+
+    Ok(if answer.is_empty() {
+        current.to_string()
+    } else {
+        answer.to_string()
+    })
+}
+
 fn create_template(issue: &crate::common::Issue) -> String {
     format!(
         r#"# Edit the fields below. Save and close to apply changes.
@@ -207,17 +534,28 @@ description: |
 }
 
 fn validate_editable_issue(editable: &EditableIssue) -> Result<()> {
+    // Required keys must not have been deleted in the editor
+    for (key, value) in [
+        ("title", &editable.title),
+        ("status", &editable.status),
+        ("priority", &editable.priority),
+    ] {
+        if value.is_none() {
+            return Err(anyhow::anyhow!("{} is required", key));
+        }
+    }
+
     // Title must be non-empty after trimming
-    if editable.title.trim().is_empty() {
+    if editable.title().trim().is_empty() {
         return Err(anyhow::anyhow!("Title cannot be empty"));
     }
 
     // Status must be valid
-    editable.status.parse::<IssueStatus>()?;
+    editable.status().parse::<IssueStatus>()?;
 
     // Priority must be valid
     editable
-        .priority
+        .priority()
         .parse::<Priority>()
         .map_err(|e| anyhow::anyhow!("Invalid priority: {}", e))?;
 
@@ -238,11 +576,11 @@ fn validate_editable_issue(editable: &EditableIssue) -> Result<()> {
         }
     }
 
-    // Assignee email format (basic check)
-    if let Some(ref email) = editable.assignee {
-        if !email.is_empty() && !email.contains('@') {
-            return Err(anyhow::anyhow!("Invalid email format: {}", email));
-        }
+    // Assignee email format (basic check; `--strict-email` is applied by the caller)
+    if let Some(ref email) = editable.assignee
+        && !email.is_empty()
+    {
+        validate_email(email, false)?;
     }
 
     Ok(())
@@ -259,7 +597,7 @@ fn apply_changes(
     let mut changes = Vec::new();
 
     // Check title change
-    let new_title = edited.title.trim().to_string();
+    let new_title = edited.title().trim().to_string();
     if original.title != new_title {
         store.update_title(issue_id, new_title.clone(), author.clone())?;
         changes.push(format!("Title: \"{}\" → \"{}\"", original.title, new_title));
@@ -280,7 +618,7 @@ fn apply_changes(
     }
 
     // Check status change
-    let new_status = edited.status.parse::<IssueStatus>()?;
+    let new_status = edited.status().parse::<IssueStatus>()?;
     if original.status != new_status {
         store.update_issue_status(issue_id, new_status, author.clone())?;
         changes.push(format!("Status: {} → {}", original.status, new_status));
@@ -288,7 +626,7 @@ fn apply_changes(
 
     // Check priority change
     let new_priority = edited
-        .priority
+        .priority()
         .parse::<Priority>()
         .map_err(|e| anyhow::anyhow!("Invalid priority: {}", e))?;
     if original.priority != new_priority {
@@ -300,13 +638,14 @@ fn apply_changes(
     }
 
     // Check creator change
-    if let Some(ref creator_email) = args.creator {
-        let new_creator = Identity::new("", creator_email);
-        if original.created_by != new_creator {
+    if let Some(ref creator) = args.creator {
+        let new_creator = parse_creator(creator);
+        if original.created_by.email != new_creator.email {
+            let new_email = new_creator.email.clone();
             store.update_created_by(issue_id, new_creator, author.clone())?;
             changes.push(format!(
                 "Creator: {} → {}",
-                original.created_by.email, creator_email
+                original.created_by.email, new_email
             ));
         }
     }
@@ -321,7 +660,13 @@ fn apply_changes(
     });
     let current_assignee = original.assignees.first();
 
-    if current_assignee != new_assignee.as_ref() {
+    let assignee_unchanged = match (current_assignee, &new_assignee) {
+        (Some(current), Some(new)) => current.same_person(new),
+        (None, None) => true,
+        _ => false,
+    };
+
+    if !assignee_unchanged {
         store.update_assignee(issue_id, new_assignee.clone(), author.clone())?;
         let assignee_change = match (current_assignee, &edited.assignee) {
             (None, Some(email)) => format!("Assignee: assigned to {}", email),
@@ -380,6 +725,17 @@ fn apply_changes(
     Ok(())
 }
 
+/// Parse a creator given either as a bare email or as "Name <email>"
+fn parse_creator(creator: &str) -> Identity {
+    let creator = creator.trim();
+    if let Some((name, rest)) = creator.split_once('<')
+        && let Some(email) = rest.strip_suffix('>')
+    {
+        return Identity::new(name.trim(), email.trim());
+    }
+    Identity::new("", creator)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -490,7 +846,7 @@ mod tests {
                 false
             }
         })
-        .expect(&format!("Should have LabelAdded event for '{}'", label));
+        .unwrap_or_else(|| panic!("Should have LabelAdded event for '{}'", label));
 
         if let IssueEvent::LabelAdded {
             label: l,
@@ -514,7 +870,7 @@ mod tests {
                 false
             }
         })
-        .expect(&format!("Should have LabelRemoved event for '{}'", label));
+        .unwrap_or_else(|| panic!("Should have LabelRemoved event for '{}'", label));
 
         if let IssueEvent::LabelRemoved {
             label: l,
@@ -613,16 +969,24 @@ mod tests {
         let author = create_test_identity();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: Some("Updated Title".to_string()),
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -646,16 +1010,24 @@ mod tests {
         let author = create_test_identity();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: Some("Updated description".to_string()),
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -688,16 +1060,24 @@ mod tests {
         let author = create_test_identity();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: Some("in-progress".to_string()),
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -724,16 +1104,24 @@ mod tests {
         let author = create_test_identity();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: vec!["bug".to_string()],
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -756,16 +1144,24 @@ mod tests {
         let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: vec!["bug".to_string(), "feature".to_string()],
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(create_test_identity().to_string()),
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -794,16 +1190,24 @@ mod tests {
             .expect("Should add label");
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: vec!["bug".to_string()],
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -847,16 +1251,24 @@ mod tests {
             .expect("Should add label");
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: vec!["new-label".to_string()],
             remove_label: vec!["old-label".to_string()],
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -870,22 +1282,62 @@ mod tests {
         assert_eq!(issue.labels.len(), 1);
     }
 
+    #[test]
+    fn test_apply_cli_edits_resolves_self_assignee() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+
+        let args = EditArgs {
+            id: issue_id.to_string(),
+            title: None,
+            description: None,
+            description_from: None,
+            status: None,
+            add_label: Vec::new(),
+            remove_label: Vec::new(),
+            assignee: Some("@me".to_string()),
+            no_editor: true,
+            from_json: None,
+            creator: None,
+            priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
+        };
+
+        let editable =
+            apply_cli_edits(&issue, &args, "me@example.com", false).expect("Edits should apply");
+        assert_eq!(editable.assignee.as_deref(), Some("me@example.com"));
+    }
+
     #[test]
     fn test_edit_assign_user() {
         let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
         let author = create_test_identity();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: Some("assignee@example.com".to_string()),
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -914,16 +1366,24 @@ mod tests {
         let author = create_test_identity();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: Some("New Title".to_string()),
             description: Some("New description".to_string()),
+            description_from: None,
             status: Some("done".to_string()),
             add_label: vec!["enhancement".to_string()],
             remove_label: Vec::new(),
             assignee: Some("developer@example.com".to_string()),
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -963,16 +1423,24 @@ mod tests {
         let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(create_test_identity().to_string()),
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1002,16 +1470,24 @@ mod tests {
         let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: Some("Original Title".to_string()), // Same as current
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(create_test_identity().to_string()),
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1028,16 +1504,24 @@ mod tests {
         let (_temp_dir, repo_path, _issue_id) = setup_temp_edit_repo();
 
         let args = EditArgs {
-            id: 9999, // Non-existent issue
+            id: "9999".to_string(), // Non-existent issue
             title: Some("Should Fail".to_string()),
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(create_test_identity().to_string()),
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path, args);
@@ -1047,9 +1531,9 @@ mod tests {
     #[test]
     fn test_validate_editable_issue_empty_title() {
         let editable = EditableIssue {
-            title: "".to_string(),
-            status: "todo".to_string(),
-            priority: "none".to_string(),
+            title: Some("".to_string()),
+            status: Some("todo".to_string()),
+            priority: Some("none".to_string()),
             labels: Vec::new(),
             assignee: None,
             description: "Description".to_string(),
@@ -1065,12 +1549,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_editable_issue_missing_title() {
+        let yaml = "status: todo\npriority: none\nlabels: []\ndescription: Body\n";
+        let editable: EditableIssue =
+            serde_yaml::from_str(yaml).expect("YAML without a title should still parse");
+
+        let result = validate_editable_issue(&editable);
+        assert_eq!(result.unwrap_err().to_string(), "title is required");
+    }
+
     #[test]
     fn test_validate_editable_issue_label_with_spaces() {
         let editable = EditableIssue {
-            title: "Valid Title".to_string(),
-            status: "todo".to_string(),
-            priority: "none".to_string(),
+            title: Some("Valid Title".to_string()),
+            status: Some("todo".to_string()),
+            priority: Some("none".to_string()),
             labels: vec!["label with spaces".to_string()],
             assignee: None,
             description: "Description".to_string(),
@@ -1084,9 +1578,9 @@ mod tests {
     #[test]
     fn test_validate_editable_issue_invalid_status() {
         let editable = EditableIssue {
-            title: "Valid Title".to_string(),
-            status: "invalid-status".to_string(),
-            priority: "none".to_string(),
+            title: Some("Valid Title".to_string()),
+            status: Some("invalid-status".to_string()),
+            priority: Some("none".to_string()),
             labels: Vec::new(),
             assignee: None,
             description: "Description".to_string(),
@@ -1100,9 +1594,9 @@ mod tests {
     #[test]
     fn test_validate_editable_issue_invalid_email() {
         let editable = EditableIssue {
-            title: "Valid Title".to_string(),
-            status: "todo".to_string(),
-            priority: "none".to_string(),
+            title: Some("Valid Title".to_string()),
+            status: Some("todo".to_string()),
+            priority: Some("none".to_string()),
             labels: Vec::new(),
             assignee: Some("not-an-email".to_string()),
             description: "Description".to_string(),
@@ -1121,9 +1615,9 @@ mod tests {
     #[test]
     fn test_validate_editable_issue_valid() {
         let editable = EditableIssue {
-            title: "Valid Title".to_string(),
-            status: "in-progress".to_string(),
-            priority: "high".to_string(),
+            title: Some("Valid Title".to_string()),
+            status: Some("in-progress".to_string()),
+            priority: Some("high".to_string()),
             labels: vec!["bug".to_string(), "urgent".to_string()],
             assignee: Some("user@example.com".to_string()),
             description: "Valid description".to_string(),
@@ -1153,16 +1647,24 @@ mod tests {
 
         // Step 2: Perform comprehensive edit with multiple changes
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: Some("Comprehensive Test Title".to_string()),
             description: Some("Comprehensive test description with detailed info".to_string()),
+            description_from: None,
             status: Some("in-progress".to_string()),
             add_label: vec!["new-feature".to_string(), "tested".to_string()],
             remove_label: vec!["old-label".to_string()],
             assignee: Some("new@example.com".to_string()),
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1248,16 +1750,24 @@ mod tests {
         let author = create_test_identity();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: None,
             priority: Some(Priority::High),
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1283,16 +1793,24 @@ mod tests {
         let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(create_test_identity().to_string()),
             priority: Some(Priority::None), // Same as default
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1316,9 +1834,9 @@ mod tests {
     #[test]
     fn test_validate_editable_issue_invalid_priority() {
         let editable = EditableIssue {
-            title: "Valid Title".to_string(),
-            status: "todo".to_string(),
-            priority: "invalid-priority".to_string(),
+            title: Some("Valid Title".to_string()),
+            status: Some("todo".to_string()),
+            priority: Some("invalid-priority".to_string()),
             labels: Vec::new(),
             assignee: None,
             description: "Description".to_string(),
@@ -1334,16 +1852,24 @@ mod tests {
         let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: Some("Multi Change Title".to_string()),
             description: Some("Multi change description".to_string()),
+            description_from: None,
             status: Some("in-progress".to_string()),
             add_label: vec!["test-label".to_string()],
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(create_test_identity().to_string()),
             priority: Some(Priority::Medium),
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1444,16 +1970,24 @@ mod tests {
         let new_creator_email = "new-creator@example.com";
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(new_creator_email.to_string()),
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1483,16 +2017,24 @@ mod tests {
         let author = create_test_identity();
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: None,
             description: None,
+            description_from: None,
             status: None,
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(author.email.clone()), // Same as current
             priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1520,16 +2062,24 @@ mod tests {
         let new_creator_email = "another-creator@example.com";
 
         let args = EditArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             title: Some("Updated Title".to_string()),
             description: None,
+            description_from: None,
             status: Some("in-progress".to_string()),
             add_label: Vec::new(),
             remove_label: Vec::new(),
             assignee: None,
             no_editor: true,
+            from_json: None,
             creator: Some(new_creator_email.to_string()),
             priority: Some(Priority::High),
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
         };
 
         let result = handle_edit(repo_path.clone(), args);
@@ -1559,4 +2109,155 @@ mod tests {
         let new_creator = Identity::new("", new_creator_email);
         assert_created_by_changed_event(&events, &old_creator, &new_creator, &author);
     }
+
+    #[test]
+    fn test_prompt_for_fields_with_scripted_input() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+
+        // Keep the title, change status, priority and labels, assign self, keep description
+        let mut input = std::io::Cursor::new("\nin-progress\nhigh\nbug, ui\n@me\n\n");
+        let mut output = Vec::new();
+        let editable = prompt_for_fields(&issue, "me@example.com", false, &mut input, &mut output)
+            .expect("Prompts should succeed");
+
+        assert_eq!(editable.title(), issue.title);
+        assert_eq!(editable.status(), "in-progress");
+        assert_eq!(editable.priority(), "high");
+        assert_eq!(editable.labels, vec!["bug", "ui"]);
+        assert_eq!(editable.assignee.as_deref(), Some("me@example.com"));
+        assert_eq!(editable.description, issue.description);
+
+        let prompts = String::from_utf8(output).unwrap();
+        assert!(prompts.starts_with(&format!("Title [{}]: ", issue.title)));
+        assert!(prompts.contains("Status [todo]: "));
+    }
+
+    #[test]
+    fn test_prompt_for_fields_rejects_invalid_status() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+
+        let mut input = std::io::Cursor::new("\nbogus\n\n\n\n\n");
+        let result =
+            prompt_for_fields(&issue, "me@example.com", false, &mut input, &mut Vec::new());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_is_missing_editor() {
+        let not_found = anyhow::Error::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert!(is_missing_editor(&not_found));
+        assert!(!is_missing_editor(&anyhow::anyhow!("Failed to parse YAML")));
+    }
+
+    #[test]
+    fn test_edit_from_json_patch() {
+        let (temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
+        let author = create_test_identity();
+        let patch_path = temp_dir.path().join("patch.json");
+        std::fs::write(
+            &patch_path,
+            r#"{"title": "Patched Title", "status": "in-progress"}"#,
+        )
+        .unwrap();
+
+        let args = EditArgs {
+            id: issue_id.to_string(),
+            title: None,
+            description: None,
+            description_from: None,
+            status: None,
+            add_label: Vec::new(),
+            remove_label: Vec::new(),
+            assignee: None,
+            no_editor: false,
+            from_json: Some(patch_path.display().to_string()),
+            creator: None,
+            priority: None,
+            strict_labels: false,
+            strict_email: false,
+            lint_description: false,
+            force: false,
+            no_dup_check: false,
+            comment: vec![],
+        };
+        handle_edit(repo_path.clone(), args).expect("JSON patch should apply");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let events = get_issue_events(&store, issue_id);
+        assert_eq!(events.len(), 3, "Should have Created + two change events");
+        assert_title_changed_event(&events, "Original Title", "Patched Title", &author);
+        assert_status_changed_event(&events, IssueStatus::Todo, IssueStatus::InProgress, &author);
+
+        // Fields left out of the patch are untouched
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(issue.description, "Original description");
+
+        let err = apply_json_patch(&issue, r#"{"titel": "Typo"}"#, &author.email, false)
+            .expect_err("Unknown fields should be rejected");
+        assert!(err.to_string().contains("Unknown field 'titel'"));
+    }
+
+    #[test]
+    fn test_new_labels_are_normalized_on_every_edit_path() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_edit_repo();
+        let mut store = IssueStore::open(&repo_path).expect("Should open store");
+        let author = create_test_identity();
+        store
+            .add_label(issue_id, "Legacy".to_string(), author.clone())
+            .expect("Should add label");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+
+        let patch = r#"{"labels": ["Legacy", "Bug Fix"]}"#;
+        let editable = apply_json_patch(&issue, patch, &author.email, true)
+            .expect("Patched labels should be normalized before validation");
+        assert_eq!(editable.labels, vec!["Legacy", "bug-fix"]);
+
+        let mut input = "\n\n\nLegacy, UI/UX\n\n\n".as_bytes();
+        let editable = prompt_for_fields(&issue, &author.email, true, &mut input, &mut Vec::new())
+            .expect("Prompted labels should be normalized before validation");
+        assert_eq!(editable.labels, vec!["Legacy", "ui-ux"]);
+
+        std::process::Command::new("git")
+            .args(["config", "labels.normalize", "true"])
+            .current_dir(&repo_path)
+            .output()
+            .expect("Failed to set git config");
+        let repo = repo_path.to_str().unwrap();
+        let id = issue_id.to_string();
+        let cli = <crate::cli::Cli as clap::Parser>::parse_from([
+            "git-issue",
+            "--repo",
+            repo,
+            "edit",
+            &id,
+            "--no-editor",
+            "--add-label",
+            "Needs Review",
+        ]);
+        crate::cli::run_command(cli).expect("Edit should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(issue.labels, vec!["Legacy", "needs-review"]);
+    }
+
+    #[test]
+    fn test_read_edited_file_keeps_invalid_utf8() {
+        let mut temp_file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+        temp_file.write_all(b"title: caf\xe9\n").unwrap();
+        let path = temp_file.path().to_path_buf();
+
+        let err = read_edited_file(temp_file).expect_err("Invalid UTF-8 should be rejected");
+        let message = err.to_string();
+        assert!(message.contains("not valid UTF-8 (at byte 10)"));
+        assert!(message.contains(&path.display().to_string()));
+
+        // The edits are still there to recover
+        assert_eq!(std::fs::read(&path).unwrap(), b"title: caf\xe9\n");
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use std::fmt::Write;
+
+use crate::common::EnvProvider;
+use crate::storage::IssueStore;
+
+use super::ASSUME_YES_VAR;
+use super::get_author_identity;
+use super::sync::determine_target_remote;
+
+/// Config keys that change how commands behave
+const EXPLAINED_CONFIG: [&str; 8] = [
+    "list.defaultStatuses",
+    "labels.normalize",
+    "issue.url",
+    "issue.commitMessage",
+    "validation.strictEmail",
+    "compat.singleAssignee",
+    "create.assignSelf",
+    "storage.maxEventSize",
+];
+
+/// Describe how the repository, identity, remote and config resolve for `repo_path`
+///
+/// This is what `--explain` prints instead of running the command.
+pub(crate) fn explain_resolution(
+    repo_path: &std::path::Path,
+    assume_yes: bool,
+    env_provider: impl EnvProvider,
+) -> Result<String> {
+    let store = IssueStore::open(repo_path)?;
+    let mut report = String::new();
+
+    writeln!(report, "Repository: {}", store.path().display())?;
+
+    let author = get_author_identity(None, None, &store, env_provider)?;
+    writeln!(report, "Identity: {} <{}>", author.name, author.email)?;
+
+    match determine_target_remote(&store, None) {
+        Ok(remote) if store.remote_exists(&remote)? => writeln!(report, "Remote: {}", remote)?,
+        Ok(remote) => writeln!(report, "Remote: {} (not configured)", remote)?,
+        Err(err) => writeln!(report, "Remote: none ({})", err)?,
+    }
+
+    writeln!(
+        report,
+        "Assume yes: {} (--yes or {})",
+        assume_yes, ASSUME_YES_VAR
+    )?;
+
+    writeln!(report, "Config:")?;
+    for key in EXPLAINED_CONFIG {
+        let value = store.get_config(key);
+        writeln!(
+            report,
+            "  {} = {}",
+            key,
+            value.as_deref().unwrap_or("(unset)")
+        )?;
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::MockEnvProvider;
+    use crate::storage::test_helpers::*;
+
+    #[test]
+    fn test_explain_resolution() {
+        let (temp_dir, _repo) = setup_temp_repo();
+        let mut env = MockEnvProvider::new();
+        env.set_var("GIT_AUTHOR_NAME", "Explained User")
+            .set_var("GIT_AUTHOR_EMAIL", "explained@example.com");
+
+        let report = explain_resolution(temp_dir.path(), false, env).unwrap();
+
+        let repo_path = IssueStore::open(temp_dir.path()).unwrap().path().to_owned();
+        assert!(report.contains(&format!("Repository: {}", repo_path.display())));
+        assert!(report.contains("Identity: Explained User <explained@example.com>"));
+        assert!(report.contains("Remote: origin (not configured)"));
+        assert!(report.contains("labels.normalize = (unset)"));
+    }
+}
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use clap::Args;
+use console::style;
+
+use crate::storage::IssueStore;
+
+#[derive(Args)]
+pub struct FeedArgs {
+    /// Only show the most recent N entries
+    #[arg(short = 'n', long)]
+    pub limit: Option<usize>,
+}
+
+pub fn handle_feed(repo_path: std::path::PathBuf, args: FeedArgs) -> Result<()> {
+    let store = IssueStore::open(&repo_path)?;
+    let mut activity = store.latest_activity()?;
+
+    if let Some(limit) = args.limit {
+        activity.drain(..activity.len().saturating_sub(limit));
+    }
+
+    for (issue_id, event) in activity {
+        println!(
+            "{} {} {} ({})",
+            style(event.timestamp().format("%Y-%m-%d %H:%M")).dim(),
+            style(format!("#{}", issue_id)).cyan(),
+            event.summary(),
+            event.author().name
+        );
+    }
+    Ok(())
+}
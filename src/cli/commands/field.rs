@@ -0,0 +1,136 @@
+use anyhow::Result;
+use clap::Args;
+
+use crate::cli::output::{success_message, warning_message};
+use crate::common::SystemEnvProvider;
+use crate::storage::IssueStore;
+
+use super::get_author_identity;
+
+#[derive(Args)]
+pub struct FieldArgs {
+    /// Issue ID or slug
+    pub id: String,
+
+    /// Field name, e.g. "severity"
+    pub key: String,
+
+    /// New value; prints the current value when omitted
+    #[arg(conflicts_with = "unset")]
+    pub value: Option<String>,
+
+    /// Remove the field from the issue
+    #[arg(long)]
+    pub unset: bool,
+
+    /// Author name (defaults to git config)
+    #[arg(long)]
+    pub author_name: Option<String>,
+
+    /// Author email (defaults to git config)
+    #[arg(long)]
+    pub author_email: Option<String>,
+}
+
+/// Field names must be usable in `list --field key=value`
+fn validate_field_key(key: &str) -> Result<()> {
+    if key.is_empty() || key.contains('=') || key.chars().any(char::is_whitespace) {
+        anyhow::bail!(
+            "Invalid field name '{}': it must be non-empty without '=' or whitespace",
+            key
+        );
+    }
+    Ok(())
+}
+
+pub fn handle_field(repo_path: std::path::PathBuf, args: FieldArgs) -> Result<()> {
+    validate_field_key(&args.key)?;
+    let mut store = IssueStore::open(&repo_path)?;
+
+    if args.value.is_none() && !args.unset {
+        let issue_id = store.resolve_ref(&args.id)?;
+        let issue = store.get_issue(issue_id)?;
+        match issue.fields.get(&args.key) {
+            Some(value) => println!("{}", value),
+            None => anyhow::bail!("Issue #{} has no field '{}'", issue_id, args.key),
+        }
+        return Ok(());
+    }
+
+    let _lock = store.lock_for_writing()?;
+    let issue_id = store.resolve_ref(&args.id)?;
+    let author = get_author_identity(
+        args.author_name,
+        args.author_email,
+        &store,
+        SystemEnvProvider,
+    )?;
+
+    let current = store.get_issue(issue_id)?.fields.get(&args.key).cloned();
+    if current == args.value {
+        let message = match &args.value {
+            Some(value) => format!("Issue #{} already has {} = {}", issue_id, args.key, value),
+            None => format!("Issue #{} has no field '{}'", issue_id, args.key),
+        };
+        println!("{}", warning_message(&message));
+        return Ok(());
+    }
+
+    let message = match &args.value {
+        Some(value) => format!("Set {} = {} on issue #{}", args.key, value, issue_id),
+        None => format!("Removed {} from issue #{}", args.key, issue_id),
+    };
+    store.set_field(issue_id, args.key, args.value, author)?;
+
+    println!("{}", success_message(&message));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_handle_field_set_and_unset() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let author = create_test_identity();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to init store");
+        let issue_id = store
+            .create_issue("Title".to_string(), String::new(), author.clone())
+            .unwrap();
+
+        let args = |value: Option<&str>, unset| FieldArgs {
+            id: issue_id.to_string(),
+            key: "severity".to_string(),
+            value: value.map(str::to_string),
+            unset,
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+        };
+
+        handle_field(repo_path.clone(), args(Some("high"), false)).unwrap();
+        let store = IssueStore::open(&repo_path).unwrap();
+        assert_eq!(
+            store.get_issue(issue_id).unwrap().fields.get("severity"),
+            Some(&"high".to_string())
+        );
+        handle_field(repo_path.clone(), args(None, false)).expect("Reading a set field");
+
+        // Setting the same value again records nothing
+        handle_field(repo_path.clone(), args(Some("high"), false)).unwrap();
+        let store = IssueStore::open(&repo_path).unwrap();
+        assert_eq!(store.get_issue_events(issue_id).unwrap().len(), 2);
+
+        handle_field(repo_path.clone(), args(None, true)).unwrap();
+        let store = IssueStore::open(&repo_path).unwrap();
+        assert!(store.get_issue(issue_id).unwrap().fields.is_empty());
+        handle_field(repo_path.clone(), args(None, true)).expect("Unsetting twice is a no-op");
+        assert_eq!(store.get_issue_events(issue_id).unwrap().len(), 3);
+        assert!(handle_field(repo_path.clone(), args(None, false)).is_err());
+
+        assert!(validate_field_key("a=b").is_err());
+    }
+}
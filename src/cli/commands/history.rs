@@ -0,0 +1,268 @@
+use anyhow::Result;
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::output::{OutputFormat, success_message, warning_message};
+use crate::common::{Identity, IssueEvent, IssueId};
+use crate::storage::IssueStore;
+use crate::storage::errors::StorageError;
+
+#[derive(Args)]
+pub struct HistoryArgs {
+    /// Issue ID or slug to show the history of
+    pub id: String,
+
+    /// Only report events whose commit author differs from the author recorded in the event
+    #[arg(long)]
+    pub check_authors: bool,
+
+    /// Output format; JSON is an array of the issue's events, oldest first
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, conflicts_with = "check_authors")]
+    pub format: OutputFormat,
+
+    /// Pair each JSON event with the OID of the commit storing it
+    #[arg(long)]
+    pub with_oids: bool,
+}
+
+/// An event with the OID of its commit, as emitted by `--format json --with-oids`
+#[derive(Debug, Serialize, Deserialize)]
+struct OidEvent {
+    commit_oid: String,
+    event: IssueEvent,
+}
+
+/// One event in an issue's history, with the author of the commit that stores it
+#[derive(Debug)]
+struct HistoryEntry {
+    commit_oid: gix::ObjectId,
+    summary: String,
+    event_author: Identity,
+    on_behalf_of: Option<Identity>,
+    commit_author: Identity,
+}
+
+impl HistoryEntry {
+    fn authors_match(&self) -> bool {
+        self.event_author == self.commit_author
+    }
+}
+
+pub fn handle_history(repo_path: std::path::PathBuf, args: HistoryArgs) -> Result<()> {
+    let store = IssueStore::open(&repo_path)?;
+    let issue_id = store.resolve_ref(&args.id)?;
+
+    if args.format == OutputFormat::Json {
+        let events = store.get_issue_events_with_oids(issue_id)?;
+        if events.is_empty() {
+            return Err(StorageError::issue_not_found(issue_id).into());
+        }
+        println!("{}", render_history_json(events, args.with_oids)?);
+        return Ok(());
+    }
+
+    let entries = collect_history(&store, issue_id)?;
+
+    if !args.check_authors {
+        for entry in &entries {
+            match &entry.on_behalf_of {
+                Some(on_behalf_of) => println!(
+                    "{} {} ({} on behalf of {})",
+                    short_oid(entry.commit_oid),
+                    entry.summary,
+                    entry.event_author,
+                    on_behalf_of
+                ),
+                None => println!(
+                    "{} {} ({})",
+                    short_oid(entry.commit_oid),
+                    entry.summary,
+                    entry.event_author
+                ),
+            }
+        }
+        return Ok(());
+    }
+
+    let mismatches: Vec<&HistoryEntry> = entries
+        .iter()
+        .filter(|entry| !entry.authors_match())
+        .collect();
+    if mismatches.is_empty() {
+        println!(
+            "{}",
+            success_message(&format!(
+                "All {} events of issue #{} match their commit authors",
+                entries.len(),
+                issue_id
+            ))
+        );
+        return Ok(());
+    }
+
+    for entry in &mismatches {
+        println!(
+            "{}",
+            warning_message(&format!(
+                "{} {}: event by {}, commit by {}",
+                short_oid(entry.commit_oid),
+                entry.summary,
+                entry.event_author,
+                entry.commit_author
+            ))
+        );
+    }
+    Err(anyhow::anyhow!(
+        "{} events of issue #{} have a commit author that differs from the event author",
+        mismatches.len(),
+        issue_id
+    ))
+}
+
+/// Pair each event of an issue with the author of its commit, oldest first
+fn collect_history(store: &IssueStore, issue_id: IssueId) -> Result<Vec<HistoryEntry>> {
+    let events = store.get_issue_events_with_oids(issue_id)?;
+    if events.is_empty() {
+        return Err(StorageError::issue_not_found(issue_id).into());
+    }
+
+    let mut entries = Vec::new();
+    for (commit_oid, event) in events {
+        let commit = store.read_commit(commit_oid)?;
+        entries.push(HistoryEntry {
+            commit_oid,
+            summary: commit
+                .message
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            event_author: event.author().clone(),
+            on_behalf_of: event.on_behalf_of().cloned(),
+            commit_author: commit.author,
+        });
+    }
+    Ok(entries)
+}
+
+/// Render events as a pretty JSON array, optionally paired with their commit OIDs
+fn render_history_json(
+    events: Vec<(gix::ObjectId, IssueEvent)>,
+    with_oids: bool,
+) -> Result<String> {
+    let json = if with_oids {
+        let events: Vec<OidEvent> = events
+            .into_iter()
+            .map(|(oid, event)| OidEvent {
+                commit_oid: oid.to_string(),
+                event,
+            })
+            .collect();
+        serde_json::to_string_pretty(&events)?
+    } else {
+        let events: Vec<IssueEvent> = events.into_iter().map(|(_, event)| event).collect();
+        serde_json::to_string_pretty(&events)?
+    };
+    Ok(json)
+}
+
+fn short_oid(oid: gix::ObjectId) -> String {
+    oid.to_hex_with_len(7).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::test_helpers::*;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo_path: &std::path::Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .env("GIT_AUTHOR_NAME", "Mallory")
+            .env("GIT_AUTHOR_EMAIL", "mallory@example.com")
+            .env("GIT_COMMITTER_NAME", "Mallory")
+            .env("GIT_COMMITTER_EMAIL", "mallory@example.com")
+            .output()
+            .expect("Failed to run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    #[test]
+    fn test_check_authors_flags_forged_commit() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .add_label(issue_id, "bug".to_string(), author)
+            .expect("Failed to add label");
+
+        let entries = collect_history(&store, issue_id).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(HistoryEntry::authors_match));
+
+        // Replay the label event in a commit authored by someone else
+        let head = git(&repo_path, &["rev-parse", "refs/git-issue/issues/1"]);
+        let tree = git(&repo_path, &["rev-parse", "refs/git-issue/issues/1^{tree}"]);
+        let forged = git(
+            &repo_path,
+            &["commit-tree", &tree, "-p", &head, "-m", "LabelAdded: bug"],
+        );
+        git(
+            &repo_path,
+            &["update-ref", "refs/git-issue/issues/1", &forged, &head],
+        );
+
+        let entries = collect_history(&store, issue_id).unwrap();
+        let mismatches: Vec<_> = entries
+            .iter()
+            .filter(|entry| !entry.authors_match())
+            .collect();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].commit_oid.to_string(), forged);
+        assert_eq!(mismatches[0].commit_author.email, "mallory@example.com");
+
+        let result = handle_history(
+            repo_path,
+            HistoryArgs {
+                id: issue_id.to_string(),
+                check_authors: true,
+                format: OutputFormat::Text,
+                with_oids: false,
+            },
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_history_json_round_trips_events() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let mut store = IssueStore::init(temp_dir.path()).expect("Failed to initialize store");
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Typed".to_string(), String::new(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .add_label(issue_id, "json".to_string(), author)
+            .expect("Failed to add label");
+        let events = store.get_issue_events_with_oids(issue_id).unwrap();
+
+        let json = render_history_json(events.clone(), false).unwrap();
+        let parsed: Vec<IssueEvent> = serde_json::from_str(&json).expect("Should deserialize");
+        let expected: Vec<IssueEvent> = events.iter().map(|(_, event)| event.clone()).collect();
+        assert_eq!(parsed, expected);
+
+        let json = render_history_json(events.clone(), true).unwrap();
+        let parsed: Vec<OidEvent> = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].commit_oid, events[1].0.to_string());
+        assert_eq!(parsed[1].event, events[1].1);
+    }
+}
@@ -1,16 +1,22 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use clap::Args;
+use console::style;
+use std::collections::HashMap;
 
 use crate::cli::output::{success_message, warning_message};
-use crate::common::{IssueId, SystemEnvProvider};
+use crate::common::{Identity, IssueEvent, IssueId, SystemEnvProvider};
 use crate::storage::IssueStore;
 
-use super::get_author_identity;
+use super::{
+    add_trailing_comment, get_author_identity, label_case_collisions, labels_normalized,
+    normalize_label,
+};
 
 #[derive(Args)]
 pub struct LabelArgs {
-    /// Issue ID to modify labels for
-    pub id: IssueId,
+    /// Issue ID or slug to modify labels for
+    pub id: String,
 
     /// Labels to add or remove (use +label to add, -label to remove). Use -- before -label if needed.
     pub labels: Vec<String>,
@@ -22,10 +28,35 @@ pub struct LabelArgs {
     /// Author email (defaults to git config)
     #[arg(short = 'e', long)]
     pub author_email: Option<String>,
+
+    /// Fail instead of warning when a label differs only in case from an existing one
+    #[arg(long)]
+    pub strict_labels: bool,
+
+    /// Remove every label from the issue
+    #[arg(long, conflicts_with = "labels")]
+    pub clear: bool,
+
+    /// List the issue's labels instead of changing them
+    #[arg(long, conflicts_with_all = ["labels", "clear"])]
+    pub list: bool,
+
+    /// With --list, show who added each label and when
+    #[arg(long, requires = "list")]
+    pub with_authors: bool,
+
+    /// Comment to add alongside the change, given after `--` and any labels that follow it
+    #[arg(last = true, value_name = "COMMENT")]
+    pub comment: Vec<String>,
 }
 
 /// Parse label operations from arguments with +/- prefixes
-fn parse_label_operations(labels: &[String]) -> Result<(Vec<String>, Vec<String>)> {
+///
+/// When `normalize` is set, labels are normalized before validation.
+fn parse_label_operations(
+    labels: &[String],
+    normalize: bool,
+) -> Result<(Vec<String>, Vec<String>)> {
     let mut add_labels = Vec::new();
     let mut remove_labels = Vec::new();
 
@@ -38,14 +69,16 @@ fn parse_label_operations(labels: &[String]) -> Result<(Vec<String>, Vec<String>
             if label.is_empty() {
                 return Err(anyhow::anyhow!("Empty label after '+' prefix"));
             }
-            validate_label_name(label)?;
-            add_labels.push(label.to_string());
+            let label = normalize_if(label, normalize);
+            validate_label_name(&label)?;
+            add_labels.push(label);
         } else if let Some(label) = label_arg.strip_prefix('-') {
             if label.is_empty() {
                 return Err(anyhow::anyhow!("Empty label after '-' prefix"));
             }
-            validate_label_name(label)?;
-            remove_labels.push(label.to_string());
+            let label = normalize_if(label, normalize);
+            validate_label_name(&label)?;
+            remove_labels.push(label);
         } else {
             return Err(anyhow::anyhow!(
                 "Label '{}' must start with '+' (to add) or '-' (to remove)",
@@ -59,6 +92,32 @@ fn parse_label_operations(labels: &[String]) -> Result<(Vec<String>, Vec<String>
     Ok((add_labels, remove_labels))
 }
 
+/// Split the words after `--` into leading label operations and a comment
+///
+/// A `-label` can only be given after `--`, so when no labels came before it
+/// the `+label`/`-label` words right after `--` are still label operations.
+fn split_trailing_labels(labels: Vec<String>, trailing: Vec<String>) -> (Vec<String>, Vec<String>) {
+    if !labels.is_empty() {
+        return (labels, trailing);
+    }
+
+    let count = trailing
+        .iter()
+        .take_while(|word| word.len() > 1 && (word.starts_with('+') || word.starts_with('-')))
+        .count();
+    let mut labels = trailing;
+    let comment = labels.split_off(count);
+    (labels, comment)
+}
+
+fn normalize_if(label: &str, normalize: bool) -> String {
+    if normalize {
+        normalize_label(label)
+    } else {
+        label.to_string()
+    }
+}
+
 /// Validate that a label name contains valid characters
 fn validate_label_name(label: &str) -> Result<()> {
     if label.trim() != label {
@@ -79,8 +138,75 @@ fn validate_label_name(label: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn handle_label(repo_path: std::path::PathBuf, args: LabelArgs) -> Result<()> {
+/// The author and time of the event that added a label
+type LabelAddition = (Identity, DateTime<Utc>);
+
+/// Who added each current label and when, in the issue's label order
+///
+/// A label that was removed and added again is attributed to the latest addition.
+fn label_attributions(
+    labels: &[String],
+    events: &[IssueEvent],
+) -> Vec<(String, Option<LabelAddition>)> {
+    let mut added_by = HashMap::new();
+    for event in events {
+        match event {
+            IssueEvent::LabelAdded {
+                label,
+                author,
+                timestamp,
+                ..
+            } => {
+                added_by.insert(label.as_str(), (author.clone(), *timestamp));
+            }
+            IssueEvent::LabelRemoved { label, .. } => {
+                added_by.remove(label.as_str());
+            }
+            _ => {}
+        }
+    }
+
+    labels
+        .iter()
+        .map(|label| (label.clone(), added_by.get(label.as_str()).cloned()))
+        .collect()
+}
+
+fn list_labels(store: &IssueStore, issue_id: IssueId, with_authors: bool) -> Result<()> {
+    let issue = store.get_issue(issue_id)?;
+    if !with_authors {
+        for label in &issue.labels {
+            println!("{}", label);
+        }
+        return Ok(());
+    }
+
+    let events = store.get_issue_events(issue_id)?;
+    for (label, added) in label_attributions(&issue.labels, &events) {
+        match added {
+            Some((author, timestamp)) => println!(
+                "{}  added by {} <{}> on {}",
+                style(label).magenta(),
+                author.name,
+                author.email,
+                timestamp.format("%Y-%m-%d %H:%M")
+            ),
+            None => println!("{}", style(label).magenta()),
+        }
+    }
+    Ok(())
+}
+
+pub fn handle_label(repo_path: std::path::PathBuf, mut args: LabelArgs) -> Result<()> {
     let mut store = IssueStore::open(&repo_path)?;
+    if args.list {
+        let issue_id = store.resolve_ref(&args.id)?;
+        return list_labels(&store, issue_id, args.with_authors);
+    }
+    (args.labels, args.comment) = split_trailing_labels(args.labels, args.comment);
+
+    let _lock = store.lock_for_writing()?;
+    let issue_id = store.resolve_ref(&args.id)?;
     let author = get_author_identity(
         args.author_name,
         args.author_email,
@@ -89,20 +215,37 @@ pub fn handle_label(repo_path: std::path::PathBuf, args: LabelArgs) -> Result<()
     )?;
 
     // Get the current issue to check existing labels
-    let current_issue = store.get_issue(args.id)?;
-    let current_labels: std::collections::HashSet<String> =
+    let current_issue = store.get_issue(issue_id)?;
+    let mut current_labels: std::collections::HashSet<String> =
         current_issue.labels.iter().cloned().collect();
 
     // Parse the label operations
-    if args.labels.is_empty() {
-        return Err(anyhow::anyhow!(
-            "No label operations specified. Use +label to add or -label to remove"
-        ));
-    }
+    let (add_labels, remove_labels) = if args.clear {
+        (Vec::new(), current_issue.labels.clone())
+    } else {
+        if args.labels.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No label operations specified. Use +label to add, -label to remove, or --clear"
+            ));
+        }
 
-    let (add_labels, remove_labels) = parse_label_operations(&args.labels)?;
+        let normalize = labels_normalized(&store);
+        parse_label_operations(&args.labels, normalize)?
+    };
+
+    // Check for near-duplicates before changing anything
+    let mut resulting_labels: Vec<String> = current_labels
+        .iter()
+        .filter(|label| !remove_labels.contains(label))
+        .cloned()
+        .collect();
+    resulting_labels.extend(add_labels.iter().cloned());
+    let collisions = label_case_collisions(&add_labels, &resulting_labels);
+    if args.strict_labels && !collisions.is_empty() {
+        return Err(anyhow::anyhow!("{}", collisions.join("; ")));
+    }
 
-    let mut warnings = Vec::new();
+    let mut warnings = collisions;
     let mut successful_adds = Vec::new();
     let mut successful_removes = Vec::new();
 
@@ -111,10 +254,11 @@ pub fn handle_label(repo_path: std::path::PathBuf, args: LabelArgs) -> Result<()
         if current_labels.contains(&label) {
             warnings.push(format!(
                 "Label '{}' already exists on issue #{}",
-                label, args.id
+                label, issue_id
             ));
         } else {
-            store.add_label(args.id, label.clone(), author.clone())?;
+            store.add_label(issue_id, label.clone(), author.clone())?;
+            current_labels.insert(label.clone());
             successful_adds.push(label);
         }
     }
@@ -122,9 +266,13 @@ pub fn handle_label(repo_path: std::path::PathBuf, args: LabelArgs) -> Result<()
     // Process removals
     for label in remove_labels {
         if !current_labels.contains(&label) {
-            warnings.push(format!("Label '{}' not found on issue #{}", label, args.id));
+            warnings.push(format!(
+                "Label '{}' not found on issue #{}",
+                label, issue_id
+            ));
         } else {
-            store.remove_label(args.id, label.clone(), author.clone())?;
+            store.remove_label(issue_id, label.clone(), author.clone())?;
+            current_labels.remove(&label);
             successful_removes.push(label);
         }
     }
@@ -145,7 +293,7 @@ pub fn handle_label(repo_path: std::path::PathBuf, args: LabelArgs) -> Result<()
             "{}",
             success_message(&format!(
                 "Updated labels for issue #{}: {}",
-                args.id,
+                issue_id,
                 changes.join("; ")
             ))
         );
@@ -158,16 +306,17 @@ pub fn handle_label(repo_path: std::path::PathBuf, args: LabelArgs) -> Result<()
 
     // If no successful operations occurred, show a message
     if successful_adds.is_empty() && successful_removes.is_empty() {
-        println!("No label changes were made to issue #{}", args.id);
+        println!("No label changes were made to issue #{}", issue_id);
     }
 
-    Ok(())
+    add_trailing_comment(&mut store, issue_id, &args.comment, author)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::storage::test_helpers::*;
+    use clap::Parser;
     use tempfile::TempDir;
 
     fn setup_temp_label_repo() -> (TempDir, std::path::PathBuf, IssueId) {
@@ -204,7 +353,7 @@ mod tests {
             "-old-label".to_string(),
         ];
 
-        let (add_labels, remove_labels) = parse_label_operations(&labels).unwrap();
+        let (add_labels, remove_labels) = parse_label_operations(&labels, false).unwrap();
 
         assert_eq!(add_labels, vec!["bug", "feature"]);
         assert_eq!(remove_labels, vec!["old-label"]);
@@ -214,7 +363,7 @@ mod tests {
     fn test_parse_label_operations_only_adds() {
         let labels = vec!["+bug".to_string(), "+feature".to_string()];
 
-        let (add_labels, remove_labels) = parse_label_operations(&labels).unwrap();
+        let (add_labels, remove_labels) = parse_label_operations(&labels, false).unwrap();
 
         assert_eq!(add_labels, vec!["bug", "feature"]);
         assert!(remove_labels.is_empty());
@@ -224,7 +373,7 @@ mod tests {
     fn test_parse_label_operations_only_removes() {
         let labels = vec!["-bug".to_string(), "-feature".to_string()];
 
-        let (add_labels, remove_labels) = parse_label_operations(&labels).unwrap();
+        let (add_labels, remove_labels) = parse_label_operations(&labels, false).unwrap();
 
         assert!(add_labels.is_empty());
         assert_eq!(remove_labels, vec!["bug", "feature"]);
@@ -234,7 +383,7 @@ mod tests {
     fn test_parse_label_operations_invalid_prefix() {
         let labels = vec!["bug".to_string()]; // Missing + or -
 
-        let result = parse_label_operations(&labels);
+        let result = parse_label_operations(&labels, false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("must start with"));
     }
@@ -243,7 +392,7 @@ mod tests {
     fn test_parse_label_operations_empty_label() {
         let labels = vec!["+".to_string()]; // Empty label after +
 
-        let result = parse_label_operations(&labels);
+        let result = parse_label_operations(&labels, false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Empty label"));
     }
@@ -275,10 +424,15 @@ mod tests {
         let author = create_test_identity();
 
         let args = LabelArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             labels: vec!["+bug".to_string(), "+feature".to_string()],
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
         };
 
         let result = handle_label(repo_path.clone(), args);
@@ -298,10 +452,15 @@ mod tests {
         let author = create_test_identity();
 
         let args = LabelArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             labels: vec!["-existing-label".to_string()],
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
         };
 
         let result = handle_label(repo_path.clone(), args);
@@ -320,7 +479,7 @@ mod tests {
         let author = create_test_identity();
 
         let args = LabelArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             labels: vec![
                 "+bug".to_string(),
                 "-existing-label".to_string(),
@@ -328,6 +487,11 @@ mod tests {
             ],
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
         };
 
         let result = handle_label(repo_path.clone(), args);
@@ -348,10 +512,15 @@ mod tests {
         let author = create_test_identity();
 
         let args = LabelArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             labels: vec!["+existing-label".to_string()], // Already exists
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
         };
 
         let result = handle_label(repo_path.clone(), args);
@@ -373,10 +542,15 @@ mod tests {
         let author = create_test_identity();
 
         let args = LabelArgs {
-            id: issue_id,
+            id: issue_id.to_string(),
             labels: vec!["-nonexistent".to_string()],
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
         };
 
         let result = handle_label(repo_path.clone(), args);
@@ -399,10 +573,15 @@ mod tests {
         let author = create_test_identity();
 
         let args = LabelArgs {
-            id: 9999, // Non-existent issue
+            id: "9999".to_string(), // Non-existent issue
             labels: vec!["+bug".to_string()],
             author_name: Some(author.name.clone()),
             author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
         };
 
         let result = handle_label(repo_path, args);
@@ -411,4 +590,281 @@ mod tests {
             "Handle label should fail for non-existent issue"
         );
     }
+
+    #[test]
+    fn test_normalize_label() {
+        assert_eq!(normalize_label("Bug Fix"), "bug-fix");
+        assert_eq!(normalize_label("  UI/UX -- Polish! "), "ui-ux-polish");
+        assert_eq!(normalize_label("already-normal"), "already-normal");
+    }
+
+    #[test]
+    fn test_parse_label_operations_normalized() {
+        let labels = vec!["+Bug Fix".to_string(), "-Needs_Triage".to_string()];
+
+        let (add_labels, remove_labels) = parse_label_operations(&labels, true).unwrap();
+        assert_eq!(add_labels, vec!["bug-fix"]);
+        assert_eq!(remove_labels, vec!["needs-triage"]);
+
+        // Without normalization the space is still rejected
+        let result = parse_label_operations(&labels, false);
+        assert!(result.unwrap_err().to_string().contains("contains spaces"));
+    }
+
+    #[test]
+    fn test_handle_label_normalize_collapses_duplicates() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_label_repo();
+        let author = create_test_identity();
+
+        std::process::Command::new("git")
+            .args(["config", "labels.normalize", "true"])
+            .current_dir(&repo_path)
+            .output()
+            .expect("Failed to set git config");
+
+        let args = LabelArgs {
+            id: issue_id.to_string(),
+            labels: vec![
+                "+Bug".to_string(),
+                "+bug".to_string(),
+                "+Existing Label".to_string(),
+            ],
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
+        };
+
+        handle_label(repo_path.clone(), args).expect("Handle label should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(
+            issue.labels.iter().filter(|l| l.as_str() == "bug").count(),
+            1
+        );
+        assert_eq!(
+            issue.labels.len(),
+            3,
+            "Should have existing, another and bug"
+        );
+        assert!(issue.labels.contains(&"existing-label".to_string()));
+    }
+
+    #[test]
+    fn test_label_case_collisions() {
+        let labels = vec!["bug".to_string(), "Bug".to_string(), "ui".to_string()];
+        assert_eq!(
+            label_case_collisions(&["Bug".to_string()], &labels),
+            vec!["Label 'Bug' differs only in case from 'bug'"]
+        );
+        assert!(label_case_collisions(&["ui".to_string()], &labels).is_empty());
+    }
+
+    #[test]
+    fn test_handle_label_case_collision_warns() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_label_repo();
+        let author = create_test_identity();
+
+        let args = LabelArgs {
+            id: issue_id.to_string(),
+            labels: vec!["+Existing-Label".to_string()],
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
+        };
+        handle_label(repo_path.clone(), args).expect("Collision should only warn");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert!(issue.labels.contains(&"Existing-Label".to_string()));
+        assert!(issue.labels.contains(&"existing-label".to_string()));
+    }
+
+    #[test]
+    fn test_handle_label_case_collision_strict_errors() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_label_repo();
+        let author = create_test_identity();
+
+        let args = LabelArgs {
+            id: issue_id.to_string(),
+            labels: vec!["+feature".to_string(), "+Existing-Label".to_string()],
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            strict_labels: true,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
+        };
+        let err = handle_label(repo_path.clone(), args).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Label 'Existing-Label' differs only in case from 'existing-label'"
+        );
+
+        // Nothing is applied when strict mode rejects the operation
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert!(!issue.labels.contains(&"feature".to_string()));
+
+        // Renaming by case is not a collision, since the old label goes away
+        let args = LabelArgs {
+            id: issue_id.to_string(),
+            labels: vec!["-existing-label".to_string(), "+Existing-Label".to_string()],
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            strict_labels: true,
+            clear: false,
+            list: false,
+            with_authors: false,
+            comment: vec![],
+        };
+        handle_label(repo_path.clone(), args).expect("Case rename should succeed");
+    }
+
+    #[test]
+    fn test_concurrent_label_edits_are_serialized() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_label_repo();
+
+        let writers: Vec<_> = ["alpha", "beta"]
+            .into_iter()
+            .map(|prefix| {
+                let repo_path = repo_path.clone();
+                std::thread::spawn(move || {
+                    for n in 0..5 {
+                        let args = LabelArgs {
+                            id: issue_id.to_string(),
+                            labels: vec![format!("+{}-{}", prefix, n)],
+                            author_name: Some("Test User".to_string()),
+                            author_email: Some("test@example.com".to_string()),
+                            strict_labels: false,
+                            clear: false,
+                            list: false,
+                            with_authors: false,
+                            comment: vec![],
+                        };
+                        handle_label(repo_path.clone(), args)?;
+                    }
+                    anyhow::Ok(())
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap().expect("Both writers should succeed");
+        }
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(issue.labels.len(), 12);
+        assert!(!repo_path.join(".git/git-issue.lock").exists());
+    }
+
+    #[test]
+    fn test_handle_label_clear() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_label_repo();
+        let author = create_test_identity();
+        let mut store = IssueStore::open(&repo_path).expect("Should open store");
+        store
+            .add_label(issue_id, "third-label".to_string(), author.clone())
+            .expect("Failed to add label");
+        let events_before = store.get_issue_events(issue_id).unwrap().len();
+
+        let args = LabelArgs {
+            id: issue_id.to_string(),
+            labels: vec![],
+            author_name: Some(author.name.clone()),
+            author_email: Some(author.email.clone()),
+            strict_labels: false,
+            clear: true,
+            list: false,
+            with_authors: false,
+            comment: vec![],
+        };
+        handle_label(repo_path.clone(), args).expect("Clearing labels should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        assert!(store.get_issue(issue_id).unwrap().labels.is_empty());
+
+        let events = store.get_issue_events(issue_id).unwrap();
+        let removed: Vec<_> = events[events_before..]
+            .iter()
+            .map(|event| match event {
+                IssueEvent::LabelRemoved { label, .. } => label.as_str(),
+                other => panic!("Unexpected event {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            removed,
+            vec!["existing-label", "another-label", "third-label"]
+        );
+    }
+
+    #[test]
+    fn test_label_attributions() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_label_repo();
+        let mut store = IssueStore::open(&repo_path).expect("Should open store");
+        let alice = Identity::new("Alice", "alice@example.com");
+        let bob = Identity::new("Bob", "bob@example.com");
+        store
+            .add_label(issue_id, "ui".to_string(), alice.clone())
+            .unwrap();
+        store
+            .add_label(issue_id, "backend".to_string(), bob.clone())
+            .unwrap();
+        store
+            .remove_label(issue_id, "ui".to_string(), bob.clone())
+            .unwrap();
+        store
+            .add_label(issue_id, "ui".to_string(), alice.clone())
+            .unwrap();
+
+        let issue = store.get_issue(issue_id).unwrap();
+        let events = store.get_issue_events(issue_id).unwrap();
+        let attributions: HashMap<String, Identity> = label_attributions(&issue.labels, &events)
+            .into_iter()
+            .map(|(label, added)| (label, added.expect("Every label was added").0))
+            .collect();
+
+        assert_eq!(attributions["ui"], alice);
+        assert_eq!(attributions["backend"], bob);
+    }
+
+    #[test]
+    fn test_handle_label_with_trailing_comment() {
+        let (_temp_dir, repo_path, issue_id) = setup_temp_label_repo();
+        let label_args = |argv: &[&str]| {
+            let mut full = vec!["git-issue", "label"];
+            full.extend_from_slice(argv);
+            match crate::cli::Cli::parse_from(full).command {
+                crate::cli::Commands::Label(args) => args,
+                _ => unreachable!(),
+            }
+        };
+
+        // Labels right after `--` still count, the rest becomes a comment
+        let args = label_args(&[&issue_id.to_string(), "+ui", "--", "triaged", "today"]);
+        handle_label(repo_path.clone(), args).expect("Label should succeed");
+        let args = label_args(&[&issue_id.to_string(), "--", "-ui", "not", "ui"]);
+        handle_label(repo_path.clone(), args).expect("Label should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Should open store");
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert!(!issue.labels.contains(&"ui".to_string()));
+        let comments: Vec<&str> = issue.comments.iter().map(|c| c.content.as_str()).collect();
+        assert_eq!(comments, vec!["triaged today", "not ui"]);
+
+        let events = store.get_issue_events(issue_id).expect("Should get events");
+        assert!(matches!(
+            events.last(),
+            Some(IssueEvent::CommentAdded { .. })
+        ));
+    }
 }
@@ -1,21 +1,233 @@
 use anyhow::Result;
-use clap::Args;
+use clap::{Args, ValueEnum};
+use regex::Regex;
+use serde::Serialize;
 
-use crate::cli::output::{format_issue_compact, format_issue_list_long};
-use crate::common::IssueStatus;
+use crate::cli::output::{
+    Lang, format_issue_compact, format_issue_list_long, format_issue_template, max_id_width,
+    validate_issue_template,
+};
+use crate::common::{Identity, Issue, IssueId, IssueStatus, Priority};
 use crate::storage::IssueStore;
+use crate::storage::search::{SearchPattern, SearchQuery};
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 
-use super::parse_status;
+use super::{issue_url_template, parse_status, status_styles};
+
+/// Output format for `list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ListFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// JSON array of issues
+    Json,
+    /// Tab-separated values with a header row
+    Tsv,
+    /// Bare issue ids, one per line, for piping into xargs
+    Ids,
+}
+
+/// Sort order for `list`; issues with equal keys are always ordered by ascending id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ListSort {
+    /// Issue id, ascending
+    #[default]
+    Id,
+    /// Most urgent first, unprioritized last
+    Priority,
+    /// Todo, then in-progress, then done
+    Status,
+    /// Newest first
+    Created,
+    /// Most recently updated first
+    Updated,
+}
+
+/// How recently an issue was created or updated, for `list --age`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AgeBucket {
+    /// Created within the last `list.ageNewDays` days (default 2)
+    New,
+    /// Updated within the last `list.ageRecentDays` days (default 7)
+    Recent,
+    /// Not updated for more than `list.ageStaleDays` days (default 30)
+    Stale,
+}
+
+impl AgeBucket {
+    /// Config key overriding this bucket's threshold
+    fn config_key(self) -> &'static str {
+        match self {
+            AgeBucket::New => "list.ageNewDays",
+            AgeBucket::Recent => "list.ageRecentDays",
+            AgeBucket::Stale => "list.ageStaleDays",
+        }
+    }
+
+    fn default_days(self) -> u32 {
+        match self {
+            AgeBucket::New => 2,
+            AgeBucket::Recent => 7,
+            AgeBucket::Stale => 30,
+        }
+    }
+
+    /// Whether `issue` falls in this bucket, given a threshold of `days` as of `now`
+    fn matches(self, issue: &Issue, days: u32, now: chrono::DateTime<chrono::Utc>) -> bool {
+        let cutoff = now - chrono::Duration::days(i64::from(days));
+        match self {
+            AgeBucket::New => issue.created_at > cutoff,
+            AgeBucket::Recent => issue.updated_at > cutoff,
+            AgeBucket::Stale => issue.updated_at < cutoff,
+        }
+    }
+}
+
+/// Version of the `list --envelope` JSON layout
+const LIST_JSON_SCHEMA: u32 = 1;
+
+/// Metadata wrapper around JSON list output, enabled with `--envelope`
+#[derive(Serialize)]
+struct ListEnvelope<'a, T> {
+    tool: &'static str,
+    version: &'static str,
+    schema: u32,
+    issues: &'a [T],
+}
+
+/// An issue as emitted by `list --format json`
+///
+/// Keys are serialized in the order declared here rather than following the
+/// layout of `Issue`, so refactoring the domain types cannot reorder or rename
+/// them. Adds fields derived from the issue and its events; `closed_at` is
+/// present once the issue is done.
+///
+/// This only covers `list`. `history`, `show --raw-json` and native exports
+/// print events in their stored form, which `EVENT_SCHEMA_VERSION` versions.
+#[derive(Serialize)]
+struct IssueJson<'a> {
+    #[serde(skip)]
+    issue: &'a Issue,
+    id: IssueId,
+    title: &'a str,
+    description: &'a str,
+    status: IssueStatus,
+    priority: Priority,
+    labels: &'a [String],
+    comments: Vec<CommentJson<'a>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    created_by: &'a Identity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_on_behalf_of: Option<&'a Identity>,
+    assignees: &'a [Identity],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    closed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    fields: &'a BTreeMap<String, String>,
+    /// Seconds since the issue was created
+    age_seconds: i64,
+    /// How many times the issue moved from done back to an open status
+    reopen_count: usize,
+}
+
+/// A comment within `IssueJson`, with its keys in a fixed order
+#[derive(Serialize)]
+struct CommentJson<'a> {
+    id: &'a str,
+    content: &'a str,
+    author: &'a Identity,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_behalf_of: Option<&'a Identity>,
+    created_at: DateTime<Utc>,
+}
+
+impl<'a> IssueJson<'a> {
+    fn new(issue: &'a Issue, now: DateTime<Utc>) -> Self {
+        Self {
+            issue,
+            id: issue.id,
+            title: &issue.title,
+            description: &issue.description,
+            status: issue.status,
+            priority: issue.priority,
+            labels: &issue.labels,
+            comments: issue
+                .comments
+                .iter()
+                .map(|comment| CommentJson {
+                    id: &comment.id,
+                    content: &comment.content,
+                    author: &comment.author,
+                    on_behalf_of: comment.on_behalf_of.as_ref(),
+                    created_at: comment.created_at,
+                })
+                .collect(),
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            created_by: &issue.created_by,
+            created_on_behalf_of: issue.created_on_behalf_of.as_ref(),
+            assignees: &issue.assignees,
+            closed_at: issue.closed_at,
+            fields: &issue.fields,
+            age_seconds: issue.age(now).num_seconds(),
+            reopen_count: issue.reopen_count,
+        }
+    }
+}
+
+/// Pair each issue with the derived fields of its JSON output
+fn json_rows(issues: &[Issue], now: chrono::DateTime<chrono::Utc>) -> Vec<IssueJson<'_>> {
+    issues
+        .iter()
+        .map(|issue| IssueJson::new(issue, now))
+        .collect()
+}
+
+/// Fields that `list --fields` can select, as named in the JSON output
+const ISSUE_FIELDS: [&str; 16] = [
+    "id",
+    "title",
+    "description",
+    "status",
+    "priority",
+    "labels",
+    "comments",
+    "created_at",
+    "updated_at",
+    "created_by",
+    "created_on_behalf_of",
+    "assignees",
+    "closed_at",
+    "fields",
+    "age_seconds",
+    "reopen_count",
+];
 
 #[derive(Args)]
 pub struct ListArgs {
     /// Optional search string to filter issues by title, description, or labels
     pub search: Option<String>,
 
+    /// Treat the search string as a regular expression over titles, descriptions and comments
+    #[arg(long, requires = "search")]
+    pub regex: bool,
+
+    /// Only show issues whose title matches this regular expression
+    #[arg(long, value_name = "PATTERN")]
+    pub title_regex: Option<String>,
+
     /// Filter by status
     #[arg(short, long)]
     pub status: Option<String>,
 
+    /// Filter by priority (none, urgent, high, medium, low, or 0-4); repeat or comma-separate to match any
+    #[arg(short, long, value_delimiter = ',', value_name = "PRIORITY")]
+    pub priority: Vec<Priority>,
+
     /// Show only issue IDs and titles
     #[arg(short, long)]
     pub compact: bool,
@@ -23,55 +235,485 @@ pub struct ListArgs {
     /// Show all issues including completed ones
     #[arg(short, long)]
     pub all: bool,
+
+    /// Fail on events written by a newer version instead of skipping them
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = ListFormat::Text)]
+    pub format: ListFormat,
+
+    /// Shorthand for `--format json`
+    #[arg(long, conflicts_with = "format")]
+    pub json: bool,
+
+    /// Pretty-print JSON output instead of a single line
+    #[arg(long)]
+    pub pretty: bool,
+
+    /// Wrap JSON output in an object with tool, version and schema fields
+    #[arg(long)]
+    pub envelope: bool,
+
+    /// Only include these comma-separated fields in JSON output, e.g. `id,title,status`
+    #[arg(long, value_delimiter = ',', value_name = "FIELDS")]
+    pub fields: Option<Vec<String>>,
+
+    /// Print each issue with a custom template, e.g. "{id} {status} {title}"
+    ///
+    /// Placeholders: id, title, status, priority, assignees, labels, created, updated
+    #[arg(long, conflicts_with_all = ["format", "json", "compact"])]
+    pub template: Option<String>,
+
+    /// Omit the header row from TSV output
+    #[arg(long)]
+    pub no_header: bool,
+
+    /// Zero-pad issue IDs to the width of the largest listed ID so columns align
+    #[arg(long)]
+    pub pad_ids: bool,
+
+    /// Only show issues with at least one event authored by this email
+    #[arg(long, value_name = "EMAIL")]
+    pub updated_by: Option<String>,
+
+    /// Only show issues with at least one comment by this email
+    #[arg(long, value_name = "EMAIL")]
+    pub commented_by: Option<String>,
+
+    /// Only show issues whose custom field KEY equals VALUE (repeatable)
+    #[arg(long = "field", value_name = "KEY=VALUE")]
+    pub field_filters: Vec<String>,
+
+    /// Only show new, recently updated or stale issues
+    #[arg(long, value_enum, value_name = "BUCKET")]
+    pub age: Option<AgeBucket>,
+
+    /// Hide done issues last updated more than this many days ago (useful with --all)
+    #[arg(long, value_name = "DAYS", visible_alias = "no-done-age")]
+    pub hide_closed_older_than: Option<u32>,
+
+    /// Sort order
+    #[arg(long, value_enum, default_value_t = ListSort::Id)]
+    pub sort: ListSort,
+
+    /// Language for relative times in the long listing
+    #[arg(long, value_enum, default_value_t = Lang::En)]
+    pub lang: Lang,
 }
 
 pub fn handle_list(repo_path: std::path::PathBuf, args: ListArgs) -> Result<()> {
-    let store = IssueStore::open(&repo_path)?;
-    let mut issues = store.list_issues()?;
+    let mut store = IssueStore::open(&repo_path)?;
+    store.set_strict(args.strict);
 
-    // Apply search filter if provided
-    if let Some(search_term) = &args.search {
-        let search_lower = search_term.to_lowercase();
-        issues.retain(|issue| {
-            // Search in title
-            issue.title.to_lowercase().contains(&search_lower)
-                // Search in description
-                || issue.description.to_lowercase().contains(&search_lower)
-                // Search in labels
-                || issue.labels.iter().any(|label| label.to_lowercase().contains(&search_lower))
-        });
+    let format = if args.json {
+        ListFormat::Json
+    } else {
+        args.format
+    };
+
+    if format != ListFormat::Json {
+        let json_only = [
+            ("--fields", args.fields.is_some()),
+            ("--pretty", args.pretty),
+            ("--envelope", args.envelope),
+        ];
+        if let Some((flag, _)) = json_only.iter().find(|(_, given)| *given) {
+            anyhow::bail!("{} is only supported with --format json", flag);
+        }
+    }
+    if let Some(fields) = &args.fields {
+        validate_fields(fields)?;
+    }
+    if let Some(template) = &args.template {
+        validate_issue_template(template).map_err(anyhow::Error::msg)?;
     }
 
-    let filtered_issues = if let Some(status_filter) = args.status {
-        let status = parse_status(&status_filter)?;
-        issues
-            .into_iter()
-            .filter(|issue| issue.status == status)
-            .collect()
-    } else if args.all {
-        // Show all issues when --all flag is specified
-        issues
+    let mut filtered_issues = filter_issues(&mut store, &args)?;
+    sort_issues(&mut filtered_issues, args.sort);
+
+    match format {
+        ListFormat::Json => {
+            let rows = json_rows(&filtered_issues, chrono::Utc::now());
+            println!(
+                "{}",
+                render_json(&rows, args.fields.as_deref(), args.pretty, args.envelope)?
+            );
+            return Ok(());
+        }
+        ListFormat::Tsv => {
+            print!("{}", render_tsv(&filtered_issues, !args.no_header));
+            return Ok(());
+        }
+        ListFormat::Ids => {
+            print!("{}", render_ids(&filtered_issues));
+            return Ok(());
+        }
+        ListFormat::Text => {}
+    }
+
+    if let Some(template) = &args.template {
+        for issue in &filtered_issues {
+            println!(
+                "{}",
+                format_issue_template(issue, template).map_err(anyhow::Error::msg)?
+            );
+        }
+        return Ok(());
+    }
+
+    let id_width = if args.pad_ids {
+        max_id_width(&filtered_issues)
     } else {
-        // By default, exclude "done" issues
-        issues
-            .into_iter()
-            .filter(|issue| issue.status != IssueStatus::Done)
-            .collect()
+        0
     };
 
+    let url_template = issue_url_template(&store);
+    let url_template = url_template.as_deref();
+    let styles = status_styles(&store)?;
+
     if args.compact {
         for issue in filtered_issues {
-            println!("{}", format_issue_compact(&issue));
+            println!(
+                "{}",
+                format_issue_compact(&issue, id_width, url_template, &styles)
+            );
         }
     } else {
         for issue in filtered_issues {
-            print!("{}", format_issue_list_long(&issue));
+            print!(
+                "{}",
+                format_issue_list_long(&issue, id_width, url_template, args.lang.units(), &styles)
+            );
         }
     }
 
     Ok(())
 }
 
+/// Sort issues by `sort`, breaking ties by ascending id so output is deterministic
+fn sort_issues(issues: &mut [Issue], sort: ListSort) {
+    issues.sort_by(|a, b| {
+        let primary = match sort {
+            ListSort::Id => Ordering::Equal,
+            ListSort::Priority => priority_rank(a.priority).cmp(&priority_rank(b.priority)),
+            ListSort::Status => status_rank(a.status).cmp(&status_rank(b.status)),
+            ListSort::Created => b.created_at.cmp(&a.created_at),
+            ListSort::Updated => b.updated_at.cmp(&a.updated_at),
+        };
+        primary.then(a.id.cmp(&b.id))
+    });
+}
+
+/// Rank priorities so that urgent sorts first and `None` sorts last
+fn priority_rank(priority: Priority) -> u8 {
+    match priority {
+        Priority::None => u8::MAX,
+        other => other as u8,
+    }
+}
+
+/// Rank statuses in workflow order
+fn status_rank(status: IssueStatus) -> u8 {
+    match status {
+        IssueStatus::Todo => 0,
+        IssueStatus::InProgress => 1,
+        IssueStatus::Done => 2,
+    }
+}
+
+/// Drop done issues whose last update is more than `days` days before `now`
+fn hide_closed_older_than(issues: &mut Vec<Issue>, days: u32, now: chrono::DateTime<chrono::Utc>) {
+    let cutoff = now - chrono::Duration::days(i64::from(days));
+    issues.retain(|issue| issue.status != IssueStatus::Done || issue.updated_at >= cutoff);
+}
+
+/// Parse `--field KEY=VALUE` arguments into pairs
+fn parse_field_filters(filters: &[String]) -> Result<Vec<(String, String)>> {
+    filters
+        .iter()
+        .map(|filter| match filter.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+            _ => anyhow::bail!("Invalid field filter '{}': expected KEY=VALUE", filter),
+        })
+        .collect()
+}
+
+/// Whether the issue has every field filter's key set to its value
+fn matches_field_filters(issue: &Issue, filters: &[(String, String)]) -> bool {
+    filters
+        .iter()
+        .all(|(key, value)| issue.fields.get(key) == Some(value))
+}
+
+/// Compile a user-supplied regular expression
+pub(super) fn compile_regex(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|err| anyhow::anyhow!("Invalid regex '{}': {}", pattern, err))
+}
+
+/// Whether `email` wrote any of the issue's comments
+fn has_comment_by(issue: &Issue, email: &str) -> bool {
+    issue
+        .comments
+        .iter()
+        .any(|comment| comment.author.has_email(email))
+}
+
+/// Keep issues where `email` authored any event
+fn filter_updated_by(store: &IssueStore, issues: Vec<Issue>, email: &str) -> Result<Vec<Issue>> {
+    let mut matching = Vec::new();
+    for issue in issues {
+        let participants = store.issue_participants(issue.id)?;
+        if participants.iter().any(|p| p.has_email(email)) {
+            matching.push(issue);
+        }
+    }
+    Ok(matching)
+}
+
+/// Render issues as a JSON array, on a single line unless `pretty` is set
+///
+/// With `fields` each issue only has the selected keys. With `envelope` the
+/// array is wrapped in a `ListEnvelope`.
+fn render_json(
+    issues: &[IssueJson],
+    fields: Option<&[String]>,
+    pretty: bool,
+    envelope: bool,
+) -> Result<String> {
+    match fields {
+        Some(fields) => {
+            let projected = issues
+                .iter()
+                .map(|issue| project_fields(issue, fields))
+                .collect::<Result<Vec<_>>>()?;
+            render_json_values(&projected, pretty, envelope)
+        }
+        None => render_json_values(issues, pretty, envelope),
+    }
+}
+
+fn render_json_values<T: Serialize>(items: &[T], pretty: bool, envelope: bool) -> Result<String> {
+    if envelope {
+        let envelope = ListEnvelope {
+            tool: "git-issue",
+            version: env!("CARGO_PKG_VERSION"),
+            schema: LIST_JSON_SCHEMA,
+            issues: items,
+        };
+        to_json(&envelope, pretty)
+    } else {
+        to_json(items, pretty)
+    }
+}
+
+/// Reject field names that `--fields` does not know about
+fn validate_fields(fields: &[String]) -> Result<()> {
+    for field in fields {
+        if !ISSUE_FIELDS.contains(&field.as_str()) {
+            anyhow::bail!(
+                "Unknown field '{}'. Valid fields: {}",
+                field,
+                ISSUE_FIELDS.join(", ")
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Serialize only the selected `fields` of an issue
+fn project_fields(
+    row: &IssueJson,
+    fields: &[String],
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let serde_json::Value::Object(mut all) = serde_json::to_value(row)? else {
+        anyhow::bail!("Issue #{} did not serialize to an object", row.issue.id);
+    };
+
+    let mut projected = serde_json::Map::new();
+    for field in fields {
+        if let Some(value) = all.remove(field) {
+            projected.insert(field.clone(), value);
+        }
+    }
+    Ok(projected)
+}
+
+fn to_json(value: &(impl Serialize + ?Sized), pretty: bool) -> Result<String> {
+    let json = if pretty {
+        serde_json::to_string_pretty(value)?
+    } else {
+        serde_json::to_string(value)?
+    };
+    Ok(json)
+}
+
+/// Columns of `list --format tsv`
+const TSV_COLUMNS: [&str; 8] = [
+    "id",
+    "status",
+    "priority",
+    "title",
+    "labels",
+    "assignees",
+    "created_at",
+    "updated_at",
+];
+
+/// Render issue ids one per line, without `#` or color
+fn render_ids(issues: &[Issue]) -> String {
+    issues
+        .iter()
+        .map(|issue| format!("{}\n", issue.id))
+        .collect()
+}
+
+/// Render issues as tab-separated values, one issue per line
+///
+/// Labels and assignee emails are comma-separated within their column.
+fn render_tsv(issues: &[Issue], header: bool) -> String {
+    let mut output = String::new();
+    if header {
+        output.push_str(&TSV_COLUMNS.join("\t"));
+        output.push('\n');
+    }
+
+    for issue in issues {
+        let assignees: Vec<&str> = issue.assignees.iter().map(|a| a.email.as_str()).collect();
+        let fields = [
+            issue.id.to_string(),
+            issue.status.to_string(),
+            issue.priority.to_string(),
+            issue.title.clone(),
+            issue.labels.join(","),
+            assignees.join(","),
+            issue.created_at.to_rfc3339(),
+            issue.updated_at.to_rfc3339(),
+        ];
+        let escaped: Vec<String> = fields.iter().map(|field| escape_tsv(field)).collect();
+        output.push_str(&escaped.join("\t"));
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Escape backslashes, tabs and line breaks so a field stays in its cell
+fn escape_tsv(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The issues `list` shows for `args`, before sorting
+fn filter_issues(store: &mut IssueStore, args: &ListArgs) -> Result<Vec<Issue>> {
+    let statuses = if let Some(status_filter) = &args.status {
+        Some(vec![parse_status(status_filter)?])
+    } else if args.all {
+        // Show all issues when --all flag is specified
+        None
+    } else if let Some(default_statuses) = configured_default_statuses(store)? {
+        // Project config overrides the built-in default
+        Some(default_statuses)
+    } else {
+        // By default, exclude "done" issues
+        Some(vec![IssueStatus::Todo, IssueStatus::InProgress])
+    };
+
+    // Filter on status through the index so excluded issues are never reconstructed
+    let mut filtered_issues = match &statuses {
+        Some(statuses) => store.list_issues_where(|entry| statuses.contains(&entry.status))?,
+        None => store.list_issues()?,
+    };
+
+    if !args.priority.is_empty() {
+        filtered_issues.retain(|issue| args.priority.contains(&issue.priority));
+    }
+
+    // Apply search filter if provided
+    if let Some(pattern) = args.search.as_deref().filter(|_| args.regex) {
+        let query = SearchQuery::new(SearchPattern::Regex(compile_regex(pattern)?));
+        filtered_issues.retain(|issue| query.matches(issue));
+    } else if let Some(search_term) = &args.search {
+        let search_lower = search_term.to_lowercase();
+        filtered_issues.retain(|issue| {
+            // Search in title
+            issue.title.to_lowercase().contains(&search_lower)
+                // Search in description
+                || issue.description.to_lowercase().contains(&search_lower)
+                // Search in labels
+                || issue.labels.iter().any(|label| label.to_lowercase().contains(&search_lower))
+        });
+    }
+
+    if let Some(pattern) = &args.title_regex {
+        let regex = compile_regex(pattern)?;
+        filtered_issues.retain(|issue| regex.is_match(&issue.title));
+    }
+
+    if let Some(email) = &args.updated_by {
+        filtered_issues = filter_updated_by(store, filtered_issues, email)?;
+    }
+
+    if let Some(email) = &args.commented_by {
+        filtered_issues.retain(|issue| has_comment_by(issue, email));
+    }
+
+    if !args.field_filters.is_empty() {
+        let filters = parse_field_filters(&args.field_filters)?;
+        filtered_issues.retain(|issue| matches_field_filters(issue, &filters));
+    }
+
+    if let Some(bucket) = args.age {
+        let days = configured_age_days(store, bucket)?;
+        let now = chrono::Utc::now();
+        filtered_issues.retain(|issue| bucket.matches(issue, days, now));
+    }
+
+    if let Some(days) = args.hide_closed_older_than {
+        hide_closed_older_than(&mut filtered_issues, days, chrono::Utc::now());
+    }
+
+    Ok(filtered_issues)
+}
+
+/// Read the `list.defaultStatuses` config key as a comma-separated list of statuses
+fn configured_default_statuses(store: &IssueStore) -> Result<Option<Vec<IssueStatus>>> {
+    let Some(value) = store.get_config("list.defaultStatuses") else {
+        return Ok(None);
+    };
+
+    let statuses = value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(parse_status)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(statuses))
+}
+
+/// Threshold in days for an age bucket, from its config key or the built-in default
+fn configured_age_days(store: &IssueStore, bucket: AgeBucket) -> Result<u32> {
+    let key = bucket.config_key();
+    match store.get_config(key) {
+        Some(value) => value
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid {} '{}': expected a number of days", key, value)),
+        None => Ok(bucket.default_days()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,9 +789,28 @@ mod tests {
         // Test search for "bug" - should find issues 1, 2, and 3 (not 4 because it's done by default)
         let _args = ListArgs {
             search: Some("bug".to_string()),
+            regex: false,
+            title_regex: None,
             status: None,
+            priority: Vec::new(),
             compact: true,
             all: false,
+            strict: false,
+            format: ListFormat::Text,
+            json: false,
+            pretty: false,
+            envelope: false,
+            fields: None,
+            template: None,
+            no_header: false,
+            pad_ids: false,
+            updated_by: None,
+            commented_by: None,
+            field_filters: Vec::new(),
+            age: None,
+            hide_closed_older_than: None,
+            sort: ListSort::Id,
+            lang: Lang::En,
         };
 
         // We can't easily capture stdout in the current implementation,
@@ -293,4 +954,559 @@ mod tests {
             "Should find 1 in-progress issue containing 'bug'"
         );
     }
+
+    /// Parse `git-issue list` followed by `extra` arguments
+    fn list_args(extra: &[&str]) -> ListArgs {
+        use clap::Parser;
+
+        let cli = crate::cli::Cli::parse_from(["git-issue", "list"].iter().chain(extra));
+        let crate::cli::Commands::List(args) = cli.command else {
+            panic!("Expected list command");
+        };
+        args
+    }
+
+    fn set_config(repo_path: &std::path::Path, key: &str, value: &str) {
+        std::process::Command::new("git")
+            .args(["config", key, value])
+            .current_dir(repo_path)
+            .output()
+            .expect("Failed to set git config");
+    }
+
+    #[test]
+    fn test_configured_default_statuses_unset() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+
+        assert!(
+            configured_default_statuses(&store)
+                .expect("Should read config")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_configured_default_statuses_includes_done() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        set_config(
+            &repo_path,
+            "list.defaultStatuses",
+            "todo, in-progress, done",
+        );
+
+        let mut store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let statuses = configured_default_statuses(&store)
+            .expect("Should read config")
+            .expect("Config should be set");
+        assert_eq!(
+            statuses,
+            vec![
+                IssueStatus::Todo,
+                IssueStatus::InProgress,
+                IssueStatus::Done
+            ]
+        );
+
+        // Closed issues are listed without --all
+        let shown = filter_issues(&mut store, &list_args(&[])).expect("Should filter issues");
+        assert_eq!(shown.len(), 5);
+        assert!(shown.iter().any(|issue| issue.status == IssueStatus::Done));
+
+        // --status still takes precedence over the configured default
+        let shown = filter_issues(&mut store, &list_args(&["--status", "todo"]))
+            .expect("Should filter issues");
+        assert!(shown.iter().all(|issue| issue.status == IssueStatus::Todo));
+    }
+
+    #[test]
+    fn test_configured_default_statuses_invalid() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        set_config(&repo_path, "list.defaultStatuses", "todo,bogus");
+
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        assert!(configured_default_statuses(&store).is_err());
+    }
+
+    #[test]
+    fn test_render_json_compact_and_pretty() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let issues = store.list_issues().expect("Failed to list issues");
+        let issues = json_rows(&issues, chrono::Utc::now());
+
+        let compact = render_json(&issues, None, false, false).expect("Should render JSON");
+        assert!(!compact.contains('\n'));
+        assert!(compact.starts_with("[{"));
+
+        let pretty = render_json(&issues, None, true, false).expect("Should render JSON");
+        assert!(pretty.contains("\n  {"));
+
+        let parsed: Vec<Issue> = serde_json::from_str(&compact).expect("Should parse JSON");
+        assert_eq!(parsed.len(), issues.len());
+    }
+
+    #[test]
+    fn test_filter_updated_by_matches_event_authors() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let mut store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let reviewer = crate::common::Identity::new("Reviewer", "reviewer@example.com");
+
+        // Issue 5 is touched by a second user who only adds a label
+        store
+            .add_label(5, "needs-review".to_string(), reviewer)
+            .expect("Failed to add label");
+
+        let issues = store.list_issues().expect("Failed to list issues");
+        let filtered = filter_updated_by(&store, issues, "Reviewer@Example.com")
+            .expect("Should filter issues");
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, 5);
+
+        // The creator of every issue matches all of them
+        let issues = store.list_issues().expect("Failed to list issues");
+        let filtered =
+            filter_updated_by(&store, issues, "test@example.com").expect("Should filter issues");
+        assert_eq!(filtered.len(), 5);
+    }
+
+    #[test]
+    fn test_has_comment_by_selects_commenters() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let mut store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let alice = crate::common::Identity::new("Alice", "alice@example.com");
+        let bob = crate::common::Identity::new("Bob", "bob@example.com");
+
+        store
+            .add_comment(1, "Seen this too".to_string(), alice.clone())
+            .unwrap();
+        store
+            .add_comment(2, "Fixed?".to_string(), bob.clone())
+            .unwrap();
+        store.add_comment(3, "Not yet".to_string(), alice).unwrap();
+        // Bob only labels issue 3, which does not count as commenting
+        store.add_label(3, "triaged".to_string(), bob).unwrap();
+
+        let issues = store.list_issues().expect("Failed to list issues");
+        let ids = |email: &str| -> Vec<crate::common::IssueId> {
+            issues
+                .iter()
+                .filter(|issue| has_comment_by(issue, email))
+                .map(|issue| issue.id)
+                .collect()
+        };
+        assert_eq!(ids("Alice@Example.com"), vec![1, 3]);
+        assert_eq!(ids("bob@example.com"), vec![2]);
+        assert!(ids("test@example.com").is_empty());
+    }
+
+    #[test]
+    fn test_sort_issues_breaks_ties_by_id() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let mut store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let author = create_test_identity();
+
+        for issue_id in [5, 2, 4] {
+            store
+                .update_priority(issue_id, Priority::High, author.clone())
+                .expect("Failed to set priority");
+        }
+        store
+            .update_priority(3, Priority::Urgent, author)
+            .expect("Failed to set priority");
+
+        let mut issues = store.list_issues().expect("Failed to list issues");
+        issues.reverse();
+        sort_issues(&mut issues, ListSort::Priority);
+        let ids: Vec<_> = issues.iter().map(|issue| issue.id).collect();
+        assert_eq!(ids, vec![3, 2, 4, 5, 1]);
+
+        // Sorting is stable across runs regardless of input order
+        issues.rotate_left(2);
+        sort_issues(&mut issues, ListSort::Priority);
+        let again: Vec<_> = issues.iter().map(|issue| issue.id).collect();
+        assert_eq!(again, ids);
+
+        sort_issues(&mut issues, ListSort::Status);
+        let ids: Vec<_> = issues.iter().map(|issue| issue.id).collect();
+        assert_eq!(ids, vec![1, 2, 3, 5, 4]);
+    }
+
+    #[test]
+    fn test_render_json_envelope() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let issues = store.list_issues().expect("Failed to list issues");
+        let issues = json_rows(&issues, chrono::Utc::now());
+
+        let json = render_json(&issues, None, false, true).expect("Should render JSON");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("Should parse JSON");
+
+        assert_eq!(value["tool"], "git-issue");
+        assert_eq!(value["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(value["schema"], LIST_JSON_SCHEMA);
+        let parsed: Vec<Issue> =
+            serde_json::from_value(value["issues"].clone()).expect("Should parse issues");
+        assert_eq!(parsed.len(), issues.len());
+    }
+
+    #[test]
+    fn test_render_json_fields_projection() {
+        use clap::Parser;
+
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let issues = store.list_issues().expect("Failed to list issues");
+        let rows = json_rows(&issues, chrono::Utc::now());
+
+        let fields = ["id".to_string(), "title".to_string(), "status".to_string()];
+        validate_fields(&fields).expect("Fields should be valid");
+        let json = render_json(&rows, Some(&fields), false, false).expect("Should render JSON");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("Should parse JSON");
+
+        let objects = value.as_array().expect("Should be an array");
+        assert_eq!(objects.len(), issues.len());
+        for (object, issue) in objects.iter().zip(&issues) {
+            let object = object.as_object().expect("Should be an object");
+            let mut keys: Vec<&str> = object.keys().map(String::as_str).collect();
+            keys.sort();
+            assert_eq!(keys, vec!["id", "status", "title"]);
+            assert_eq!(object["id"], issue.id);
+            assert_eq!(object["title"], issue.title.as_str());
+        }
+
+        let cli = crate::cli::Cli::parse_from(["git-issue", "list", "--fields", "id,title"]);
+        let crate::cli::Commands::List(args) = cli.command else {
+            panic!("Expected list command");
+        };
+        let err = handle_list(repo_path.clone(), args).expect_err("Text output has no fields");
+        assert_eq!(
+            err.to_string(),
+            "--fields is only supported with --format json"
+        );
+
+        for flags in [&["--pretty"][..], &["--format", "tsv", "--envelope"]] {
+            let cli = crate::cli::Cli::parse_from(["git-issue", "list"].iter().chain(flags));
+            let crate::cli::Commands::List(args) = cli.command else {
+                panic!("Expected list command");
+            };
+            let err = handle_list(repo_path.clone(), args).expect_err("Only JSON takes the flag");
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "{} is only supported with --format json",
+                    flags.last().unwrap()
+                )
+            );
+        }
+
+        let err = validate_fields(&["id".to_string(), "nope".to_string()])
+            .expect_err("Unknown field should be rejected");
+        assert!(err.to_string().contains("Unknown field 'nope'"));
+        assert!(err.to_string().contains("id, title, description"));
+    }
+
+    #[test]
+    fn test_hide_closed_older_than() {
+        let now = chrono::Utc::now();
+        let issue = |id, status, age_days| {
+            let mut issue = Issue::new(
+                id,
+                format!("Issue {}", id),
+                "".to_string(),
+                create_test_identity(),
+            );
+            issue.status = status;
+            issue.updated_at = now - chrono::Duration::days(age_days);
+            issue
+        };
+        let mut issues = vec![
+            issue(1, IssueStatus::Done, 2),
+            issue(2, IssueStatus::Done, 400),
+            issue(3, IssueStatus::Todo, 400),
+        ];
+
+        hide_closed_older_than(&mut issues, 30, now);
+
+        // Only the recently closed issue is kept among the done ones
+        let ids: Vec<_> = issues.iter().map(|issue| issue.id).collect();
+        assert_eq!(ids, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_render_tsv_golden() {
+        let timestamp = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let mut issue = Issue::new(
+            7,
+            "Tabs\there and\nnewlines".to_string(),
+            "".to_string(),
+            create_test_identity(),
+        );
+        issue.status = IssueStatus::InProgress;
+        issue.priority = Priority::High;
+        issue.labels = vec!["bug".to_string(), "ui".to_string()];
+        issue.assignees = vec![create_test_identity()];
+        issue.created_at = timestamp;
+        issue.updated_at = timestamp;
+
+        assert_eq!(
+            render_tsv(std::slice::from_ref(&issue), true),
+            "id\tstatus\tpriority\ttitle\tlabels\tassignees\tcreated_at\tupdated_at\n\
+             7\tin-progress\thigh\tTabs\\there and\\nnewlines\tbug,ui\ttest@example.com\t\
+             2024-05-01T12:00:00+00:00\t2024-05-01T12:00:00+00:00\n"
+        );
+
+        let without_header = render_tsv(&[issue], false);
+        assert!(without_header.starts_with("7\t"));
+        assert_eq!(without_header.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_field_filters() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let mut store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let author = create_test_identity();
+        store
+            .set_field(
+                1,
+                "severity".to_string(),
+                Some("high".to_string()),
+                author.clone(),
+            )
+            .unwrap();
+        store
+            .set_field(
+                2,
+                "severity".to_string(),
+                Some("low".to_string()),
+                author.clone(),
+            )
+            .unwrap();
+        store
+            .set_field(2, "customer".to_string(), Some("ACME".to_string()), author)
+            .unwrap();
+
+        let filter = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+            let filters = parse_field_filters(&args).unwrap();
+            let mut issues = store.list_issues().unwrap();
+            issues.retain(|issue| matches_field_filters(issue, &filters));
+            issues.iter().map(|issue| issue.id).collect::<Vec<_>>()
+        };
+
+        assert_eq!(filter(&["severity=high"]), vec![1]);
+        assert_eq!(filter(&["severity=low", "customer=ACME"]), vec![2]);
+        assert!(filter(&["severity=high", "customer=ACME"]).is_empty());
+        assert!(parse_field_filters(&["severity".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_render_ids() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let issues = store.list_issues().expect("Failed to list issues");
+
+        assert_eq!(render_ids(&issues[..3]), "1\n2\n3\n");
+        assert_eq!(render_ids(&[]), "");
+    }
+
+    #[test]
+    fn test_json_rows_include_derived_fields() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let mut store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let author = create_test_identity();
+
+        // Close issue 1, reopen it once, then close it again
+        for status in [IssueStatus::Done, IssueStatus::Todo, IssueStatus::Done] {
+            store
+                .update_issue_status(1, status, author.clone())
+                .expect("Failed to update status");
+        }
+
+        let issues = vec![store.get_issue(1).expect("Failed to get issue")];
+        let now = issues[0].created_at + chrono::Duration::seconds(90);
+        let rows = json_rows(&issues, now);
+        let json = render_json(&rows, None, false, false).expect("Should render JSON");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("Should parse JSON");
+
+        let closed_at = issues[0].closed_at.expect("Issue should be closed");
+        assert_eq!(
+            value[0]["closed_at"],
+            serde_json::to_value(closed_at).unwrap()
+        );
+        assert_eq!(value[0]["age_seconds"], 90);
+        assert_eq!(value[0]["reopen_count"], 1);
+        assert_eq!(value[0]["title"], issues[0].title);
+    }
+
+    #[test]
+    fn test_regex_search() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        let mut store = IssueStore::open(&repo_path).expect("Failed to open store");
+        store
+            .add_comment(
+                5,
+                "Blocked on the v2 API".to_string(),
+                create_test_identity(),
+            )
+            .expect("Failed to add comment");
+        let issues = store.list_issues().expect("Failed to list issues");
+        let ids = |pattern: &str| -> Vec<crate::common::IssueId> {
+            let regex = compile_regex(pattern).expect("Pattern should compile");
+            let query = SearchQuery::new(SearchPattern::Regex(regex));
+            issues
+                .iter()
+                .filter(|issue| query.matches(issue))
+                .map(|issue| issue.id)
+                .collect()
+        };
+
+        // Titles, descriptions and comments are all searched
+        assert_eq!(ids(r"(?i)bug\b"), vec![1, 3, 4]);
+        assert_eq!(ids(r"v\d+ API"), vec![5]);
+
+        // Anchors apply to each field separately
+        assert_eq!(ids("^Fix"), vec![1, 3]);
+        assert!(ids("^bug").is_empty());
+
+        let err = compile_regex("bug(").expect_err("Unbalanced group should be rejected");
+        assert!(err.to_string().starts_with("Invalid regex 'bug(':"));
+    }
+
+    #[test]
+    fn test_age_buckets() {
+        let now = chrono::DateTime::parse_from_rfc3339("2024-05-31T12:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let issue = |id, created_days_ago, updated_days_ago| {
+            let mut issue = Issue::new(
+                id,
+                format!("Issue {}", id),
+                "".to_string(),
+                create_test_identity(),
+            );
+            issue.created_at = now - chrono::Duration::days(created_days_ago);
+            issue.updated_at = now - chrono::Duration::days(updated_days_ago);
+            issue
+        };
+        let issues = [
+            issue(1, 1, 1),
+            issue(2, 20, 3),
+            issue(3, 90, 45),
+            issue(4, 60, 10),
+        ];
+        let ids = |bucket: AgeBucket, days: u32| -> Vec<crate::common::IssueId> {
+            issues
+                .iter()
+                .filter(|issue| bucket.matches(issue, days, now))
+                .map(|issue| issue.id)
+                .collect()
+        };
+
+        assert_eq!(ids(AgeBucket::New, 2), vec![1]);
+        assert_eq!(ids(AgeBucket::Recent, 7), vec![1, 2]);
+        assert_eq!(ids(AgeBucket::Stale, 30), vec![3]);
+        // Issue 4 is in no default bucket, but a tighter threshold makes it stale
+        assert_eq!(ids(AgeBucket::Stale, 7), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_configured_age_days() {
+        let (_temp_dir, repo_path) = setup_test_issues();
+        set_config(&repo_path, "list.ageStaleDays", "14");
+
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        assert_eq!(configured_age_days(&store, AgeBucket::Stale).unwrap(), 14);
+        assert_eq!(configured_age_days(&store, AgeBucket::New).unwrap(), 2);
+
+        set_config(&repo_path, "list.ageNewDays", "soon");
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let err = configured_age_days(&store, AgeBucket::New).unwrap_err();
+        assert!(err.to_string().contains("Invalid list.ageNewDays 'soon'"));
+    }
+
+    #[test]
+    fn test_issue_json_key_order() {
+        let created_at = "2024-01-02T03:04:05Z".parse().unwrap();
+        let author = Identity::new("Alice", "alice@example.com");
+        let issue = Issue {
+            id: 7,
+            title: "Title".to_string(),
+            description: "Body".to_string(),
+            status: IssueStatus::Done,
+            priority: Priority::High,
+            labels: vec!["bug".to_string()],
+            comments: vec![crate::common::Comment {
+                id: "7-1".to_string(),
+                content: "Hi".to_string(),
+                author: author.clone(),
+                on_behalf_of: Some(author.clone()),
+                created_at,
+            }],
+            created_at,
+            updated_at: created_at,
+            created_by: author.clone(),
+            created_on_behalf_of: Some(author.clone()),
+            assignees: vec![author],
+            closed_at: Some(created_at),
+            fields: BTreeMap::from([("severity".to_string(), "S1".to_string())]),
+            reopen_count: 2,
+        };
+
+        let row = IssueJson::new(&issue, created_at + chrono::Duration::seconds(60));
+        let json = serde_json::to_string(&row).expect("Should serialize");
+
+        // Consumers rely on this exact shape; change it deliberately or not at all
+        let alice = r#"{"name":"Alice","email":"alice@example.com"}"#;
+        let time = r#""2024-01-02T03:04:05Z""#;
+        assert_eq!(
+            json,
+            format!(
+                concat!(
+                    r#"{{"id":7,"title":"Title","description":"Body","status":"Done","#,
+                    r#""priority":"High","labels":["bug"],"#,
+                    r#""comments":[{{"id":"7-1","content":"Hi","author":{alice},"#,
+                    r#""on_behalf_of":{alice},"created_at":{time}}}],"#,
+                    r#""created_at":{time},"updated_at":{time},"created_by":{alice},"#,
+                    r#""created_on_behalf_of":{alice},"assignees":[{alice}],"closed_at":{time},"#,
+                    r#""fields":{{"severity":"S1"}},"age_seconds":60,"reopen_count":2}}"#,
+                ),
+                alice = alice,
+                time = time,
+            )
+        );
+
+        // Every key of a fully populated row can be picked with --fields, and nothing else
+        let value: serde_json::Value = serde_json::from_str(&json).expect("Valid JSON");
+        let mut keys: Vec<&str> = value
+            .as_object()
+            .expect("Should be an object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort();
+        let mut fields = ISSUE_FIELDS.to_vec();
+        fields.sort();
+        assert_eq!(keys, fields);
+    }
+
+    #[test]
+    fn test_list_priority_filter_parses_repeated_and_comma_values() {
+        use clap::Parser;
+
+        let cli =
+            crate::cli::Cli::parse_from(["git-issue", "list", "--priority", "high,1", "-p", "low"]);
+        let crate::cli::Commands::List(args) = cli.command else {
+            panic!("Expected list command");
+        };
+        assert_eq!(
+            args.priority,
+            vec![Priority::High, Priority::Urgent, Priority::Low]
+        );
+
+        let result = crate::cli::Cli::try_parse_from(["git-issue", "list", "--priority", "hihg"]);
+        assert!(result.is_err(), "Unknown priorities should be rejected");
+    }
 }
@@ -2,25 +2,62 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 
 mod assign;
+mod bundle;
+mod close;
+mod comment;
 mod create;
+mod doctor;
 mod edit;
+mod explain;
+mod feed;
+mod field;
+mod history;
 mod label;
 mod list;
+mod priority;
+mod reopen;
+mod search;
+#[cfg(feature = "server")]
+mod serve;
 mod show;
+mod stats;
 mod status;
 mod sync;
+mod version;
 
 pub use assign::{AssignArgs, UnassignArgs, handle_assign, handle_unassign};
+pub use bundle::{ExportArgs, ImportArgs, handle_export, handle_import};
+pub use close::{CloseArgs, handle_close};
+pub use comment::{CommentArgs, handle_comment};
 pub use create::{CreateArgs, handle_create};
+pub use doctor::{DoctorArgs, handle_doctor};
 pub use edit::{EditArgs, handle_edit};
+pub use feed::{FeedArgs, handle_feed};
+pub use field::{FieldArgs, handle_field};
+pub use history::{HistoryArgs, handle_history};
 pub use label::{LabelArgs, handle_label};
 pub use list::{ListArgs, handle_list};
+pub use priority::{PriorityArgs, handle_priority};
+pub use reopen::{ReopenArgs, handle_reopen};
+pub use search::{SearchArgs, handle_search};
+#[cfg(feature = "server")]
+pub use serve::{ServeArgs, handle_serve};
 pub use show::{ShowArgs, handle_show};
+pub use stats::{StatsArgs, handle_stats};
 pub use status::{StatusArgs, handle_status};
-pub use sync::{RefComparisonResult, SyncArgs, SyncRef, SyncSummary, handle_sync};
+pub use sync::{SyncArgs, handle_sync};
+pub use version::{VersionArgs, handle_version};
 
-use crate::common::{EnvProvider, Identity, IssueStatus};
+use crate::cli::output::{StatusStyles, success_message, warning_message};
+use crate::common::suggest::did_you_mean;
+use crate::common::{
+    EnvProvider, Identity, Issue, IssueId, IssueStatus, SystemEnvProvider, markdown,
+};
 use crate::storage::IssueStore;
+use crate::storage::contributors::{CONTRIBUTORS_PATH, Contributors};
+use crate::storage::errors::{GitError, StorageError};
+use std::collections::HashSet;
+use std::io::{BufRead, Write};
 
 #[derive(Parser)]
 #[command(name = "git-issue")]
@@ -30,9 +67,17 @@ pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
 
-    /// Repository path (defaults to current directory)
+    /// Repository path (defaults to current directory), or a remote URL for read-only use
     #[arg(short, long, global = true)]
     pub repo: Option<std::path::PathBuf>,
+
+    /// Answer yes to all confirmation prompts (also set by GIT_ISSUE_YES=1)
+    #[arg(short = 'y', long, visible_alias = "assume-yes", global = true)]
+    pub yes: bool,
+
+    /// Print how repository, identity, remote and config resolve, then exit without running
+    #[arg(long, global = true)]
+    pub explain: bool,
 }
 
 #[derive(Subcommand)]
@@ -41,36 +86,159 @@ pub enum Commands {
     Create(CreateArgs),
     /// List issues
     List(ListArgs),
+    /// Search titles, descriptions and comments
+    Search(SearchArgs),
     /// Show issue details
     Show(ShowArgs),
+    /// Show the event history of an issue
+    History(HistoryArgs),
+    /// Show the latest activity across all issues
+    Feed(FeedArgs),
+    /// Summarize issue counts and time-to-close
+    Stats(StatsArgs),
     /// Change issue status
     Status(StatusArgs),
+    /// Mark an issue as done
+    Close(CloseArgs),
+    /// Reopen a done issue
+    Reopen(ReopenArgs),
+    /// Change issue priority, on one or many issues
+    Priority(PriorityArgs),
     /// Edit an issue
     Edit(EditArgs),
     /// Manage issue labels
     Label(LabelArgs),
+    /// Get, set or unset a custom field on an issue
+    Field(FieldArgs),
     /// Assign users to an issue
     Assign(AssignArgs),
     /// Unassign users from an issue
     Unassign(UnassignArgs),
+    /// Add a comment to an issue
+    Comment(CommentArgs),
     /// Sync issues with remote repository
     Sync(SyncArgs),
+    /// Export issues for transfer without a remote
+    Export(ExportArgs),
+    /// Import issues from an export
+    Import(ImportArgs),
+    /// Check issue storage for problems and optionally repair them
+    Doctor(DoctorArgs),
+    /// Serve a read-only HTML view of the issues over HTTP
+    #[cfg(feature = "server")]
+    Serve(ServeArgs),
+    /// Print version information
+    Version(VersionArgs),
 }
 
 pub fn run_command(cli: Cli) -> Result<()> {
-    let repo_path = cli.repo.unwrap_or_else(|| std::env::current_dir().unwrap());
+    let (repo_path, _remote_clone) = resolve_repo(cli.repo, &cli.command)?;
+    let assume_yes = assume_yes(cli.yes, SystemEnvProvider);
+
+    if cli.explain {
+        let report = explain::explain_resolution(&repo_path, assume_yes, SystemEnvProvider)
+            .map_err(|err| explain_error(err, &repo_path))?;
+        print!("{}", report);
+        return Ok(());
+    }
+
+    let result = match cli.command {
+        Commands::Create(args) => handle_create(repo_path.clone(), args),
+        Commands::List(args) => handle_list(repo_path.clone(), args),
+        Commands::Search(args) => handle_search(repo_path.clone(), args),
+        Commands::Show(args) => handle_show(repo_path.clone(), args),
+        Commands::History(args) => handle_history(repo_path.clone(), args),
+        Commands::Feed(args) => handle_feed(repo_path.clone(), args),
+        Commands::Stats(args) => handle_stats(repo_path.clone(), args),
+        Commands::Status(args) => handle_status(repo_path.clone(), args),
+        Commands::Close(args) => handle_close(repo_path.clone(), args),
+        Commands::Reopen(args) => handle_reopen(repo_path.clone(), args),
+        Commands::Priority(args) => handle_priority(repo_path.clone(), args),
+        Commands::Edit(args) => handle_edit(repo_path.clone(), args),
+        Commands::Label(args) => handle_label(repo_path.clone(), args),
+        Commands::Field(args) => handle_field(repo_path.clone(), args),
+        Commands::Assign(args) => handle_assign(repo_path.clone(), args),
+        Commands::Unassign(args) => handle_unassign(repo_path.clone(), args),
+        Commands::Comment(args) => handle_comment(repo_path.clone(), args),
+        Commands::Sync(args) => handle_sync(repo_path.clone(), args),
+        Commands::Export(args) => handle_export(repo_path.clone(), args),
+        Commands::Import(args) => handle_import(repo_path.clone(), args),
+        Commands::Doctor(args) => handle_doctor(repo_path.clone(), args, assume_yes),
+        #[cfg(feature = "server")]
+        Commands::Serve(args) => handle_serve(repo_path.clone(), args),
+        Commands::Version(args) => handle_version(args),
+    };
+
+    result.map_err(|err| explain_error(err, &repo_path))
+}
+
+/// Whether `--repo` names a remote (`scheme://...` or scp-like `user@host:path`)
+fn is_remote_url(repo: &std::path::Path) -> bool {
+    let repo = repo.to_string_lossy();
+    repo.contains("://")
+        || repo
+            .split_once(':')
+            .is_some_and(|(host, _)| host.contains('@') && !host.contains('/'))
+}
 
-    match cli.command {
-        Commands::Create(args) => handle_create(repo_path, args),
-        Commands::List(args) => handle_list(repo_path, args),
-        Commands::Show(args) => handle_show(repo_path, args),
-        Commands::Status(args) => handle_status(repo_path, args),
-        Commands::Edit(args) => handle_edit(repo_path, args),
-        Commands::Label(args) => handle_label(repo_path, args),
-        Commands::Assign(args) => handle_assign(repo_path, args),
-        Commands::Unassign(args) => handle_unassign(repo_path, args),
-        Commands::Sync(args) => handle_sync(repo_path, args),
+/// Commands that only read issues, and so may run against a remote URL
+fn is_read_only(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::List(_)
+            | Commands::Show(_)
+            | Commands::History(_)
+            | Commands::Feed(_)
+            | Commands::Stats(_)
+            | Commands::Search(_)
+            | Commands::Export(_)
+    )
+}
+
+/// Resolve `--repo` to a local path
+///
+/// A remote URL is fetched into a temporary repository, which is removed when
+/// the returned guard is dropped.
+fn resolve_repo(
+    repo: Option<std::path::PathBuf>,
+    command: &Commands,
+) -> Result<(std::path::PathBuf, Option<tempfile::TempDir>)> {
+    let Some(repo) = repo else {
+        return Ok((std::env::current_dir()?, None));
+    };
+    if !is_remote_url(&repo) {
+        return Ok((repo, None));
     }
+
+    // git would read a leading dash as an option rather than a URL
+    if repo.to_string_lossy().starts_with('-') {
+        anyhow::bail!("'{}' is not a valid remote URL", repo.display());
+    }
+
+    if !is_read_only(command) {
+        anyhow::bail!(
+            "'{}' is a remote URL, which is read-only; clone it to make changes",
+            repo.display()
+        );
+    }
+
+    let temp_dir = tempfile::tempdir()?;
+    IssueStore::open_remote(&repo.to_string_lossy(), temp_dir.path())?;
+    Ok((temp_dir.path().to_path_buf(), Some(temp_dir)))
+}
+
+/// Add actionable hints to errors users commonly run into
+fn explain_error(err: anyhow::Error, repo_path: &std::path::Path) -> anyhow::Error {
+    if let Some(StorageError::Git(GitError::RepositoryNotFound { .. })) =
+        err.downcast_ref::<StorageError>()
+    {
+        return anyhow::anyhow!(
+            "'{}' is not a git repository. Run `git init` there first, \
+             or point --repo at an existing worktree root or .git directory",
+            repo_path.display()
+        );
+    }
+    err
 }
 
 /// Get author identity from provided arguments or environment variables
@@ -80,24 +248,339 @@ pub(crate) fn get_author_identity(
     store: &IssueStore,
     env_provider: impl EnvProvider,
 ) -> Result<Identity> {
-    let name = name.unwrap_or_else(|| {
-        env_provider
-            .get_var("GIT_AUTHOR_NAME")
-            .or_else(|| store.get_config("user.name"))
-            .or_else(|| env_provider.get_var("USER"))
-            .unwrap_or_else(|| "Unknown".to_string())
-    });
+    let name = resolve_setting(
+        name,
+        &["GIT_AUTHOR_NAME"],
+        "user.name",
+        store,
+        &env_provider,
+    )
+    .or_else(|| env_provider.get_var("USER"))
+    .unwrap_or_else(|| "Unknown".to_string());
 
-    let email = email.unwrap_or_else(|| {
-        env_provider
-            .get_var("GIT_AUTHOR_EMAIL")
-            .or_else(|| store.get_config("user.email"))
-            .unwrap_or_else(|| "unknown@localhost".to_string())
-    });
+    let email = resolve_setting(
+        email,
+        &["GIT_AUTHOR_EMAIL"],
+        "user.email",
+        store,
+        &env_provider,
+    )
+    .unwrap_or_else(|| "unknown@localhost".to_string());
 
     Ok(Identity::new(&name, &email))
 }
 
+/// Resolve a setting through its layers, returning the first one that is set
+///
+/// Precedence is: CLI flag, then the environment variables in order, then the
+/// store's settings (`.git-issue/config.toml` before git config). Callers supply
+/// the built-in default for when every layer is empty.
+pub(crate) fn resolve_setting(
+    flag: Option<String>,
+    env_vars: &[&str],
+    key: &str,
+    store: &IssueStore,
+    env_provider: &impl EnvProvider,
+) -> Option<String> {
+    flag.or_else(|| env_vars.iter().find_map(|var| env_provider.get_var(var)))
+        .or_else(|| store.get_config(key))
+}
+
+/// Environment variable that answers yes to all confirmation prompts
+pub(crate) const ASSUME_YES_VAR: &str = "GIT_ISSUE_YES";
+
+/// Whether confirmation prompts should be skipped, from `--yes` or `GIT_ISSUE_YES`
+pub(crate) fn assume_yes(flag: bool, env_provider: impl EnvProvider) -> bool {
+    flag || env_provider.get_var(ASSUME_YES_VAR).is_some_and(|value| {
+        matches!(
+            value.trim().to_lowercase().as_str(),
+            "1" | "y" | "yes" | "true"
+        )
+    })
+}
+
+/// Ask a yes/no question on stdin, or answer yes without asking when `assume_yes` is set
+pub(crate) fn confirm(prompt: &str, assume_yes: bool) -> bool {
+    assume_yes || ask(prompt, &mut std::io::stdin().lock())
+}
+
+/// Ask a yes/no question, defaulting to no
+fn ask(question: &str, input: &mut impl BufRead) -> bool {
+    print!("{} [y/N] ", question);
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    input.read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// URL template for hyperlinking issue IDs, from the `issue.url` config
+///
+/// Only returned when stdout is a terminal with styling enabled, so piped output stays plain.
+pub(crate) fn issue_url_template(store: &IssueStore) -> Option<String> {
+    hyperlink_template(store.get_config("issue.url"), console::colors_enabled())
+}
+
+/// Status labels and colors, overridden by `status.<status>.label` and `status.<status>.color`
+///
+/// `.git-issue/config.toml` takes precedence over git config, e.g.
+/// `[status.todo]` with `label = "Backlog"` and `color = "cyan"`.
+pub(crate) fn status_styles(store: &IssueStore) -> Result<StatusStyles> {
+    let setting = |key: String| resolve_setting(None, &[], &key, store, &SystemEnvProvider);
+
+    let mut styles = StatusStyles::default();
+    for status in [
+        IssueStatus::Todo,
+        IssueStatus::InProgress,
+        IssueStatus::Done,
+    ] {
+        let label = setting(format!("status.{}.label", status));
+        let color = setting(format!("status.{}.color", status));
+        styles
+            .configure(&status, label, color.as_deref())
+            .map_err(anyhow::Error::msg)?;
+    }
+    Ok(styles)
+}
+
+fn hyperlink_template(template: Option<String>, enabled: bool) -> Option<String> {
+    template.filter(|template| enabled && !template.is_empty())
+}
+
+/// Check an email address, as given to `assign` or `edit --assignee`
+///
+/// By default any address containing `@` is accepted, as older data may rely on it.
+/// With `strict` the address needs a non-empty local part and a dotted domain.
+pub(crate) fn validate_email(email: &str, strict: bool) -> Result<()> {
+    let valid = if strict {
+        is_strict_email(email)
+    } else {
+        email.contains('@')
+    };
+    if !valid {
+        anyhow::bail!("Invalid email format: {}", email);
+    }
+    Ok(())
+}
+
+fn is_strict_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && !email.chars().any(char::is_whitespace)
+        && domain.contains('.')
+        && domain.split('.').all(|part| !part.is_empty())
+}
+
+/// Whether strict email validation is on, from `--strict-email` or `validation.strictEmail`
+pub(crate) fn strict_email_enabled(flag: bool, store: &IssueStore) -> bool {
+    flag || store
+        .get_config_bool("validation.strictEmail")
+        .unwrap_or(false)
+}
+
+/// Whether descriptions are linted, from `--lint-description` or `validation.lintDescription`
+pub(crate) fn lint_description_enabled(flag: bool, store: &IssueStore) -> bool {
+    flag || store
+        .get_config_bool("validation.lintDescription")
+        .unwrap_or(false)
+}
+
+/// Print a warning for each markdown problem found in a description
+pub(crate) fn warn_description_lint(description: &str) {
+    for warning in markdown::lint(description) {
+        println!("{}", warning_message(&format!("description {}", warning)));
+    }
+}
+
+/// Reject an assignee missing from the project's contributors allowlist
+///
+/// Without a `.git-issue/contributors.toml` every email is accepted, as is any
+/// email when `force` is set.
+pub(crate) fn check_contributor(
+    contributors: Option<&Contributors>,
+    email: &str,
+    force: bool,
+) -> Result<()> {
+    let Some(contributors) = contributors else {
+        return Ok(());
+    };
+    if force || contributors.contains(email) {
+        return Ok(());
+    }
+
+    let known: Vec<&str> = contributors.emails().collect();
+    anyhow::bail!(
+        "Unknown contributor '{}'{}; add it to {} or pass --force",
+        email,
+        did_you_mean(email, &known),
+        CONTRIBUTORS_PATH
+    )
+}
+
+/// Warn about open issues with the same title, ignoring case and spacing
+///
+/// `exclude` is the issue being edited, which never counts as its own duplicate.
+pub(crate) fn warn_duplicate_titles(
+    store: &IssueStore,
+    title: &str,
+    exclude: Option<IssueId>,
+) -> Result<()> {
+    if let Some(warning) = duplicate_title_warning(&store.list_issues()?, title, exclude) {
+        println!("{}", warning_message(&warning));
+    }
+    Ok(())
+}
+
+/// Describe the open issues among `issues` whose title matches `title`
+fn duplicate_title_warning(
+    issues: &[Issue],
+    title: &str,
+    exclude: Option<IssueId>,
+) -> Option<String> {
+    let key = title_key(title);
+    let duplicates: Vec<String> = issues
+        .iter()
+        .filter(|issue| Some(issue.id) != exclude && issue.status != IssueStatus::Done)
+        .filter(|issue| title_key(&issue.title) == key)
+        .map(|issue| format!("#{} \"{}\"", issue.id, issue.title))
+        .collect();
+
+    match duplicates.as_slice() {
+        [] => None,
+        [duplicate] => Some(format!("Possible duplicate of {}", duplicate)),
+        _ => Some(format!("Possible duplicates: {}", duplicates.join(", "))),
+    }
+}
+
+/// A title lowercased with runs of whitespace collapsed, for comparing titles
+fn title_key(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Add the words given after `--` as a comment on the issue, if there are any
+///
+/// Lets any mutation carry a note, as in `status 3 done -- fixed in PR 42`.
+pub(crate) fn add_trailing_comment(
+    store: &mut IssueStore,
+    issue_id: IssueId,
+    words: &[String],
+    author: Identity,
+) -> Result<()> {
+    let content = words.join(" ").trim().to_string();
+    if content.is_empty() {
+        return Ok(());
+    }
+
+    let comment_id = store.add_comment(issue_id, content, author)?;
+    println!(
+        "{}",
+        success_message(&format!(
+            "Added comment {} to issue #{}",
+            comment_id, issue_id
+        ))
+    );
+    Ok(())
+}
+
+/// Keyword accepted in place of an assignee email to mean the current user
+pub(crate) const SELF_ASSIGNEE: &str = "@me";
+
+/// Expand the `@me` keyword to the current user's email
+pub(crate) fn resolve_assignee(assignee: &str, self_email: &str) -> String {
+    if assignee.trim() == SELF_ASSIGNEE {
+        self_email.to_string()
+    } else {
+        assignee.to_string()
+    }
+}
+
+/// Read a description from a `<file>#<heading>` spec, or the whole file if no heading is given
+pub(crate) fn read_description_from(spec: &str) -> Result<String> {
+    let (path, heading) = match spec.rsplit_once('#') {
+        Some((path, heading)) if !path.is_empty() => (path, Some(heading)),
+        _ => (spec, None),
+    };
+
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path, e))?;
+
+    match heading {
+        Some(heading) => markdown::extract_section(&content, heading)
+            .ok_or_else(|| anyhow::anyhow!("Heading '{}' not found in '{}'", heading, path)),
+        None => Ok(content.trim().to_string()),
+    }
+}
+
+/// Whether `labels.normalize` is set, so added labels are normalized
+pub(crate) fn labels_normalized(store: &IssueStore) -> bool {
+    store.get_config_bool("labels.normalize").unwrap_or(false)
+}
+
+/// Normalize a label: lowercase, with runs of non-alphanumeric characters replaced by `-`
+///
+/// `Bug Fix` becomes `bug-fix`. Leading and trailing separators are dropped.
+pub(crate) fn normalize_label(label: &str) -> String {
+    let mut normalized = String::with_capacity(label.len());
+    let mut pending_dash = false;
+
+    for c in label.chars() {
+        if c.is_alphanumeric() {
+            if pending_dash && !normalized.is_empty() {
+                normalized.push('-');
+            }
+            pending_dash = false;
+            normalized.extend(c.to_lowercase());
+        } else {
+            pending_dash = true;
+        }
+    }
+
+    normalized
+}
+
+/// Normalize the labels in `labels` that are not in `existing`, when `normalize` is set
+///
+/// Labels the issue already has are kept as they are, so turning the setting on
+/// does not rewrite them on the next edit.
+pub(crate) fn normalize_new_labels(labels: &mut Vec<String>, existing: &[String], normalize: bool) {
+    if !normalize {
+        return;
+    }
+    let mut seen = HashSet::new();
+    labels.retain_mut(|label| {
+        if !existing.contains(label) {
+            *label = normalize_label(label);
+        }
+        !label.is_empty() && seen.insert(label.clone())
+    });
+}
+
+/// Describe labels in `added` that differ only in case from another label in `labels`
+///
+/// `labels` is the full label set after the change; each collision is reported once.
+pub(crate) fn label_case_collisions(added: &[String], labels: &[String]) -> Vec<String> {
+    let mut collisions = Vec::new();
+    for label in added {
+        if let Some(other) = labels
+            .iter()
+            .find(|other| *other != label && other.eq_ignore_ascii_case(label))
+        {
+            collisions.push(format!(
+                "Label '{}' differs only in case from '{}'",
+                label, other
+            ));
+        }
+    }
+    collisions
+}
+
 /// Parse status string into IssueStatus enum
 pub(crate) fn parse_status(status_str: &str) -> Result<IssueStatus> {
     match status_str.to_lowercase().as_str() {
@@ -105,8 +588,334 @@ pub(crate) fn parse_status(status_str: &str) -> Result<IssueStatus> {
         "in-progress" | "inprogress" | "progress" => Ok(IssueStatus::InProgress),
         "done" | "closed" | "complete" => Ok(IssueStatus::Done),
         _ => anyhow::bail!(
-            "Invalid status '{}'. Valid options: todo, in-progress, done",
-            status_str
+            "Invalid status '{}'{}. Valid options: todo, in-progress, done",
+            status_str,
+            did_you_mean(status_str, &["todo", "in-progress", "done"])
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_ask_defaults_to_no() {
+        assert!(ask("Drop?", &mut std::io::Cursor::new("y\n")));
+        assert!(ask("Drop?", &mut std::io::Cursor::new("YES\n")));
+        assert!(!ask("Drop?", &mut std::io::Cursor::new("\n")));
+        assert!(!ask("Drop?", &mut std::io::Cursor::new("")));
+    }
+
+    #[test]
+    fn test_validate_email_strict() {
+        for email in ["user@example.com", "first.last+tag@mail.example.org"] {
+            assert!(
+                validate_email(email, true).is_ok(),
+                "{} should be accepted",
+                email
+            );
+        }
+        for email in [
+            "a@",
+            "@b",
+            "user@localhost",
+            "user@example.",
+            "user@.com",
+            "user@@example.com",
+            "us er@example.com",
+            "no-at-sign",
+        ] {
+            assert!(
+                validate_email(email, true).is_err(),
+                "{} should be rejected",
+                email
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_email_lenient_by_default() {
+        assert!(validate_email("a@", false).is_ok());
+        assert!(validate_email("@b", false).is_ok());
+        assert!(validate_email("user@localhost", false).is_ok());
+        assert!(validate_email("no-at-sign", false).is_err());
+    }
+
+    #[test]
+    fn test_hyperlink_template_requires_terminal_and_config() {
+        let template = || Some("https://tracker/{id}".to_string());
+        assert_eq!(hyperlink_template(template(), true), template());
+        assert_eq!(hyperlink_template(template(), false), None);
+        assert_eq!(hyperlink_template(None, true), None);
+        assert_eq!(hyperlink_template(Some(String::new()), true), None);
+    }
+
+    #[test]
+    fn test_assume_yes_from_flag_or_env() {
+        use crate::common::MockEnvProvider;
+
+        assert!(assume_yes(true, MockEnvProvider::new()));
+        assert!(!assume_yes(false, MockEnvProvider::new()));
+
+        for (value, expected) in [
+            ("1", true),
+            ("yes", true),
+            ("TRUE", true),
+            ("0", false),
+            ("", false),
+        ] {
+            let mut env = MockEnvProvider::new();
+            env.set_var(ASSUME_YES_VAR, value);
+            assert_eq!(
+                assume_yes(false, env),
+                expected,
+                "GIT_ISSUE_YES={:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_command_not_a_git_repo() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo = temp_dir.path().to_str().unwrap();
+
+        let cli = Cli::parse_from(["git-issue", "--repo", repo, "list"]);
+        let err = run_command(cli).expect_err("Listing outside a repository should fail");
+
+        let message = err.to_string();
+        assert!(message.contains("is not a git repository"));
+        assert!(message.contains("git init"));
+        assert!(message.contains(repo));
+    }
+
+    #[test]
+    fn test_run_command_accepts_worktree_and_git_dir() {
+        let (temp_dir, _repo) = setup_temp_repo();
+        let worktree = temp_dir.path();
+
+        for path in [worktree.to_path_buf(), worktree.join(".git")] {
+            let cli = Cli::parse_from(["git-issue", "--repo", path.to_str().unwrap(), "list"]);
+            assert!(
+                run_command(cli).is_ok(),
+                "--repo {} should work",
+                path.display()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_status_suggests_close_match() {
+        let err = parse_status("inprogres").unwrap_err().to_string();
+        assert!(err.contains("did you mean 'in-progress'?"), "{}", err);
+
+        let err = parse_status("xyzzy").unwrap_err().to_string();
+        assert!(!err.contains("did you mean"), "{}", err);
+    }
+
+    #[test]
+    fn test_remote_url_repo_is_read_only() {
+        let (temp_dir, _repo) = setup_temp_repo();
+        let repo_path = temp_dir.path();
+        let mut store = IssueStore::open(repo_path).unwrap();
+        store
+            .create_issue(
+                "Remote issue".to_string(),
+                String::new(),
+                Identity::new("Test User", "test@example.com"),
+            )
+            .unwrap();
+        let url = format!("file://{}", repo_path.display());
+
+        let cli = Cli::parse_from(["git-issue", "--repo", &url, "list"]);
+        let (clone_path, _clone) = resolve_repo(cli.repo, &cli.command).unwrap();
+        let issues = IssueStore::open(&clone_path)
+            .unwrap()
+            .list_issues()
+            .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].title, "Remote issue");
+
+        let cli = Cli::parse_from(["git-issue", "--repo", &url, "search", "Remote"]);
+        assert!(resolve_repo(cli.repo, &cli.command).is_ok());
+
+        let cli = Cli::parse_from(["git-issue", "--repo", &url, "create", "New"]);
+        let err = resolve_repo(cli.repo, &cli.command).unwrap_err();
+        assert!(err.to_string().contains("read-only"), "{}", err);
+    }
+
+    #[test]
+    fn test_remote_url_repo_rejects_option_like_values() {
+        let cli = Cli::parse_from(["git-issue", "--repo=--upload-pack=touch pwned://x", "list"]);
+        let err = resolve_repo(cli.repo, &cli.command).unwrap_err();
+        assert!(
+            err.to_string().contains("not a valid remote URL"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_resolve_setting_precedence() {
+        use crate::common::MockEnvProvider;
+
+        let (temp_dir, _repo) = setup_temp_repo();
+        let repo_path = temp_dir.path();
+        let git_config = |key: &str, value: &str| {
+            std::process::Command::new("git")
+                .args(["config", key, value])
+                .current_dir(repo_path)
+                .status()
+                .unwrap();
+        };
+        git_config("issue.url", "https://git.example.com/{id}");
+        git_config("list.defaultStatuses", "todo");
+        git_config("user.name", "Git Config");
+        std::fs::create_dir_all(repo_path.join(".git-issue")).unwrap();
+        std::fs::write(
+            repo_path.join(".git-issue/config.toml"),
+            "[issue]\nurl = \"https://project.example.com/{id}\"\n\n[user]\nname = \"Project File\"\n",
+        )
+        .unwrap();
+        let store = IssueStore::open(repo_path).unwrap();
+        let mut env = MockEnvProvider::new();
+        env.set_var("ISSUE_URL", "https://env.example.com/{id}");
+
+        let resolve = |flag: Option<&str>, env: &MockEnvProvider| {
+            resolve_setting(
+                flag.map(str::to_string),
+                &["ISSUE_URL"],
+                "issue.url",
+                &store,
+                env,
+            )
+        };
+        assert_eq!(
+            resolve(Some("https://flag.example.com/{id}"), &env).as_deref(),
+            Some("https://flag.example.com/{id}")
+        );
+        assert_eq!(
+            resolve(None, &env).as_deref(),
+            Some("https://env.example.com/{id}")
+        );
+        let empty = MockEnvProvider::new();
+        assert_eq!(
+            resolve(None, &empty).as_deref(),
+            Some("https://project.example.com/{id}")
+        );
+
+        // Git config applies when the project file is silent on a key
+        assert_eq!(
+            store.get_config("list.defaultStatuses").as_deref(),
+            Some("todo")
+        );
+
+        // The project file never sets who the author is
+        let identity = get_author_identity(None, None, &store, MockEnvProvider::new()).unwrap();
+        assert_eq!(identity.name, "Git Config");
+    }
+
+    #[test]
+    fn test_duplicate_title_warning() {
+        let (temp_dir, _repo) = setup_temp_repo();
+        let repo_path = temp_dir.path().to_path_buf();
+        let create = |title: &str| {
+            let args = CreateArgs {
+                title: title.to_string(),
+                description: None,
+                description_from: None,
+                stdin: false,
+                author_name: Some("Test User".to_string()),
+                author_email: Some("test@example.com".to_string()),
+                on_behalf_of: None,
+                priority: None,
+                id: None,
+                force: false,
+                no_assign_self: false,
+                lint_description: false,
+                no_dup_check: false,
+                create_repo: false,
+            };
+            handle_create(repo_path.clone(), args).expect("Create should succeed");
+        };
+
+        create("Fix login bug");
+        let store = IssueStore::open(&repo_path).unwrap();
+        let second = "  fix LOGIN   bug";
+        assert_eq!(
+            duplicate_title_warning(&store.list_issues().unwrap(), second, None).as_deref(),
+            Some("Possible duplicate of #1 \"Fix login bug\"")
+        );
+
+        // The duplicate is still created, and an issue is not its own duplicate
+        create(second);
+        let issues = store.list_issues().unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            duplicate_title_warning(&issues, second, Some(2)).as_deref(),
+            Some("Possible duplicate of #1 \"Fix login bug\"")
+        );
+        assert_eq!(
+            duplicate_title_warning(&issues, "Fix login bug", None).as_deref(),
+            Some("Possible duplicates: #1 \"Fix login bug\", #2 \"fix LOGIN   bug\"")
+        );
+
+        // Closed issues are not reported
+        let mut store = IssueStore::open(&repo_path).unwrap();
+        store
+            .update_issue_status(1, IssueStatus::Done, create_test_identity())
+            .unwrap();
+        let issues = store.list_issues().unwrap();
+        assert_eq!(duplicate_title_warning(&issues, second, Some(2)), None);
+    }
+
+    #[test]
+    fn test_status_styles_from_project_config() {
+        let (temp_dir, _repo) = setup_temp_repo();
+        let config_path = temp_dir
+            .path()
+            .join(crate::storage::project_config::PROJECT_CONFIG_PATH);
+        std::fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &config_path,
+            "[status.todo]\nlabel = \"Backlog\"\ncolor = \"cyan\"\n\n[status.done]\ncolor = \"208\"\n",
+        )
+        .unwrap();
+
+        let mut store = IssueStore::open(temp_dir.path()).unwrap();
+        let styles = status_styles(&store).expect("Styles should load");
+        let todo = styles.get(&IssueStatus::Todo);
+        assert_eq!(
+            (todo.label.as_str(), todo.color),
+            ("Backlog", console::Color::Cyan)
+        );
+        let done = styles.get(&IssueStatus::Done);
+        assert_eq!(
+            (done.label.as_str(), done.color),
+            ("DONE", console::Color::Color256(208))
+        );
+
+        // Only the display changes; the stored status is still canonical
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), create_test_identity())
+            .unwrap();
+        let issue = store.get_issue(issue_id).unwrap();
+        assert_eq!(issue.status, IssueStatus::Todo);
+        let rendered = crate::cli::output::format_issue_compact(&issue, 0, None, &styles);
+        assert_eq!(
+            console::strip_ansi_codes(&rendered),
+            format!("#{} [Backlog] Title", issue_id)
+        );
+
+        std::fs::write(&config_path, "[status.in-progress]\ncolor = \"teal\"\n").unwrap();
+        let store = IssueStore::open(temp_dir.path()).unwrap();
+        let err = status_styles(&store).expect_err("Unknown colors should be rejected");
+        assert!(
+            err.to_string()
+                .starts_with("Invalid color 'teal' for status in-progress.")
+        );
+    }
+}
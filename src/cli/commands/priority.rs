@@ -0,0 +1,251 @@
+use anyhow::Result;
+use clap::Args;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::cli::output::success_message;
+use crate::common::suggest::did_you_mean;
+use crate::common::{Identity, IssueId, Priority, SystemEnvProvider};
+use crate::storage::IssueStore;
+
+use super::get_author_identity;
+
+#[derive(Args)]
+pub struct PriorityArgs {
+    /// New priority (none, urgent, high, medium, low, or 0-4), or `bump`/`drop` to move one level
+    pub priority: PriorityChange,
+
+    /// Issue IDs or slugs to update
+    #[arg(required_unless_present = "where_label")]
+    pub ids: Vec<String>,
+
+    /// Also update every issue that has this label
+    #[arg(long, value_name = "LABEL")]
+    pub where_label: Option<String>,
+
+    /// Author name (defaults to git config)
+    #[arg(long)]
+    pub author_name: Option<String>,
+
+    /// Author email (defaults to git config)
+    #[arg(long)]
+    pub author_email: Option<String>,
+}
+
+/// How to change the priority of each issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriorityChange {
+    /// Set this exact priority
+    Set(Priority),
+    /// Raise the priority one level, e.g. medium to high
+    Bump,
+    /// Lower the priority one level, e.g. high to medium
+    Drop,
+}
+
+impl PriorityChange {
+    /// The priority an issue at `current` ends up with
+    fn apply(self, current: Priority) -> Priority {
+        match self {
+            PriorityChange::Set(priority) => priority,
+            PriorityChange::Bump => current.next(),
+            PriorityChange::Drop => current.prev(),
+        }
+    }
+}
+
+impl FromStr for PriorityChange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bump" => Ok(PriorityChange::Bump),
+            "drop" => Ok(PriorityChange::Drop),
+            _ => s.parse().map(PriorityChange::Set).map_err(|_| {
+                format!(
+                    "Invalid priority '{}'{}. Valid options: none, urgent, high, medium, low (or 0-4), bump, drop",
+                    s,
+                    did_you_mean(s, &["none", "urgent", "high", "medium", "low", "bump", "drop"])
+                )
+            }),
+        }
+    }
+}
+
+impl fmt::Display for PriorityChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriorityChange::Set(priority) => write!(f, "Set priority to {}", priority),
+            PriorityChange::Bump => write!(f, "Bumped priority"),
+            PriorityChange::Drop => write!(f, "Dropped priority"),
+        }
+    }
+}
+
+pub fn handle_priority(repo_path: std::path::PathBuf, args: PriorityArgs) -> Result<()> {
+    let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
+    let author = get_author_identity(
+        args.author_name,
+        args.author_email,
+        &store,
+        SystemEnvProvider,
+    )?;
+
+    let mut issue_ids = Vec::new();
+    for id in &args.ids {
+        issue_ids.push(store.resolve_ref(id)?);
+    }
+    if let Some(label) = &args.where_label {
+        issue_ids.extend(
+            store
+                .list_issues_where(|entry| entry.labels.contains(label))?
+                .iter()
+                .map(|issue| issue.id),
+        );
+    }
+    issue_ids.sort_unstable();
+    issue_ids.dedup();
+
+    let changed = set_priority(&mut store, &issue_ids, args.priority, author)?;
+
+    println!(
+        "{}",
+        success_message(&format!(
+            "{} on {} of {} issues",
+            args.priority,
+            changed.len(),
+            issue_ids.len()
+        ))
+    );
+    Ok(())
+}
+
+/// Apply `change` to each issue, returning the IDs whose priority actually changed
+fn set_priority(
+    store: &mut IssueStore,
+    issue_ids: &[IssueId],
+    change: PriorityChange,
+    author: Identity,
+) -> Result<Vec<IssueId>> {
+    let mut changed = Vec::new();
+    for &issue_id in issue_ids {
+        let current = store.get_issue(issue_id)?.priority;
+        let priority = change.apply(current);
+        if priority == current {
+            continue;
+        }
+        store.update_priority(issue_id, priority, author.clone())?;
+        changed.push(issue_id);
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_priority_where_label_only_changes_matching_issues() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let author = create_test_identity();
+
+        for (title, label) in [("Crash", "bug"), ("Docs", "docs"), ("Leak", "bug")] {
+            let issue_id = store
+                .create_issue(title.to_string(), "".to_string(), author.clone())
+                .expect("Failed to create issue");
+            store
+                .add_label(issue_id, label.to_string(), author.clone())
+                .expect("Failed to add label");
+        }
+        // Already high, so it matches but does not change
+        store
+            .update_priority(3, Priority::High, author.clone())
+            .expect("Failed to update priority");
+
+        handle_priority(
+            repo_path.clone(),
+            PriorityArgs {
+                priority: PriorityChange::Set(Priority::High),
+                ids: vec![],
+                where_label: Some("bug".to_string()),
+                author_name: None,
+                author_email: None,
+            },
+        )
+        .expect("Priority command should succeed");
+
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        let priorities: Vec<_> = store
+            .list_issues()
+            .expect("Failed to list issues")
+            .iter()
+            .map(|issue| (issue.id, issue.priority))
+            .collect();
+        assert_eq!(
+            priorities,
+            vec![
+                (1, Priority::High),
+                (2, Priority::None),
+                (3, Priority::High)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_priority_reports_changed_issues() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let mut store = IssueStore::init(temp_dir.path()).expect("Failed to initialize store");
+        let author = create_test_identity();
+        for title in ["First", "Second"] {
+            store
+                .create_issue(title.to_string(), "".to_string(), author.clone())
+                .expect("Failed to create issue");
+        }
+        store
+            .update_priority(2, Priority::Low, author.clone())
+            .expect("Failed to update priority");
+
+        let changed = set_priority(
+            &mut store,
+            &[1, 2],
+            PriorityChange::Set(Priority::Low),
+            author,
+        )
+        .unwrap();
+        assert_eq!(changed, vec![1]);
+    }
+
+    #[test]
+    fn test_priority_bump_from_medium_to_high() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let author = create_test_identity();
+        store
+            .create_issue("Slow".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .update_priority(1, Priority::Medium, author)
+            .expect("Failed to update priority");
+
+        let args = |change: &str| PriorityArgs {
+            priority: change.parse().expect("Valid priority change"),
+            ids: vec!["1".to_string()],
+            where_label: None,
+            author_name: None,
+            author_email: None,
+        };
+        handle_priority(repo_path.clone(), args("bump")).expect("Bump should succeed");
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        assert_eq!(store.get_issue(1).unwrap().priority, Priority::High);
+
+        handle_priority(repo_path.clone(), args("DROP")).expect("Drop should succeed");
+        let store = IssueStore::open(&repo_path).expect("Failed to open store");
+        assert_eq!(store.get_issue(1).unwrap().priority, Priority::Medium);
+    }
+}
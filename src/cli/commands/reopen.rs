@@ -0,0 +1,145 @@
+use anyhow::Result;
+use clap::Args;
+
+use crate::cli::output::{success_message, warning_message};
+use crate::common::{Identity, IssueId, IssueStatus, SystemEnvProvider};
+use crate::storage::IssueStore;
+
+use super::{add_trailing_comment, get_author_identity};
+
+#[derive(Args)]
+pub struct ReopenArgs {
+    /// Issue ID or slug to reopen
+    pub id: String,
+
+    /// Also remove all assignees so the issue goes back to triage
+    #[arg(long)]
+    pub reset_assignees: bool,
+
+    /// Comment to add when reopening the issue
+    #[arg(short, long)]
+    pub comment: Option<String>,
+
+    /// Author name (defaults to git config)
+    #[arg(long)]
+    pub author_name: Option<String>,
+
+    /// Author email (defaults to git config)
+    #[arg(long)]
+    pub author_email: Option<String>,
+}
+
+pub fn handle_reopen(repo_path: std::path::PathBuf, args: ReopenArgs) -> Result<()> {
+    let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
+    let issue_id = store.resolve_ref(&args.id)?;
+    let author = get_author_identity(
+        args.author_name,
+        args.author_email,
+        &store,
+        SystemEnvProvider,
+    )?;
+
+    if reopen(&mut store, issue_id, args.reset_assignees, author.clone())? {
+        println!(
+            "{}",
+            success_message(&format!("Reopened issue #{}", issue_id))
+        );
+        add_trailing_comment(&mut store, issue_id, args.comment.as_slice(), author)
+    } else {
+        println!(
+            "{}",
+            warning_message(&format!("Issue #{} is already open", issue_id))
+        );
+        Ok(())
+    }
+}
+
+/// Move a done issue back to todo, returning whether it was reopened
+///
+/// Issues that are not done are left untouched, assignees included.
+fn reopen(
+    store: &mut IssueStore,
+    issue_id: IssueId,
+    reset_assignees: bool,
+    author: Identity,
+) -> Result<bool> {
+    let issue = store.get_issue(issue_id)?;
+    if issue.status != IssueStatus::Done {
+        return Ok(false);
+    }
+
+    store.update_issue_status(issue_id, IssueStatus::Todo, author.clone())?;
+    if reset_assignees {
+        store.update_assignees(issue_id, Vec::new(), author)?;
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::IssueEvent;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    fn setup_closed_issue() -> (TempDir, IssueStore, IssueId) {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let mut store = IssueStore::init(temp_dir.path()).expect("Failed to init store");
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), String::new(), author.clone())
+            .unwrap();
+        store
+            .update_assignees(issue_id, vec![author.clone()], author.clone())
+            .unwrap();
+        store
+            .update_issue_status(issue_id, IssueStatus::Done, author)
+            .unwrap();
+        (temp_dir, store, issue_id)
+    }
+
+    #[test]
+    fn test_reopen() {
+        let (_temp_dir, mut store, issue_id) = setup_closed_issue();
+        let reopener = Identity::new("Reopener", "reopener@example.com");
+
+        assert!(reopen(&mut store, issue_id, false, reopener.clone()).unwrap());
+        let events = store.get_issue_events(issue_id).unwrap();
+        assert!(matches!(
+            events.last().unwrap(),
+            IssueEvent::StatusChanged { from: IssueStatus::Done, to: IssueStatus::Todo, author, .. }
+                if author == &reopener
+        ));
+        assert_eq!(store.get_issue(issue_id).unwrap().assignees.len(), 1);
+
+        // Reopening an open issue changes nothing
+        assert!(!reopen(&mut store, issue_id, true, reopener).unwrap());
+        assert_eq!(
+            store.get_issue_events(issue_id).unwrap().len(),
+            events.len()
+        );
+    }
+
+    #[test]
+    fn test_reopen_reset_assignees() {
+        let (_temp_dir, mut store, issue_id) = setup_closed_issue();
+        let before = store.get_issue_events(issue_id).unwrap().len();
+
+        assert!(reopen(&mut store, issue_id, true, create_test_identity()).unwrap());
+        let events = store.get_issue_events(issue_id).unwrap();
+        assert_eq!(events.len(), before + 2);
+        assert!(matches!(
+            &events[before],
+            IssueEvent::StatusChanged {
+                to: IssueStatus::Todo,
+                ..
+            }
+        ));
+        assert!(matches!(
+            &events[before + 1],
+            IssueEvent::AssigneesChanged { new_assignees, .. } if new_assignees.is_empty()
+        ));
+        assert!(store.get_issue(issue_id).unwrap().assignees.is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::Result;
+use clap::Args;
+
+use crate::cli::output::format_issue_compact;
+use crate::storage::IssueStore;
+use crate::storage::search::{SearchPattern, SearchQuery};
+
+use super::list::compile_regex;
+use super::{issue_url_template, parse_status, status_styles};
+
+#[derive(Args)]
+pub struct SearchArgs {
+    /// Text to look for in titles, descriptions and comments
+    pub query: String,
+
+    /// Treat the query as a regular expression
+    #[arg(long)]
+    pub regex: bool,
+
+    /// Only search issues with this status
+    #[arg(short, long)]
+    pub status: Option<String>,
+
+    /// Only search issues with this label; repeat to require several
+    #[arg(short, long, value_name = "LABEL")]
+    pub label: Vec<String>,
+}
+
+pub fn handle_search(repo_path: std::path::PathBuf, args: SearchArgs) -> Result<()> {
+    let store = IssueStore::open(&repo_path)?;
+
+    let pattern = if args.regex {
+        SearchPattern::Regex(compile_regex(&args.query)?)
+    } else {
+        SearchPattern::text(&args.query)
+    };
+    let mut query = SearchQuery::new(pattern);
+    if let Some(status) = &args.status {
+        query.statuses = Some(vec![parse_status(status)?]);
+    }
+    query.labels = args.label;
+
+    let issues = store.search(&query)?;
+
+    let url_template = issue_url_template(&store);
+    let styles = status_styles(&store)?;
+    for issue in &issues {
+        println!(
+            "{}",
+            format_issue_compact(issue, 0, url_template.as_deref(), &styles)
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    #[test]
+    fn test_search_args() {
+        let cli = crate::cli::Cli::parse_from([
+            "git-issue",
+            "search",
+            "crash",
+            "--regex",
+            "-s",
+            "done",
+            "-l",
+            "bug",
+            "-l",
+            "ui",
+        ]);
+        let crate::cli::Commands::Search(args) = cli.command else {
+            panic!("Expected search command");
+        };
+        assert_eq!(args.query, "crash");
+        assert!(args.regex);
+        assert_eq!(args.status.as_deref(), Some("done"));
+        assert_eq!(args.label, vec!["bug", "ui"]);
+    }
+}
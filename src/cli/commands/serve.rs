@@ -0,0 +1,214 @@
+use anyhow::Result;
+use clap::Args;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+use crate::common::{Issue, IssueId};
+use crate::storage::IssueStore;
+use crate::storage::errors::StorageError;
+
+/// How long a client may take to send its request line
+///
+/// Connections are answered one at a time, so a client that never sends
+/// anything would otherwise stall the server for everyone.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Address to bind to
+    #[arg(long, default_value = "127.0.0.1")]
+    pub bind: String,
+}
+
+pub fn handle_serve(repo_path: std::path::PathBuf, args: ServeArgs) -> Result<()> {
+    let store = IssueStore::open(&repo_path)?;
+    let listener = TcpListener::bind((args.bind.as_str(), args.port))?;
+    println!("Serving issues on http://{}/", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        // A broken connection should not take the server down
+        if let Err(e) = stream
+            .map_err(anyhow::Error::from)
+            .and_then(|s| serve_connection(&store, s))
+        {
+            log::warn!("Failed to serve request: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Answer a single HTTP request on `stream`
+fn serve_connection(store: &IssueStore, mut stream: TcpStream) -> Result<()> {
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+
+    let mut parts = request_line.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(path)) => route(store, path),
+        _ => (
+            "405 Method Not Allowed",
+            page("Not allowed", "<p>Only GET is supported.</p>"),
+        ),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.0 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Render the page for `path`, returning the HTTP status and HTML body
+fn route(store: &IssueStore, path: &str) -> (&'static str, String) {
+    let result = match path.trim_end_matches('/') {
+        "" => store.list_issues().map(|issues| render_issue_list(&issues)),
+        path => match path
+            .strip_prefix("/issues/")
+            .and_then(|id| id.parse::<IssueId>().ok())
+        {
+            Some(issue_id) => store.get_issue(issue_id).map(|issue| render_issue(&issue)),
+            None => return ("404 Not Found", page("Not found", "<p>No such page.</p>")),
+        },
+    };
+
+    match result {
+        Ok(body) => ("200 OK", body),
+        Err(StorageError::IssueNotFound { issue_id }) => (
+            "404 Not Found",
+            page(
+                "Not found",
+                &format!("<p>Issue #{} does not exist.</p>", issue_id),
+            ),
+        ),
+        Err(e) => (
+            "500 Internal Server Error",
+            page("Error", &format!("<p>{}</p>", escape_html(&e.to_string()))),
+        ),
+    }
+}
+
+fn render_issue_list(issues: &[Issue]) -> String {
+    let rows: String = issues
+        .iter()
+        .map(|issue| {
+            format!(
+                "<tr><td><a href=\"/issues/{id}\">#{id}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                issue.status,
+                issue.priority,
+                escape_html(&issue.title),
+                id = issue.id,
+            )
+        })
+        .collect();
+
+    page(
+        "Issues",
+        &format!(
+            "<table>\n<tr><th>ID</th><th>Status</th><th>Priority</th><th>Title</th></tr>\n{}</table>",
+            rows
+        ),
+    )
+}
+
+fn render_issue(issue: &Issue) -> String {
+    let mut body = format!(
+        "<p><a href=\"/\">All issues</a></p>\n<p>Status: {} &middot; Priority: {} &middot; Created by {}</p>\n",
+        issue.status,
+        issue.priority,
+        escape_html(&issue.created_by.to_string())
+    );
+
+    if !issue.labels.is_empty() {
+        body.push_str(&format!(
+            "<p>Labels: {}</p>\n",
+            escape_html(&issue.labels.join(", "))
+        ));
+    }
+
+    body.push_str(&format!("<pre>{}</pre>\n", escape_html(&issue.description)));
+
+    for comment in &issue.comments {
+        body.push_str(&format!(
+            "<h3>{} on {}</h3>\n<pre>{}</pre>\n",
+            escape_html(&comment.author.to_string()),
+            comment.created_at.format("%Y-%m-%d %H:%M"),
+            escape_html(&comment.content)
+        ));
+    }
+
+    page(&format!("#{} {}", issue.id, issue.title), &body)
+}
+
+fn page(title: &str, body: &str) -> String {
+    let title = escape_html(title);
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{title}</title></head>\n<body>\n<h1>{title}</h1>\n{body}\n</body></html>\n"
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::test_helpers::*;
+    use std::io::Read;
+    use tempfile::TempDir;
+
+    fn get(listener: &TcpListener, store: &IssueStore, path: &str) -> String {
+        let addr = listener.local_addr().unwrap();
+        let request = format!("GET {} HTTP/1.0\r\n\r\n", path);
+        let client = std::thread::spawn(move || {
+            let mut stream = TcpStream::connect(addr).expect("Failed to connect");
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+
+        let (stream, _) = listener.accept().expect("Failed to accept");
+        serve_connection(store, stream).expect("Failed to serve");
+        client.join().unwrap()
+    }
+
+    #[test]
+    fn test_list_endpoint_shows_issue_titles() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let mut store = IssueStore::init(temp_dir.path()).expect("Failed to initialize store");
+        store
+            .create_issue(
+                "Crash on <startup>".to_string(),
+                "".to_string(),
+                create_test_identity(),
+            )
+            .expect("Failed to create issue");
+
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind");
+
+        let response = get(&listener, &store, "/");
+        assert!(response.starts_with("HTTP/1.0 200 OK"));
+        assert!(response.contains("Crash on &lt;startup&gt;"));
+        assert!(response.contains("<a href=\"/issues/1\">#1</a>"));
+
+        let response = get(&listener, &store, "/issues/1");
+        assert!(response.starts_with("HTTP/1.0 200 OK"));
+
+        let response = get(&listener, &store, "/issues/99");
+        assert!(response.starts_with("HTTP/1.0 404 Not Found"));
+    }
+}
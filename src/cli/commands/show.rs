@@ -1,21 +1,304 @@
 use anyhow::Result;
 use clap::Args;
+use console::style;
+use serde::Serialize;
+use std::time::Duration;
 
-use crate::cli::output::format_issue_detailed;
-use crate::common::IssueId;
+use crate::cli::output::{
+    Lang, format_issue_detailed, format_issue_markdown, format_issue_references,
+    format_issue_template,
+};
+use crate::common::markdown::extract_issue_references;
+use crate::common::{Issue, IssueEvent, IssueId};
 use crate::storage::IssueStore;
+use crate::storage::errors::StorageError;
+
+use super::{issue_url_template, status_styles};
 
 #[derive(Args)]
 pub struct ShowArgs {
-    /// Issue ID to show
-    pub id: IssueId,
+    /// Issue ID or slug to show
+    pub id: String,
+
+    /// Print the raw event chain as JSON, including the git object IDs of each event
+    #[arg(long)]
+    pub raw_json: bool,
+
+    /// Print the issue and its comments as markdown
+    #[arg(long, conflicts_with_all = ["raw_json", "follow"])]
+    pub markdown: bool,
+
+    /// Print the issue with a custom template, as for `list --template`
+    #[arg(long, conflicts_with_all = ["raw_json", "markdown", "follow"])]
+    pub template: Option<String>,
+
+    /// Fail on events written by a newer version instead of skipping them
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Keep watching the issue and reprint it whenever it changes, until interrupted
+    #[arg(short, long, conflicts_with = "raw_json")]
+    pub follow: bool,
+
+    /// Seconds between checks for changes with --follow
+    #[arg(long, default_value_t = 2, requires = "follow")]
+    pub interval: u64,
+
+    /// Language for relative times
+    #[arg(long, value_enum, default_value_t = Lang::En)]
+    pub lang: Lang,
+}
+
+/// A single event together with the git objects that store it
+#[derive(Debug, Serialize)]
+pub struct RawEventRecord {
+    pub commit_oid: String,
+    pub tree_oid: String,
+    pub parent_oid: Option<String>,
+    pub event: IssueEvent,
 }
 
 pub fn handle_show(repo_path: std::path::PathBuf, args: ShowArgs) -> Result<()> {
-    let store = IssueStore::open(&repo_path)?;
-    let issue = store.get_issue(args.id)?;
+    let mut store = IssueStore::open(&repo_path)?;
+    store.set_strict(args.strict);
+    let issue_id = store.resolve_ref(&args.id)?;
+
+    if args.raw_json {
+        let records = collect_raw_events(&store, issue_id)?;
+        println!("{}", serde_json::to_string_pretty(&records)?);
+        return Ok(());
+    }
+
+    if let Some(template) = &args.template {
+        let issue = store.get_issue(issue_id)?;
+        println!(
+            "{}",
+            format_issue_template(&issue, template).map_err(anyhow::Error::msg)?
+        );
+        return Ok(());
+    }
 
-    print!("{}", format_issue_detailed(&issue));
+    if args.markdown {
+        let issue = store.get_issue(issue_id)?;
+        print!("{}", format_issue_markdown(&issue, args.lang.units()));
+        return Ok(());
+    }
+
+    print_issue(&store, issue_id, args.lang)?;
+    if !args.follow {
+        return Ok(());
+    }
+
+    let interval = Duration::from_secs(args.interval.max(1));
+    let mut head = store.get_issue_head_commit(issue_id)?;
+    loop {
+        head = wait_for_change(&store, issue_id, head, interval)?;
+        println!(
+            "{}",
+            style(format!(
+                "--- updated {} ---",
+                chrono::Local::now().format("%H:%M:%S")
+            ))
+            .dim()
+        );
+        print_issue(&store, issue_id, args.lang)?;
+    }
+}
+
+fn print_issue(store: &IssueStore, issue_id: IssueId, lang: Lang) -> Result<()> {
+    let issue = store.get_issue(issue_id)?;
+
+    let url_template = issue_url_template(store);
+    let styles = status_styles(store)?;
+    print!(
+        "{}",
+        format_issue_detailed(&issue, url_template.as_deref(), lang.units(), &styles)
+    );
+
+    let references = resolve_references(store, &issue)?;
+    if !references.is_empty() {
+        print!("{}", format_issue_references(&references));
+    }
 
     Ok(())
 }
+
+/// Poll the issue ref every `interval` until its head moves away from `head`
+///
+/// Returns the new head.
+fn wait_for_change(
+    store: &IssueStore,
+    issue_id: IssueId,
+    head: gix::ObjectId,
+    interval: Duration,
+) -> Result<gix::ObjectId> {
+    loop {
+        std::thread::sleep(interval);
+        let current = store.get_issue_head_commit(issue_id)?;
+        if current != head {
+            return Ok(current);
+        }
+    }
+}
+
+/// Look up issues referenced as `#N` in the description, ignoring ones that don't exist
+///
+/// Any other failure to read a referenced issue is an error, not a missing issue.
+fn resolve_references(store: &IssueStore, issue: &Issue) -> Result<Vec<Issue>> {
+    let mut references = Vec::new();
+    for id in extract_issue_references(&issue.description) {
+        if id == issue.id {
+            continue;
+        }
+        match store.get_issue(id) {
+            Ok(referenced) => references.push(referenced),
+            Err(StorageError::IssueNotFound { .. }) => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Ok(references)
+}
+
+/// Collect all events of an issue along with their commit, tree and parent OIDs
+fn collect_raw_events(store: &IssueStore, issue_id: IssueId) -> Result<Vec<RawEventRecord>> {
+    let events = store.get_issue_events_with_oids(issue_id)?;
+
+    if events.is_empty() {
+        return Err(StorageError::issue_not_found(issue_id).into());
+    }
+
+    let mut records = Vec::new();
+    for (commit_oid, event) in events {
+        let commit = store.read_commit(commit_oid)?;
+        records.push(RawEventRecord {
+            commit_oid: commit_oid.to_string(),
+            tree_oid: commit.tree,
+            parent_oid: commit.parents.into_iter().next(),
+            event,
+        });
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::IssueStatus;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_raw_events_includes_oids() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let author = create_test_identity();
+
+        let issue_id = store
+            .create_issue("Raw".to_string(), "Events".to_string(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .update_issue_status(issue_id, IssueStatus::Done, author)
+            .expect("Failed to update status");
+
+        let records = collect_raw_events(&store, issue_id).expect("Should collect raw events");
+        assert_eq!(records.len(), 2);
+
+        for record in &records {
+            let commit_oid: gix::ObjectId = record.commit_oid.parse().expect("Valid commit OID");
+            let tree_oid: gix::ObjectId = record.tree_oid.parse().expect("Valid tree OID");
+            assert_git_object_exists(&repo_path, &commit_oid);
+            assert_git_object_exists(&repo_path, &tree_oid);
+        }
+
+        // The chain is linked: the first event has no parent, the second points at the first
+        assert!(records[0].parent_oid.is_none());
+        assert_eq!(
+            records[1].parent_oid.as_deref(),
+            Some(records[0].commit_oid.as_str())
+        );
+        assert!(matches!(records[0].event, IssueEvent::Created { .. }));
+        assert!(matches!(records[1].event, IssueEvent::StatusChanged { .. }));
+
+        let json = serde_json::to_value(&records).expect("Should serialize");
+        assert!(json[0]["commit_oid"].is_string());
+        assert!(json[0]["parent_oid"].is_null());
+    }
+
+    #[test]
+    fn test_collect_raw_events_missing_issue() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let store = IssueStore::init(temp_dir.path()).expect("Failed to initialize store");
+
+        assert!(collect_raw_events(&store, 999).is_err());
+    }
+
+    #[test]
+    fn test_resolve_references_skips_missing_issues() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let mut store = IssueStore::init(temp_dir.path()).expect("Failed to initialize store");
+        let author = create_test_identity();
+
+        let login_id = store
+            .create_issue("Fix login".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .update_issue_status(login_id, IssueStatus::Done, author.clone())
+            .expect("Failed to update status");
+
+        let issue_id = store
+            .create_issue(
+                "Follow-up".to_string(),
+                format!("Continues #{} but not #42.", login_id),
+                author,
+            )
+            .expect("Failed to create issue");
+        let issue = store.get_issue(issue_id).expect("Failed to get issue");
+
+        let references = resolve_references(&store, &issue).expect("Should resolve references");
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].id, login_id);
+        assert_eq!(references[0].title, "Fix login");
+        assert_eq!(references[0].status, IssueStatus::Done);
+
+        let footer = format_issue_references(&references);
+        assert!(footer.starts_with("References:"));
+        assert!(footer.contains("(Fix login)"));
+        assert!(footer.contains("done"));
+    }
+
+    #[test]
+    fn test_wait_for_change_detects_update() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let issue_id = store
+            .create_issue(
+                "Watched".to_string(),
+                "".to_string(),
+                create_test_identity(),
+            )
+            .expect("Failed to create issue");
+        let head = store.get_issue_head_commit(issue_id).unwrap();
+
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            let mut store = IssueStore::open(&repo_path).expect("Failed to open store");
+            store
+                .update_issue_status(issue_id, IssueStatus::InProgress, create_test_identity())
+                .expect("Failed to update status");
+        });
+
+        let new_head = wait_for_change(&store, issue_id, head, Duration::from_millis(10))
+            .expect("Should detect the change");
+        writer.join().unwrap();
+
+        assert_ne!(new_head, head);
+        assert_eq!(new_head, store.get_issue_head_commit(issue_id).unwrap());
+        assert_eq!(
+            store.get_issue(issue_id).unwrap().status,
+            IssueStatus::InProgress
+        );
+    }
+}
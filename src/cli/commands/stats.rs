@@ -0,0 +1,201 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::output::OutputFormat;
+use crate::common::{Issue, IssueId, IssueStatus};
+use crate::storage::IssueStore;
+
+#[derive(Args)]
+pub struct StatsArgs {
+    /// Output format (json prints the statistics as a single JSON object)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+/// Issue counts and closing times, as printed by `stats`
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct IssueStats {
+    pub total: usize,
+    pub todo: usize,
+    pub in_progress: usize,
+    pub done: usize,
+    /// `None` when no issue has been closed yet
+    pub time_to_close: Option<TimeToClose>,
+    /// `None` when every issue is done
+    pub oldest_open: Option<OldestOpenIssue>,
+}
+
+/// Average and median time from creation to closing, in seconds
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimeToClose {
+    pub average_seconds: i64,
+    pub median_seconds: i64,
+}
+
+/// The open issue created the longest ago, with its age in seconds
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct OldestOpenIssue {
+    pub id: IssueId,
+    pub age_seconds: i64,
+}
+
+impl IssueStats {
+    /// Gather the statistics of `issues`, with ages measured at `now`
+    pub fn collect(issues: &[Issue], now: DateTime<Utc>) -> Self {
+        let count = |status| issues.iter().filter(|i| i.status == status).count();
+        Self {
+            total: issues.len(),
+            todo: count(IssueStatus::Todo),
+            in_progress: count(IssueStatus::InProgress),
+            done: count(IssueStatus::Done),
+            time_to_close: time_to_close_summary(issues).map(|(average, median)| TimeToClose {
+                average_seconds: average.num_seconds(),
+                median_seconds: median.num_seconds(),
+            }),
+            oldest_open: issues
+                .iter()
+                .filter(|i| i.status != IssueStatus::Done)
+                .max_by_key(|i| i.age(now))
+                .map(|oldest| OldestOpenIssue {
+                    id: oldest.id,
+                    age_seconds: oldest.age(now).num_seconds(),
+                }),
+        }
+    }
+}
+
+/// Average and median time-to-close over the closed issues, if there are any
+fn time_to_close_summary(issues: &[Issue]) -> Option<(Duration, Duration)> {
+    let mut durations: Vec<Duration> = issues.iter().filter_map(Issue::time_to_close).collect();
+    if durations.is_empty() {
+        return None;
+    }
+    durations.sort();
+
+    let count = durations.len() as i32;
+    let average = durations.iter().copied().sum::<Duration>() / count;
+    let middle = durations.len() / 2;
+    let median = if durations.len().is_multiple_of(2) {
+        (durations[middle - 1] + durations[middle]) / 2
+    } else {
+        durations[middle]
+    };
+    Some((average, median))
+}
+
+fn format_duration(duration: Duration) -> String {
+    let days = duration.num_days();
+    let hours = duration.num_hours() % 24;
+    let minutes = duration.num_minutes() % 60;
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+pub fn handle_stats(repo_path: std::path::PathBuf, args: StatsArgs) -> Result<()> {
+    let store = IssueStore::open(&repo_path)?;
+    let issues = store.list_issues()?;
+    let stats = IssueStats::collect(&issues, Utc::now());
+
+    if args.format == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    println!(
+        "Issues: {} ({} todo, {} in-progress, {} done)",
+        stats.total, stats.todo, stats.in_progress, stats.done
+    );
+
+    match &stats.time_to_close {
+        Some(time_to_close) => println!(
+            "Time to close: {} average, {} median",
+            format_duration(Duration::seconds(time_to_close.average_seconds)),
+            format_duration(Duration::seconds(time_to_close.median_seconds))
+        ),
+        None => println!("Time to close: no closed issues"),
+    }
+
+    if let Some(oldest) = &stats.oldest_open {
+        println!(
+            "Oldest open issue: #{} ({})",
+            oldest.id,
+            format_duration(Duration::seconds(oldest.age_seconds))
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Identity;
+
+    #[test]
+    fn test_time_to_close_summary() {
+        let created = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let issue = |closed_after: Option<i64>| {
+            let mut issue = Issue::new(
+                1,
+                "Title".to_string(),
+                String::new(),
+                Identity::new("Test User", "test@example.com"),
+            );
+            issue.created_at = created;
+            issue.closed_at = closed_after.map(|hours| created + Duration::hours(hours));
+            issue
+        };
+
+        assert_eq!(time_to_close_summary(&[issue(None)]), None);
+
+        let issues = [issue(Some(2)), issue(None), issue(Some(4)), issue(Some(12))];
+        assert_eq!(
+            time_to_close_summary(&issues),
+            Some((Duration::hours(6), Duration::hours(4)))
+        );
+        assert_eq!(format_duration(Duration::hours(30)), "1d 6h");
+        assert_eq!(format_duration(Duration::minutes(90)), "1h 30m");
+    }
+
+    #[test]
+    fn test_issue_stats_json_roundtrip() {
+        let created = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let issue = |id, closed_after: Option<i64>| {
+            let mut issue = Issue::new(
+                id,
+                "Title".to_string(),
+                String::new(),
+                Identity::new("Test User", "test@example.com"),
+            );
+            issue.created_at = created;
+            if let Some(hours) = closed_after {
+                issue.status = IssueStatus::Done;
+                issue.closed_at = Some(created + Duration::hours(hours));
+            }
+            issue
+        };
+        let issues = [issue(1, Some(2)), issue(2, None), issue(3, Some(4))];
+
+        let stats = IssueStats::collect(&issues, created + Duration::days(1));
+        let json = serde_json::to_string_pretty(&stats).expect("Should serialize");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("Valid JSON");
+        assert_eq!(value["total"], 3);
+        assert_eq!(value["done"], 2);
+        assert_eq!(value["time_to_close"]["median_seconds"], 3 * 3600);
+        assert_eq!(value["oldest_open"]["id"], 2);
+        assert_eq!(value["oldest_open"]["age_seconds"], 24 * 3600);
+
+        let parsed: IssueStats = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(parsed, stats);
+    }
+}
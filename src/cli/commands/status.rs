@@ -2,15 +2,15 @@ use anyhow::Result;
 use clap::Args;
 
 use crate::cli::output::success_message;
-use crate::common::{IssueId, SystemEnvProvider};
+use crate::common::SystemEnvProvider;
 use crate::storage::IssueStore;
 
-use super::{get_author_identity, parse_status};
+use super::{add_trailing_comment, get_author_identity, parse_status};
 
 #[derive(Args)]
 pub struct StatusArgs {
-    /// Issue ID to update
-    pub id: IssueId,
+    /// Issue ID or slug to update
+    pub id: String,
 
     /// New status (todo, in-progress, done)
     pub status: String,
@@ -22,10 +22,16 @@ pub struct StatusArgs {
     /// Author email (defaults to git config)
     #[arg(long)]
     pub author_email: Option<String>,
+
+    /// Comment to add alongside the change, given after `--`
+    #[arg(last = true, value_name = "COMMENT")]
+    pub comment: Vec<String>,
 }
 
 pub fn handle_status(repo_path: std::path::PathBuf, args: StatusArgs) -> Result<()> {
     let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
+    let issue_id = store.resolve_ref(&args.id)?;
     let author = get_author_identity(
         args.author_name,
         args.author_email,
@@ -34,22 +40,23 @@ pub fn handle_status(repo_path: std::path::PathBuf, args: StatusArgs) -> Result<
     )?;
     let new_status = parse_status(&args.status)?;
 
-    store.update_issue_status(args.id, new_status, author)?;
+    store.update_issue_status(issue_id, new_status, author.clone())?;
 
     println!(
         "{}",
         success_message(&format!(
             "Updated issue #{} status to {}",
-            args.id, new_status
+            issue_id, new_status
         ))
     );
-    Ok(())
+    add_trailing_comment(&mut store, issue_id, &args.comment, author)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::common::IssueStatus;
+    use clap::Parser;
 
     #[test]
     fn test_parse_status_valid() {
@@ -80,4 +87,43 @@ mod tests {
         assert!(result.is_err(), "Should return error for invalid status");
         assert!(result.unwrap_err().to_string().contains("Invalid status"));
     }
+
+    #[test]
+    fn test_handle_status_with_trailing_comment() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temporary directory");
+        let repo_path = temp_dir.path().to_path_buf();
+        let mut store = IssueStore::init(&repo_path).expect("Failed to initialize store");
+        let author = crate::storage::test_helpers::create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        let cli = crate::cli::Cli::parse_from([
+            "git-issue",
+            "status",
+            &issue_id.to_string(),
+            "done",
+            "--",
+            "fixed in PR 42",
+        ]);
+        let crate::cli::Commands::Status(args) = cli.command else {
+            unreachable!()
+        };
+        handle_status(repo_path, args).expect("Status should succeed");
+
+        let issue = store.get_issue(issue_id).expect("Should get issue");
+        assert_eq!(issue.status, IssueStatus::Done);
+        assert_eq!(issue.comments.len(), 1);
+        assert_eq!(issue.comments[0].content, "fixed in PR 42");
+
+        // The comment follows the status change it annotates
+        let events = store.get_issue_events(issue_id).expect("Should get events");
+        assert!(matches!(
+            &events[events.len() - 2..],
+            [
+                crate::common::IssueEvent::StatusChanged { .. },
+                crate::common::IssueEvent::CommentAdded { .. }
+            ]
+        ));
+    }
 }
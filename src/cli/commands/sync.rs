@@ -1,18 +1,28 @@
 use anyhow::Result;
 use clap::Args;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::cli::output::{error_message, success_message, warning_message};
+use crate::cli::output::{OutputFormat, error_message, success_message, warning_message};
 use crate::common::{Identity, IssueId, SystemEnvProvider};
 use crate::storage::IssueStore;
+use crate::storage::errors::{GitError, StorageError, StorageResult};
+use crate::storage::issue_store::{CounterReconciliation, SyncJournalEntry};
 
 use super::get_author_identity;
 
+/// How often a sync tries to push the issue counter while other syncs keep moving it
+const COUNTER_PUSH_ATTEMPTS: u32 = 3;
+
 #[derive(Args)]
 pub struct SyncArgs {
-    /// Remote to sync with (defaults to git's configured default remote)
+    /// Remote to sync with (repeatable; defaults to git's configured default remote)
     #[arg(long)]
-    pub remote: Option<String>,
+    pub remote: Vec<String>,
+
+    /// Sync with every configured remote
+    #[arg(long, conflicts_with = "remote")]
+    pub all_remotes: bool,
 
     /// Show what would be synced without actually syncing
     #[arg(long)]
@@ -33,6 +43,14 @@ pub struct SyncArgs {
     /// Verbose output showing detailed sync operations
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Pack loose issue objects before pushing, so fewer objects are transferred
+    #[arg(long)]
+    pub repack: bool,
+
+    /// Output format (json prints the sync summary as a single JSON object)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
 }
 
 /// Result of comparing local and remote refs
@@ -53,6 +71,10 @@ pub enum RefComparisonResult {
     NewRef,
     /// Local ref doesn't exist but remote does (deleted locally)
     LocallyDeleted,
+    /// Local issue counter is higher than the remote's (force pushed with a
+    /// lease on the remote counter at `remote_oid`, as the counter blob has
+    /// no history to fast-forward)
+    CounterAhead { remote_oid: Option<String> },
 }
 
 /// Information about a ref to be synced
@@ -60,30 +82,60 @@ pub enum RefComparisonResult {
 pub struct SyncRef {
     pub ref_name: String,
     pub local_oid: Option<String>,
+    #[allow(unused)]
     pub remote_oid: Option<String>,
     pub comparison: RefComparisonResult,
     pub issue_id: Option<IssueId>,
 }
 
+/// A ref that could not be pushed, with the reason
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncFailure {
+    pub ref_name: String,
+    pub error: String,
+}
+
+impl SyncFailure {
+    pub fn new(ref_name: impl Into<String>, error: impl ToString) -> Self {
+        Self {
+            ref_name: ref_name.into(),
+            error: error.to_string(),
+        }
+    }
+}
+
 /// Summary of sync operation results
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct SyncSummary {
+    /// Whether this summary describes a dry run (pushed refs were not actually pushed)
+    #[serde(default)]
+    pub dry_run: bool,
     pub pushed_refs: Vec<String>,
     pub skipped_refs: Vec<String>,
-    pub failed_refs: Vec<(String, String)>, // ref_name, error_message
+    pub failed_refs: Vec<SyncFailure>,
     pub conflicts: Vec<String>,
 }
 
+/// Outcome of syncing with one of several remotes
+#[derive(Debug, Serialize)]
+struct RemoteSyncReport {
+    remote: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<SyncSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 /// Handle syncing issues to remote
-pub fn handle_sync(repo_path: std::path::PathBuf, args: SyncArgs) -> Result<()> {
+pub fn handle_sync(repo_path: std::path::PathBuf, mut args: SyncArgs) -> Result<()> {
     let mut store = IssueStore::open(&repo_path)?;
+    let _lock = store.lock_for_writing()?;
     let author = get_author_identity(None, None, &store, SystemEnvProvider)?;
+    let json = args.format == OutputFormat::Json;
 
-    // Determine target remote
-    let remote_name = determine_target_remote(&store, args.remote.as_deref())?;
-
-    if args.verbose {
-        println!("Using remote: {}", remote_name);
+    // Progress output would corrupt the JSON document
+    if json {
+        args.verbose = false;
     }
 
     // Validate arguments
@@ -93,12 +145,159 @@ pub fn handle_sync(repo_path: std::path::PathBuf, args: SyncArgs) -> Result<()>
         ));
     }
 
-    // Discover refs to sync
-    let refs_to_sync = discover_sync_refs(&store, args.issues.as_deref())?;
+    if args.repack && !args.dry_run {
+        repack(&store, args.verbose)?;
+    }
+
+    let remotes = if args.all_remotes {
+        let remotes = store.list_remotes()?;
+        if remotes.is_empty() {
+            anyhow::bail!("--all-remotes given, but no remotes are configured");
+        }
+        remotes
+    } else {
+        args.remote.clone()
+    };
+
+    if remotes.len() <= 1 {
+        let summary = sync_remote(
+            &mut store,
+            remotes.first().map(String::as_str),
+            &args,
+            &author,
+        )?;
+        if json {
+            print_sync_json(&summary)?;
+        }
+        return check_summary(&summary);
+    }
+
+    // Sync each remote in turn; a failing remote does not stop the others
+    let reports = sync_remotes(&mut store, &remotes, &args, &author);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    } else {
+        for report in &reports {
+            if let Some(error) = &report.error {
+                println!(
+                    "{}",
+                    error_message(&format!("{}: {}", report.remote, error))
+                );
+            }
+        }
+    }
+
+    let failed: Vec<&str> = reports
+        .iter()
+        .filter(|report| report.error.is_some())
+        .map(|report| report.remote.as_str())
+        .collect();
+    if !failed.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Sync failed for {} of {} remotes: {}",
+            failed.len(),
+            reports.len(),
+            failed.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Pack loose issue objects, reporting object counts before and after when `verbose`
+fn repack(store: &IssueStore, verbose: bool) -> Result<()> {
+    let before = store.object_counts()?;
+    store.repack()?;
+    if verbose {
+        let after = store.object_counts()?;
+        println!(
+            "Repacked issue objects: {} loose in {} packs before, {} loose in {} packs after",
+            before.loose, before.packs, after.loose, after.packs
+        );
+    }
+    Ok(())
+}
+
+/// Sync with each remote, collecting a report per remote
+fn sync_remotes(
+    store: &mut IssueStore,
+    remotes: &[String],
+    args: &SyncArgs,
+    author: &Identity,
+) -> Vec<RemoteSyncReport> {
+    remotes
+        .iter()
+        .map(|remote| {
+            if args.format == OutputFormat::Text {
+                println!("Syncing with {}:", remote);
+            }
+            let result = sync_remote(store, Some(remote), args, author)
+                .and_then(|summary| check_summary(&summary).map(|_| summary));
+            match result {
+                Ok(summary) => RemoteSyncReport {
+                    remote: remote.clone(),
+                    summary: Some(summary),
+                    error: None,
+                },
+                Err(e) => RemoteSyncReport {
+                    remote: remote.clone(),
+                    summary: None,
+                    error: Some(e.to_string()),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Fail when a sync stopped on conflicts or any ref failed to push
+///
+/// Pushed and skipped refs alone are a success.
+fn check_summary(summary: &SyncSummary) -> Result<()> {
+    if !summary.conflicts.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Cannot sync due to conflicts. Use --force for force-with-lease or --force-without-lease for unsafe force push"
+        ));
+    }
+    if !summary.failed_refs.is_empty() {
+        let refs: Vec<&str> = summary
+            .failed_refs
+            .iter()
+            .map(|failure| failure.ref_name.as_str())
+            .collect();
+        return Err(anyhow::anyhow!(
+            "Failed to sync {} refs: {}",
+            refs.len(),
+            refs.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Sync with a single remote, printing text progress unless JSON output was requested
+///
+/// Conflicts are reported in the returned summary rather than as an error.
+fn sync_remote(
+    store: &mut IssueStore,
+    remote: Option<&str>,
+    args: &SyncArgs,
+    author: &Identity,
+) -> Result<SyncSummary> {
+    let json = args.format == OutputFormat::Json;
+
+    // Discover refs first, so a repository without issues needs no remote either
+    let refs_to_sync = discover_sync_refs(store, args.issues.as_deref())?;
 
     if refs_to_sync.is_empty() {
-        println!("No issue refs found to sync");
-        return Ok(());
+        if !json {
+            println!("No issue refs found to sync");
+        }
+        return Ok(SyncSummary::default());
+    }
+
+    // Determine target remote
+    let remote_name = determine_target_remote(store, remote)?;
+
+    if args.verbose {
+        println!("Using remote: {}", remote_name);
     }
 
     if args.verbose {
@@ -110,11 +309,14 @@ pub fn handle_sync(repo_path: std::path::PathBuf, args: SyncArgs) -> Result<()>
         // For dry-run, we can skip the actual fetch and use placeholder data
         HashMap::new()
     } else {
-        fetch_remote_refs(&store, &remote_name, &refs_to_sync)?
+        store.fetch_refs_from_remote(&remote_name, &refs_to_sync)?
     };
 
     // Compare local and remote refs
-    let sync_refs = compare_refs(&refs_to_sync, &remote_refs)?;
+    let mut sync_refs = compare_refs(store, &refs_to_sync, &remote_refs)?;
+    if !args.dry_run {
+        reconcile_issue_counter(store, &remote_name, &mut sync_refs, args.verbose)?;
+    }
 
     // Filter refs that need syncing
     let refs_needing_sync: Vec<&SyncRef> = sync_refs
@@ -123,8 +325,13 @@ pub fn handle_sync(repo_path: std::path::PathBuf, args: SyncArgs) -> Result<()>
         .collect();
 
     if refs_needing_sync.is_empty() {
-        println!("{}", success_message("All refs are up to date"));
-        return Ok(());
+        if !json {
+            println!("{}", success_message("All refs are up to date"));
+        }
+        return Ok(SyncSummary {
+            skipped_refs: refs_to_sync,
+            ..SyncSummary::default()
+        });
     }
 
     // Check for conflicts that require user intervention
@@ -140,32 +347,59 @@ pub fn handle_sync(repo_path: std::path::PathBuf, args: SyncArgs) -> Result<()>
         .collect();
 
     if !conflicted_refs.is_empty() && !args.force && !args.force_without_lease {
-        print_conflict_summary(&conflicted_refs);
-        return Err(anyhow::anyhow!(
-            "Cannot sync due to conflicts. Use --force for force-with-lease or --force-without-lease for unsafe force push"
-        ));
+        if !json {
+            print_conflict_summary(&conflicted_refs);
+        }
+        return Ok(SyncSummary {
+            conflicts: conflicted_refs.iter().map(|r| r.ref_name.clone()).collect(),
+            ..SyncSummary::default()
+        });
     }
 
     // Show what will be synced
-    if args.dry_run || args.verbose {
+    if !json && (args.dry_run || args.verbose) {
         print_sync_preview(&refs_needing_sync, args.dry_run);
     }
 
     if args.dry_run {
-        return Ok(());
+        return Ok(SyncSummary {
+            dry_run: true,
+            pushed_refs: refs_needing_sync
+                .iter()
+                .map(|r| r.ref_name.clone())
+                .collect(),
+            ..SyncSummary::default()
+        });
     }
 
     // Perform the actual sync
-    let summary = perform_sync(&mut store, &remote_name, &refs_needing_sync, &args, author)?;
+    let summary = perform_sync(
+        store,
+        &remote_name,
+        &refs_needing_sync,
+        args,
+        author.clone(),
+    )?;
 
     // Print results
-    print_sync_results(&summary);
+    if !json {
+        print_sync_results(&summary);
+    }
 
+    Ok(summary)
+}
+
+/// Print a sync summary as JSON
+fn print_sync_json(summary: &SyncSummary) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(summary)?);
     Ok(())
 }
 
 /// Determine which remote to use for syncing
-fn determine_target_remote(store: &IssueStore, remote_arg: Option<&str>) -> Result<String> {
+pub(super) fn determine_target_remote(
+    store: &IssueStore,
+    remote_arg: Option<&str>,
+) -> Result<String> {
     if let Some(remote) = remote_arg {
         // User specified a remote, validate it exists
         if store.remote_exists(remote)? {
@@ -184,60 +418,43 @@ fn discover_sync_refs(
     store: &IssueStore,
     specific_issues: Option<&[IssueId]>,
 ) -> Result<Vec<String>> {
-    let mut refs = Vec::new();
-
-    if let Some(issue_ids) = specific_issues {
-        // Sync only specific issues
-        for &issue_id in issue_ids {
-            let ref_name = format!("refs/git-issue/issues/{}", issue_id);
-            if store.ref_exists(&ref_name)? {
-                refs.push(ref_name);
-            } else {
-                return Err(anyhow::anyhow!("Issue #{} does not exist", issue_id));
-            }
+    store.sync_refs(specific_issues).map_err(|err| match err {
+        StorageError::IssueNotFound { issue_id } => {
+            anyhow::anyhow!("Issue #{} does not exist", issue_id)
         }
-    } else {
-        // Sync all issue refs
-        let issue_refs = store.list_issue_refs()?;
-        refs.extend(issue_refs);
-
-        // Also include metadata refs
-        let meta_refs = store.list_meta_refs()?;
-        refs.extend(meta_refs);
-    }
-
-    Ok(refs)
-}
-
-/// Fetch remote refs for comparison
-fn fetch_remote_refs(
-    store: &IssueStore,
-    remote_name: &str,
-    local_refs: &[String],
-) -> Result<HashMap<String, String>> {
-    // This is a placeholder - in a real implementation, this would
-    // fetch the specific refs from the remote for comparison
-    Ok(store.fetch_refs_from_remote(remote_name, local_refs)?)
+        err => err.into(),
+    })
 }
 
 /// Compare local and remote refs to determine sync actions needed
 fn compare_refs(
+    store: &IssueStore,
     local_refs: &[String],
     remote_refs: &HashMap<String, String>,
 ) -> Result<Vec<SyncRef>> {
+    let counter_ref = store.next_issue_id_ref();
     let mut sync_refs = Vec::new();
 
     for ref_name in local_refs {
-        let local_oid = Some("placeholder_local_oid".to_string()); // Would get actual OID
+        let local_oid = store.read_ref(ref_name)?.map(|oid| oid.to_string());
         let remote_oid = remote_refs.get(ref_name).cloned();
 
         let comparison = match (&local_oid, &remote_oid) {
             (Some(_), None) => RefComparisonResult::NewRef,
             (Some(local), Some(remote)) if local == remote => RefComparisonResult::UpToDate,
-            (Some(_), Some(_)) => {
-                // In real implementation, would use git to determine relationship
-                RefComparisonResult::FastForward { local_commits: 1 }
-            }
+            // The counter is a blob without history; it is compared by value instead
+            (Some(_), Some(_)) if *ref_name == counter_ref => RefComparisonResult::Diverged {
+                local_commits: 0,
+                remote_commits: 0,
+            },
+            (Some(local), Some(remote)) => match store.compare_refs(local, remote)? {
+                (local_commits, 0) => RefComparisonResult::FastForward { local_commits },
+                (0, remote_commits) => RefComparisonResult::Behind { remote_commits },
+                (local_commits, remote_commits) => RefComparisonResult::Diverged {
+                    local_commits,
+                    remote_commits,
+                },
+            },
             (None, Some(_)) => RefComparisonResult::LocallyDeleted,
             (None, None) => continue, // Skip non-existent refs
         };
@@ -263,6 +480,42 @@ fn compare_refs(
     Ok(sync_refs)
 }
 
+/// Compare the issue counter by value, so a sync never moves it backwards
+///
+/// A higher remote counter is adopted locally and nothing is pushed; a higher
+/// local counter is pushed over the remote one.
+fn reconcile_issue_counter(
+    store: &mut IssueStore,
+    remote_name: &str,
+    sync_refs: &mut [SyncRef],
+    verbose: bool,
+) -> Result<()> {
+    let counter_ref = store.next_issue_id_ref();
+    let Some(sync_ref) = sync_refs
+        .iter_mut()
+        .find(|sync_ref| sync_ref.ref_name == counter_ref)
+    else {
+        return Ok(());
+    };
+
+    let before = store.next_issue_id()?;
+    sync_ref.comparison = match store.reconcile_issue_counter(remote_name)? {
+        CounterReconciliation::Ahead { remote_oid } => {
+            RefComparisonResult::CounterAhead { remote_oid }
+        }
+        CounterReconciliation::UpToDate => RefComparisonResult::UpToDate,
+    };
+
+    let after = store.next_issue_id()?;
+    if verbose && after != before {
+        println!(
+            "Adopted issue counter {} from {} (was {})",
+            after, remote_name, before
+        );
+    }
+    Ok(())
+}
+
 /// Print conflicts that require user attention
 fn print_conflict_summary(conflicted_refs: &[&SyncRef]) {
     println!("{}", error_message("Sync conflicts detected:"));
@@ -315,6 +568,7 @@ fn print_sync_preview(refs_to_sync: &[&SyncRef], is_dry_run: bool) {
                 format!("push {} new commits", local_commits)
             }
             RefComparisonResult::NewRef => "create new ref".to_string(),
+            RefComparisonResult::CounterAhead { .. } => "push higher issue counter".to_string(),
             RefComparisonResult::Diverged { .. } => "force push (diverged)".to_string(),
             RefComparisonResult::Behind { .. } => "force push (behind)".to_string(),
             _ => "update".to_string(),
@@ -335,15 +589,78 @@ fn perform_sync(
     refs_to_sync: &[&SyncRef],
     args: &SyncArgs,
     _author: Identity,
+) -> Result<SyncSummary> {
+    perform_sync_with(
+        store,
+        remote_name,
+        refs_to_sync,
+        args,
+        |store, remote, ref_name, force| store.push_ref_to_remote(remote, ref_name, force),
+    )
+}
+
+/// Push each ref with `push`, journaling successful pushes so an interrupted
+/// sync can be resumed without pushing the same refs again
+fn perform_sync_with(
+    store: &mut IssueStore,
+    remote_name: &str,
+    refs_to_sync: &[&SyncRef],
+    args: &SyncArgs,
+    mut push: impl FnMut(&IssueStore, &str, &str, bool) -> StorageResult<()>,
 ) -> Result<SyncSummary> {
     let mut summary = SyncSummary::default();
+    let journal = store.sync_journal()?;
 
     for sync_ref in refs_to_sync {
+        let local_oid = sync_ref.local_oid.clone();
+
+        // Skip refs already pushed at this OID by an interrupted sync
+        if let Some(oid) = &local_oid
+            && journal.iter().any(|entry| {
+                entry.remote == remote_name
+                    && entry.ref_name == sync_ref.ref_name
+                    && entry.oid == *oid
+            })
+        {
+            summary.skipped_refs.push(sync_ref.ref_name.clone());
+            if args.verbose {
+                println!("- Already pushed {} (resumed)", sync_ref.ref_name);
+            }
+            continue;
+        }
+
         match sync_ref.comparison {
+            RefComparisonResult::CounterAhead { ref remote_oid } => {
+                // A counter ahead of the remote replaces its blob
+                match push_issue_counter(store, remote_name, remote_oid.clone()) {
+                    Ok(true) => {
+                        journal_push(store, remote_name, &sync_ref.ref_name, local_oid)?;
+                        summary.pushed_refs.push(sync_ref.ref_name.clone());
+                        if args.verbose {
+                            println!("✓ Pushed {}", sync_ref.ref_name);
+                        }
+                    }
+                    Ok(false) => {
+                        summary.skipped_refs.push(sync_ref.ref_name.clone());
+                        if args.verbose {
+                            println!("- Adopted the remote's higher issue counter");
+                        }
+                    }
+                    Err(e) => {
+                        summary
+                            .failed_refs
+                            .push(SyncFailure::new(sync_ref.ref_name.clone(), &e));
+                        if args.verbose {
+                            println!("✗ Failed to push {}: {}", sync_ref.ref_name, e);
+                        }
+                    }
+                }
+            }
             RefComparisonResult::FastForward { .. } | RefComparisonResult::NewRef => {
                 // Safe to push
-                match store.push_ref_to_remote(remote_name, &sync_ref.ref_name, false) {
+                match push(store, remote_name, &sync_ref.ref_name, false) {
                     Ok(_) => {
+                        journal_push(store, remote_name, &sync_ref.ref_name, local_oid)?;
                         summary.pushed_refs.push(sync_ref.ref_name.clone());
                         if args.verbose {
                             println!("✓ Pushed {}", sync_ref.ref_name);
@@ -352,7 +669,7 @@ fn perform_sync(
                     Err(e) => {
                         summary
                             .failed_refs
-                            .push((sync_ref.ref_name.clone(), e.to_string()));
+                            .push(SyncFailure::new(sync_ref.ref_name.clone(), &e));
                         if args.verbose {
                             println!("✗ Failed to push {}: {}", sync_ref.ref_name, e);
                         }
@@ -362,8 +679,9 @@ fn perform_sync(
             RefComparisonResult::Diverged { .. } | RefComparisonResult::Behind { .. } => {
                 // Requires force push
                 let use_lease = args.force && !args.force_without_lease;
-                match store.push_ref_to_remote(remote_name, &sync_ref.ref_name, !use_lease) {
+                match push(store, remote_name, &sync_ref.ref_name, !use_lease) {
                     Ok(_) => {
+                        journal_push(store, remote_name, &sync_ref.ref_name, local_oid)?;
                         summary.pushed_refs.push(sync_ref.ref_name.clone());
                         if args.verbose {
                             let method = if use_lease {
@@ -377,7 +695,7 @@ fn perform_sync(
                     Err(e) => {
                         summary
                             .failed_refs
-                            .push((sync_ref.ref_name.clone(), e.to_string()));
+                            .push(SyncFailure::new(sync_ref.ref_name.clone(), &e));
                         if args.verbose {
                             println!("✗ Failed to force push {}: {}", sync_ref.ref_name, e);
                         }
@@ -391,9 +709,58 @@ fn perform_sync(
         }
     }
 
+    // A complete sync leaves nothing to resume
+    if summary.failed_refs.is_empty() {
+        store.clear_sync_journal(remote_name)?;
+    }
+
     Ok(summary)
 }
 
+/// Push the issue counter with a lease on the remote counter it was reconciled
+/// against, reconciling again whenever another sync moved it in between
+///
+/// Returns whether the counter was pushed; a remote counter that went past the
+/// local one in the meantime is adopted instead.
+fn push_issue_counter(
+    store: &mut IssueStore,
+    remote_name: &str,
+    mut remote_oid: Option<String>,
+) -> StorageResult<bool> {
+    let mut attempts = 1;
+    loop {
+        match store.push_issue_counter(remote_name, remote_oid.as_deref()) {
+            Ok(()) => return Ok(true),
+            Err(StorageError::Git(GitError::ConcurrentReferenceUpdate { .. }))
+                if attempts < COUNTER_PUSH_ATTEMPTS => {}
+            Err(err) => return Err(err),
+        }
+        attempts += 1;
+
+        match store.reconcile_issue_counter(remote_name)? {
+            CounterReconciliation::Ahead { remote_oid: oid } => remote_oid = oid,
+            CounterReconciliation::UpToDate => return Ok(false),
+        }
+    }
+}
+
+/// Record a successful push in the sync journal
+fn journal_push(
+    store: &mut IssueStore,
+    remote_name: &str,
+    ref_name: &str,
+    local_oid: Option<String>,
+) -> Result<()> {
+    if let Some(oid) = local_oid {
+        store.append_sync_journal(SyncJournalEntry {
+            remote: remote_name.to_string(),
+            ref_name: ref_name.to_string(),
+            oid,
+        })?;
+    }
+    Ok(())
+}
+
 /// Print the results of the sync operation
 fn print_sync_results(summary: &SyncSummary) {
     if !summary.pushed_refs.is_empty() {
@@ -414,8 +781,8 @@ fn print_sync_results(summary: &SyncSummary) {
                 summary.failed_refs.len()
             ))
         );
-        for (ref_name, error) in &summary.failed_refs {
-            println!("  {}: {}", ref_name, error);
+        for failure in &summary.failed_refs {
+            println!("  {}: {}", failure.ref_name, failure.error);
         }
     }
 
@@ -435,13 +802,15 @@ mod tests {
     use super::*;
     use crate::common::Identity;
     use crate::storage::IssueStore;
+    use crate::storage::errors::StorageError;
     use std::process::Command;
     use tempfile::TempDir;
 
     /// Test helper functions for setting up mock remote repositories
     pub struct MockRemoteSetup {
-        pub local_temp_dir: TempDir,
-        pub remote_temp_dir: TempDir,
+        // Hold the directories so they live as long as the setup
+        _local_temp_dir: TempDir,
+        _remote_temp_dir: TempDir,
         pub local_path: std::path::PathBuf,
         pub remote_path: std::path::PathBuf,
     }
@@ -457,7 +826,7 @@ mod tests {
 
             // Initialize bare remote repository
             let output = Command::new("git")
-                .args(&["init", "--bare"])
+                .args(["init", "--bare"])
                 .current_dir(&remote_path)
                 .output()
                 .expect("Failed to initialize bare remote repository");
@@ -471,7 +840,7 @@ mod tests {
 
             // Initialize local repository
             let output = Command::new("git")
-                .args(&["init"])
+                .args(["init"])
                 .current_dir(&local_path)
                 .output()
                 .expect("Failed to initialize local repository");
@@ -486,7 +855,7 @@ mod tests {
             // Add remote to local repository
             let remote_url = format!("file://{}", remote_path.display());
             let output = Command::new("git")
-                .args(&["remote", "add", "origin", &remote_url])
+                .args(["remote", "add", "origin", &remote_url])
                 .current_dir(&local_path)
                 .output()
                 .expect("Failed to add remote");
@@ -500,20 +869,20 @@ mod tests {
 
             // Set up basic git config for local repo
             Command::new("git")
-                .args(&["config", "user.name", "Test User"])
+                .args(["config", "user.name", "Test User"])
                 .current_dir(&local_path)
                 .output()
                 .expect("Failed to set user.name");
 
             Command::new("git")
-                .args(&["config", "user.email", "test@example.com"])
+                .args(["config", "user.email", "test@example.com"])
                 .current_dir(&local_path)
                 .output()
                 .expect("Failed to set user.email");
 
             MockRemoteSetup {
-                local_temp_dir,
-                remote_temp_dir,
+                _local_temp_dir: local_temp_dir,
+                _remote_temp_dir: remote_temp_dir,
                 local_path,
                 remote_path,
             }
@@ -537,66 +906,12 @@ mod tests {
                 .expect("Failed to create issue")
         }
 
-        /// Create a mock issue in the remote repository by creating refs directly
-        pub fn create_remote_issue(&self, issue_id: u64, commit_oid: &str) {
-            let ref_name = format!("refs/git-issue/issues/{}", issue_id);
-
-            // Write the commit OID to the ref file in the bare repository
-            let ref_file_path = self.remote_path.join(&ref_name);
-            std::fs::create_dir_all(ref_file_path.parent().unwrap())
-                .expect("Failed to create ref directory");
-            std::fs::write(ref_file_path, format!("{}\n", commit_oid))
-                .expect("Failed to write ref file");
-        }
-
-        /// Simulate concurrent modifications by creating conflicting commits
-        pub fn simulate_concurrent_modification(&self, issue_id: IssueId) {
-            // This would create conflicting commits in both local and remote
-            // For now, just create different refs to simulate divergence
-            self.create_remote_issue(issue_id, "remote_commit_oid_123");
-        }
-
-        /// Assert that sync state matches expected refs
-        pub fn assert_sync_state(
-            &self,
-            expected_local_refs: &[&str],
-            expected_remote_refs: &[&str],
-        ) {
-            // Check local refs
-            let store = IssueStore::open(&self.local_path).expect("Failed to open local store");
-            let local_refs = store.list_issue_refs().expect("Failed to list local refs");
-
-            for expected_ref in expected_local_refs {
-                assert!(
-                    local_refs.iter().any(|r| r == expected_ref),
-                    "Expected local ref {} not found",
-                    expected_ref
-                );
-            }
-
-            // Check remote refs (simplified check)
-            for expected_ref in expected_remote_refs {
-                let ref_file = self.remote_path.join(expected_ref);
-                assert!(
-                    ref_file.exists(),
-                    "Expected remote ref {} not found",
-                    expected_ref
-                );
-            }
-        }
-
         /// Get a test author identity
         pub fn test_author() -> Identity {
             Identity::new("Test User", "test@example.com")
         }
     }
 
-    fn setup_test_sync() -> (TempDir, std::path::PathBuf) {
-        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
-        let repo_path = temp_dir.path().to_path_buf();
-        (temp_dir, repo_path)
-    }
-
     #[test]
     fn test_mock_remote_setup() {
         let setup = MockRemoteSetup::new();
@@ -668,30 +983,44 @@ mod tests {
     }
 
     #[test]
-    fn test_compare_refs_fast_forward() {
+    fn test_compare_refs_new_ref() {
+        let setup = MockRemoteSetup::new();
+        setup.create_local_issue(1, "Test Issue", "Test Description");
+        let store = IssueStore::open(&setup.local_path).expect("Failed to open store");
+
         let local_refs = vec!["refs/git-issue/issues/1".to_string()];
         let remote_refs = HashMap::new(); // No remote ref = new ref
 
-        let result = compare_refs(&local_refs, &remote_refs).expect("Should compare refs");
+        let result = compare_refs(&store, &local_refs, &remote_refs).expect("Should compare refs");
         assert_eq!(result.len(), 1);
         assert!(matches!(result[0].comparison, RefComparisonResult::NewRef));
     }
 
     #[test]
-    fn test_compare_refs_up_to_date() {
-        let local_refs = vec!["refs/git-issue/issues/1".to_string()];
-        let mut remote_refs = HashMap::new();
-        remote_refs.insert(
-            "refs/git-issue/issues/1".to_string(),
-            "placeholder_local_oid".to_string(),
-        );
+    fn test_compare_refs_against_remote_oid() {
+        let setup = MockRemoteSetup::new();
+        let issue_id = setup.create_local_issue(1, "Test Issue", "Test Description");
+        let mut store = IssueStore::open(&setup.local_path).expect("Failed to open store");
 
-        let result = compare_refs(&local_refs, &remote_refs).expect("Should compare refs");
-        assert_eq!(result.len(), 1);
-        assert!(matches!(
+        let local_refs = vec!["refs/git-issue/issues/1".to_string()];
+        let old_head = store.read_ref(&local_refs[0]).unwrap().unwrap().to_string();
+        let remote_refs = HashMap::from([(local_refs[0].clone(), old_head)]);
+        let result = compare_refs(&store, &local_refs, &remote_refs).expect("Should compare refs");
+        assert_eq!(result[0].comparison, RefComparisonResult::UpToDate);
+
+        // A new local event puts the local ref one commit ahead of the remote
+        store
+            .add_comment(
+                issue_id,
+                "Local only".to_string(),
+                MockRemoteSetup::test_author(),
+            )
+            .unwrap();
+        let result = compare_refs(&store, &local_refs, &remote_refs).expect("Should compare refs");
+        assert_eq!(
             result[0].comparison,
-            RefComparisonResult::UpToDate
-        ));
+            RefComparisonResult::FastForward { local_commits: 1 }
+        );
     }
 
     #[test]
@@ -700,19 +1029,22 @@ mod tests {
         setup.create_local_issue(1, "Test Issue", "Test Description");
 
         let args = SyncArgs {
-            remote: Some("origin".to_string()),
+            remote: vec!["origin".to_string()],
+            all_remotes: false,
             dry_run: true,
             force: false,
             force_without_lease: false,
             issues: None,
             verbose: true,
+            repack: false,
+            format: OutputFormat::Text,
         };
 
         // This test would normally call handle_sync, but since our implementation
         // uses placeholder remote operations, we just verify the arguments parse correctly
         assert!(args.dry_run);
         assert!(!args.force);
-        assert_eq!(args.remote, Some("origin".to_string()));
+        assert_eq!(args.remote, vec!["origin".to_string()]);
     }
 
     #[test]
@@ -723,4 +1055,448 @@ mod tests {
         assert!(summary.failed_refs.is_empty());
         assert!(summary.conflicts.is_empty());
     }
+
+    #[test]
+    fn test_sync_summary_json_roundtrip() {
+        let summary = SyncSummary {
+            dry_run: false,
+            pushed_refs: vec!["refs/git-issue/issues/1".to_string()],
+            skipped_refs: vec!["refs/git-issue/issues/2".to_string()],
+            failed_refs: vec![SyncFailure::new(
+                "refs/git-issue/issues/3",
+                "remote rejected",
+            )],
+            conflicts: Vec::new(),
+        };
+
+        let json = serde_json::to_string_pretty(&summary).expect("Should serialize");
+        let value: serde_json::Value = serde_json::from_str(&json).expect("Valid JSON");
+        assert_eq!(
+            value["failed_refs"][0]["ref_name"],
+            "refs/git-issue/issues/3"
+        );
+        assert_eq!(value["failed_refs"][0]["error"], "remote rejected");
+
+        let parsed: SyncSummary = serde_json::from_str(&json).expect("Should deserialize");
+        assert_eq!(parsed, summary);
+    }
+
+    #[test]
+    fn test_perform_sync_resumes_after_interruption() {
+        let setup = MockRemoteSetup::new();
+        for i in 1..=3 {
+            setup.create_local_issue(i, &format!("Issue {}", i), "Description");
+        }
+
+        let mut store = IssueStore::open(&setup.local_path).expect("Failed to open store");
+        let refs = store.list_issue_refs().expect("Failed to list refs");
+        let sync_refs = compare_refs(&store, &refs, &HashMap::new()).expect("Should compare refs");
+        let refs_needing_sync: Vec<&SyncRef> = sync_refs.iter().collect();
+        let args = SyncArgs {
+            remote: vec!["origin".to_string()],
+            all_remotes: false,
+            dry_run: false,
+            force: false,
+            force_without_lease: false,
+            issues: None,
+            verbose: false,
+            repack: false,
+            format: OutputFormat::Text,
+        };
+
+        // The first run is interrupted after two pushes
+        let mut pushed = Vec::new();
+        let summary = perform_sync_with(
+            &mut store,
+            "origin",
+            &refs_needing_sync,
+            &args,
+            |_, _, ref_name, _| {
+                if pushed.len() == 2 {
+                    return Err(StorageError::invalid_event_sequence("connection lost"));
+                }
+                pushed.push(ref_name.to_string());
+                Ok(())
+            },
+        )
+        .expect("Sync should report failures in the summary");
+        assert_eq!(summary.pushed_refs.len(), 2);
+        assert_eq!(summary.failed_refs.len(), 1);
+        assert_eq!(store.sync_journal().unwrap().len(), 2);
+
+        // Resuming only pushes the ref that failed
+        let mut resumed = Vec::new();
+        let summary = perform_sync_with(
+            &mut store,
+            "origin",
+            &refs_needing_sync,
+            &args,
+            |_, _, ref_name, _| {
+                resumed.push(ref_name.to_string());
+                Ok(())
+            },
+        )
+        .expect("Resumed sync should succeed");
+        assert_eq!(resumed.len(), 1);
+        assert!(!pushed.contains(&resumed[0]));
+        assert_eq!(summary.skipped_refs.len(), 2);
+        assert!(summary.failed_refs.is_empty());
+
+        // A complete sync clears the journal
+        assert!(store.sync_journal().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sync_remotes_isolates_failures() {
+        let setup = MockRemoteSetup::new();
+        let backup = MockRemoteSetup::new();
+        setup.create_local_issue(1, "Issue 1", "Description");
+
+        let backup_url = format!("file://{}", backup.remote_path.display());
+        let output = Command::new("git")
+            .args(["remote", "add", "backup", &backup_url])
+            .current_dir(&setup.local_path)
+            .output()
+            .expect("Failed to add remote");
+        assert!(output.status.success());
+
+        let mut store = IssueStore::open(&setup.local_path).expect("Failed to open store");
+        let mut remotes = store.list_remotes().expect("Should list remotes");
+        remotes.sort();
+        assert_eq!(remotes, vec!["backup", "origin"]);
+
+        let args = SyncArgs {
+            remote: Vec::new(),
+            all_remotes: false,
+            dry_run: false,
+            force: false,
+            force_without_lease: false,
+            issues: None,
+            verbose: false,
+            repack: false,
+            format: OutputFormat::Json,
+        };
+        let remotes = vec![
+            "origin".to_string(),
+            "missing".to_string(),
+            "backup".to_string(),
+        ];
+        let reports = sync_remotes(&mut store, &remotes, &args, &MockRemoteSetup::test_author());
+
+        assert_eq!(reports.len(), 3);
+        assert_eq!(reports[0].remote, "origin");
+        assert!(reports[0].error.is_none());
+        assert!(
+            reports[0]
+                .summary
+                .as_ref()
+                .unwrap()
+                .pushed_refs
+                .contains(&"refs/git-issue/issues/1".to_string())
+        );
+
+        assert_eq!(reports[1].remote, "missing");
+        assert_eq!(
+            reports[1].error.as_deref(),
+            Some("Remote 'missing' does not exist")
+        );
+
+        // The failing remote did not stop the one after it
+        assert_eq!(reports[2].remote, "backup");
+        assert!(reports[2].error.is_none());
+        assert_eq!(
+            reports[2].summary.as_ref().unwrap().pushed_refs,
+            reports[0].summary.as_ref().unwrap().pushed_refs
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_handle_sync_fails_when_a_ref_is_rejected() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let setup = MockRemoteSetup::new();
+        for i in 1..=3 {
+            setup.create_local_issue(i, &format!("Issue {}", i), "Description");
+        }
+
+        // The remote refuses issue 2 only
+        let hook = setup.remote_path.join("hooks/update");
+        std::fs::write(&hook, "#!/bin/sh\n[ \"$1\" != refs/git-issue/issues/2 ]\n").unwrap();
+        std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let args = SyncArgs {
+            remote: vec!["origin".to_string()],
+            all_remotes: false,
+            dry_run: false,
+            force: false,
+            force_without_lease: false,
+            issues: None,
+            verbose: false,
+            repack: false,
+            format: OutputFormat::Text,
+        };
+        let err = handle_sync(setup.local_path.clone(), args)
+            .expect_err("A rejected ref should fail the sync");
+        assert_eq!(
+            err.to_string(),
+            "Failed to sync 1 refs: refs/git-issue/issues/2"
+        );
+
+        // The other refs were still pushed
+        let remote = IssueStore::open(&setup.remote_path).unwrap();
+        assert!(
+            remote
+                .read_ref("refs/git-issue/issues/1")
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            remote
+                .read_ref("refs/git-issue/issues/2")
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            remote
+                .read_ref("refs/git-issue/issues/3")
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_sync_empty_repository_is_a_no_op() {
+        let setup = MockRemoteSetup::new();
+        let args = SyncArgs {
+            remote: vec![],
+            all_remotes: false,
+            dry_run: false,
+            force: false,
+            force_without_lease: false,
+            issues: None,
+            verbose: true,
+            repack: false,
+            format: OutputFormat::Text,
+        };
+        handle_sync(setup.local_path.clone(), args).expect("Syncing no issues should succeed");
+
+        let store = IssueStore::open(&setup.local_path).expect("Failed to open store");
+        assert!(store.sync_refs(None).unwrap().is_empty());
+
+        // Nothing reached the remote, not even meta refs
+        let remote = IssueStore::open(&setup.remote_path).unwrap();
+        assert!(remote.sync_refs(None).unwrap().is_empty());
+
+        // Without any remote configured there is still nothing to do
+        let (temp_dir, _repo) = crate::storage::test_helpers::setup_temp_repo();
+        let args = SyncArgs {
+            remote: vec![],
+            all_remotes: false,
+            dry_run: false,
+            force: false,
+            force_without_lease: false,
+            issues: None,
+            verbose: false,
+            repack: false,
+            format: OutputFormat::Text,
+        };
+        handle_sync(temp_dir.path().to_path_buf(), args)
+            .expect("A repository without issues needs no remote");
+    }
+
+    #[test]
+    fn test_sync_all_remotes_without_remotes_fails() {
+        let (temp_dir, _repo) = crate::storage::test_helpers::setup_temp_repo();
+        let mut store = IssueStore::open(temp_dir.path()).expect("Failed to open store");
+        store
+            .create_issue(
+                "Title".to_string(),
+                "".to_string(),
+                MockRemoteSetup::test_author(),
+            )
+            .expect("Failed to create issue");
+
+        let args = SyncArgs {
+            remote: vec![],
+            all_remotes: true,
+            dry_run: false,
+            force: false,
+            force_without_lease: false,
+            issues: None,
+            verbose: false,
+            repack: false,
+            format: OutputFormat::Text,
+        };
+        let err = handle_sync(temp_dir.path().to_path_buf(), args)
+            .expect_err("Syncing with no remotes should fail");
+        assert!(
+            err.to_string().contains("no remotes are configured"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_sync_repack_keeps_issues_readable() {
+        let setup = MockRemoteSetup::new();
+        for i in 1..=3 {
+            setup.create_local_issue(i, &format!("Issue {}", i), "Description");
+        }
+        let store = IssueStore::open(&setup.local_path).unwrap();
+        let before = store.object_counts().unwrap();
+        assert_eq!(before.packs, 0);
+
+        let args = SyncArgs {
+            remote: vec!["origin".to_string()],
+            all_remotes: false,
+            dry_run: false,
+            force: false,
+            force_without_lease: false,
+            issues: None,
+            verbose: true,
+            repack: true,
+            format: OutputFormat::Text,
+        };
+        handle_sync(setup.local_path.clone(), args).expect("Sync with repack should succeed");
+
+        // Issue objects moved into a pack, and the issues still reconstruct;
+        // only unreachable objects and the sync journal stay loose
+        let store = IssueStore::open(&setup.local_path).unwrap();
+        let after = store.object_counts().unwrap();
+        assert_eq!(after.packs, 1);
+        assert!(
+            after.packed >= 9,
+            "3 issues need 3 commits, trees and blobs"
+        );
+        assert!(after.loose < before.loose);
+        let titles: Vec<String> = store
+            .list_issues()
+            .unwrap()
+            .into_iter()
+            .map(|issue| issue.title)
+            .collect();
+        assert_eq!(titles, vec!["Issue 1", "Issue 2", "Issue 3"]);
+
+        let remote = IssueStore::open(&setup.remote_path).unwrap();
+        assert_eq!(remote.get_issue(2).unwrap().title, "Issue 2");
+    }
+
+    #[test]
+    fn test_sync_never_moves_issue_counter_backwards() {
+        let setup = MockRemoteSetup::new();
+        let other_dir = TempDir::new().expect("Failed to create temp directory");
+        let output = Command::new("git")
+            .args(["clone", "--quiet"])
+            .arg(&setup.remote_path)
+            .arg(other_dir.path())
+            .output()
+            .expect("Failed to clone remote");
+        assert!(output.status.success());
+
+        let sync = |path: &std::path::Path| {
+            let args = SyncArgs {
+                remote: vec!["origin".to_string()],
+                all_remotes: false,
+                dry_run: false,
+                force: false,
+                force_without_lease: false,
+                issues: None,
+                verbose: false,
+                repack: false,
+                format: OutputFormat::Text,
+            };
+            handle_sync(path.to_path_buf(), args).expect("Sync should succeed");
+        };
+        let remote_counter = || {
+            let remote = IssueStore::open(&setup.remote_path).unwrap();
+            remote.next_issue_id().unwrap()
+        };
+
+        // Machine A is at 50, machine B only at 30
+        let mut store_a = IssueStore::open(&setup.local_path).unwrap();
+        store_a.advance_issue_id_past(49).unwrap();
+        sync(&setup.local_path);
+        assert_eq!(remote_counter(), 50);
+
+        let mut store_b = IssueStore::open(other_dir.path()).unwrap();
+        store_b.advance_issue_id_past(29).unwrap();
+        sync(other_dir.path());
+
+        // B adopted the higher counter instead of pushing its own
+        assert_eq!(remote_counter(), 50);
+        let store_b = IssueStore::open(other_dir.path()).unwrap();
+        assert_eq!(store_b.next_issue_id().unwrap(), 50);
+
+        // Once B is ahead, its counter is pushed over the remote one
+        let mut store_b = IssueStore::open(other_dir.path()).unwrap();
+        store_b.advance_issue_id_past(59).unwrap();
+        sync(other_dir.path());
+        assert_eq!(remote_counter(), 60);
+
+        // Syncing A again adopts 60 and leaves the remote alone
+        sync(&setup.local_path);
+        assert_eq!(remote_counter(), 60);
+        let store_a = IssueStore::open(&setup.local_path).unwrap();
+        assert_eq!(store_a.next_issue_id().unwrap(), 60);
+    }
+
+    #[test]
+    fn test_issue_counter_push_reconciles_again_when_the_lease_fails() {
+        let setup = MockRemoteSetup::new();
+        let other_dir = TempDir::new().expect("Failed to create temp directory");
+        let output = Command::new("git")
+            .args(["clone", "--quiet"])
+            .arg(&setup.remote_path)
+            .arg(other_dir.path())
+            .output()
+            .expect("Failed to clone remote");
+        assert!(output.status.success());
+
+        let remote_counter = || {
+            let remote = IssueStore::open(&setup.remote_path).unwrap();
+            remote.next_issue_id().unwrap()
+        };
+        let mut store_a = IssueStore::open(&setup.local_path).unwrap();
+        let mut store_b = IssueStore::open(other_dir.path()).unwrap();
+        let push_a = |store_a: &mut IssueStore| {
+            let CounterReconciliation::Ahead { remote_oid } =
+                store_a.reconcile_issue_counter("origin").unwrap()
+            else {
+                panic!("A should be ahead");
+            };
+            assert!(push_issue_counter(store_a, "origin", remote_oid).unwrap());
+        };
+        store_a.advance_issue_id_past(49).unwrap();
+        push_a(&mut store_a);
+        assert_eq!(remote_counter(), 50);
+
+        // B compares against 50, then A pushes 55 before B gets to push 60
+        store_b.advance_issue_id_past(59).unwrap();
+        let CounterReconciliation::Ahead { remote_oid } =
+            store_b.reconcile_issue_counter("origin").unwrap()
+        else {
+            panic!("B should be ahead");
+        };
+        store_a.advance_issue_id_past(54).unwrap();
+        push_a(&mut store_a);
+        assert_eq!(remote_counter(), 55);
+
+        assert!(push_issue_counter(&mut store_b, "origin", remote_oid).unwrap());
+        assert_eq!(remote_counter(), 60);
+
+        // B compares against 60, then A goes past it first: B adopts A's counter
+        store_b.advance_issue_id_past(64).unwrap();
+        let CounterReconciliation::Ahead { remote_oid } =
+            store_b.reconcile_issue_counter("origin").unwrap()
+        else {
+            panic!("B should be ahead");
+        };
+        store_a.advance_issue_id_past(69).unwrap();
+        push_a(&mut store_a);
+
+        assert!(!push_issue_counter(&mut store_b, "origin", remote_oid).unwrap());
+        assert_eq!(remote_counter(), 70);
+        assert_eq!(store_b.next_issue_id().unwrap(), 70);
+    }
 }
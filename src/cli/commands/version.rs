@@ -0,0 +1,47 @@
+use anyhow::Result;
+use clap::Args;
+
+use crate::common::EVENT_SCHEMA_VERSION;
+use crate::storage::repo::REFS_NAMESPACE;
+
+#[derive(Args)]
+pub struct VersionArgs {
+    /// Also print the storage format and library versions, for bug reports
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+pub fn handle_version(args: VersionArgs) -> Result<()> {
+    print!("{}", version_report(args.verbose));
+    Ok(())
+}
+
+/// The crate version, followed by storage and build details when `verbose`
+fn version_report(verbose: bool) -> String {
+    let mut report = format!("git-issue {}\n", env!("CARGO_PKG_VERSION"));
+    if verbose {
+        report.push_str(&format!("ref namespace: {}\n", REFS_NAMESPACE));
+        report.push_str(&format!("event schema version: {}\n", EVENT_SCHEMA_VERSION));
+        report.push_str(&format!("gix: {}\n", env!("GIT_ISSUE_GIX_VERSION")));
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_report_verbose() {
+        assert_eq!(
+            version_report(false),
+            format!("git-issue {}\n", env!("CARGO_PKG_VERSION"))
+        );
+
+        let report = version_report(true);
+        assert!(report.contains(env!("CARGO_PKG_VERSION")));
+        assert!(report.contains(&format!("event schema version: {}", EVENT_SCHEMA_VERSION)));
+        assert!(report.contains("ref namespace: refs/git-issue"));
+        assert!(!report.contains("gix: unknown"));
+    }
+}
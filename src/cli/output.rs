@@ -1,41 +1,93 @@
-use crate::common::{Issue, IssueStatus, Priority};
+use crate::common::{Identity, Issue, IssueId, IssueStatus, Priority};
 use chrono::Utc;
 use console::{Color, style};
 use std::time::Duration;
 
-fn format_time_ago(duration: Duration) -> String {
-    let total_seconds = duration.as_secs();
+/// Output format for commands that can emit machine-readable results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// JSON for scripts and CI
+    Json,
+}
 
-    if total_seconds < 60 {
-        return format!(
-            "{} second{}",
-            total_seconds,
-            if total_seconds == 1 { "" } else { "s" }
-        );
-    }
+/// Language for relative times such as "2 days ago"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Lang {
+    /// English
+    #[default]
+    En,
+    /// French
+    Fr,
+}
 
-    let minutes = total_seconds / 60;
-    if minutes < 60 {
-        return format!("{} minute{}", minutes, if minutes == 1 { "" } else { "s" });
+impl Lang {
+    pub fn units(self) -> &'static TimeUnits {
+        match self {
+            Lang::En => &ENGLISH_UNITS,
+            Lang::Fr => &FRENCH_UNITS,
+        }
     }
+}
 
-    let hours = minutes / 60;
-    if hours < 24 {
-        return format!("{} hour{}", hours, if hours == 1 { "" } else { "s" });
-    }
+/// Translated (singular, plural) unit names and the phrase wrapping an amount
+pub struct TimeUnits {
+    /// Phrase with `{}` standing for the amount, e.g. "{} ago"
+    pub ago: &'static str,
+    pub second: (&'static str, &'static str),
+    pub minute: (&'static str, &'static str),
+    pub hour: (&'static str, &'static str),
+    pub day: (&'static str, &'static str),
+    pub month: (&'static str, &'static str),
+    pub year: (&'static str, &'static str),
+}
 
+pub const ENGLISH_UNITS: TimeUnits = TimeUnits {
+    ago: "{} ago",
+    second: ("second", "seconds"),
+    minute: ("minute", "minutes"),
+    hour: ("hour", "hours"),
+    day: ("day", "days"),
+    month: ("month", "months"),
+    year: ("year", "years"),
+};
+
+pub const FRENCH_UNITS: TimeUnits = TimeUnits {
+    ago: "il y a {}",
+    second: ("seconde", "secondes"),
+    minute: ("minute", "minutes"),
+    hour: ("heure", "heures"),
+    day: ("jour", "jours"),
+    month: ("mois", "mois"),
+    year: ("an", "ans"),
+};
+
+fn format_time_ago(duration: Duration, units: &TimeUnits) -> String {
+    let total_seconds = duration.as_secs();
+    let minutes = total_seconds / 60;
+    let hours = minutes / 60;
     let days = hours / 24;
-    if days < 30 {
-        return format!("{} day{}", days, if days == 1 { "" } else { "s" });
-    }
-
     let months = days / 30;
-    if months < 12 {
-        return format!("{} month{}", months, if months == 1 { "" } else { "s" });
-    }
-
     let years = months / 12;
-    format!("{} year{}", years, if years == 1 { "" } else { "s" })
+
+    let (amount, (singular, plural)) = if total_seconds < 60 {
+        (total_seconds, units.second)
+    } else if minutes < 60 {
+        (minutes, units.minute)
+    } else if hours < 24 {
+        (hours, units.hour)
+    } else if days < 30 {
+        (days, units.day)
+    } else if months < 12 {
+        (months, units.month)
+    } else {
+        (years, units.year)
+    };
+
+    let unit = if amount == 1 { singular } else { plural };
+    units.ago.replace("{}", &format!("{} {}", amount, unit))
 }
 
 fn truncate_to_first_paragraph(text: &str) -> (String, Option<usize>) {
@@ -62,14 +114,108 @@ fn truncate_to_first_paragraph(text: &str) -> (String, Option<usize>) {
     }
 }
 
-pub fn format_issue_status(status: &IssueStatus) -> console::StyledObject<&str> {
-    match status {
-        IssueStatus::Todo => style("TODO").fg(Color::Yellow),
-        IssueStatus::InProgress => style("IN PROGRESS").fg(Color::Blue),
-        IssueStatus::Done => style("DONE").fg(Color::Green),
+/// Colors accepted for `status.<status>.color`, besides ANSI numbers 0-255
+const STATUS_COLORS: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// How a status is displayed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusStyle {
+    pub label: String,
+    pub color: Color,
+}
+
+/// Display label and color of each status
+///
+/// Only the display changes; statuses are still parsed and stored by their
+/// canonical names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusStyles {
+    todo: StatusStyle,
+    in_progress: StatusStyle,
+    done: StatusStyle,
+}
+
+impl Default for StatusStyles {
+    fn default() -> Self {
+        let status_style = |label: &str, color| StatusStyle {
+            label: label.to_string(),
+            color,
+        };
+        Self {
+            todo: status_style("TODO", Color::Yellow),
+            in_progress: status_style("IN PROGRESS", Color::Blue),
+            done: status_style("DONE", Color::Green),
+        }
     }
 }
 
+impl StatusStyles {
+    pub fn get(&self, status: &IssueStatus) -> &StatusStyle {
+        match status {
+            IssueStatus::Todo => &self.todo,
+            IssueStatus::InProgress => &self.in_progress,
+            IssueStatus::Done => &self.done,
+        }
+    }
+
+    /// Override the label and color of `status`, with the color given by name
+    pub fn configure(
+        &mut self,
+        status: &IssueStatus,
+        label: Option<String>,
+        color: Option<&str>,
+    ) -> Result<(), String> {
+        let status_style = match status {
+            IssueStatus::Todo => &mut self.todo,
+            IssueStatus::InProgress => &mut self.in_progress,
+            IssueStatus::Done => &mut self.done,
+        };
+        if let Some(label) = label.filter(|label| !label.trim().is_empty()) {
+            status_style.label = label.trim().to_string();
+        }
+        if let Some(color) = color {
+            status_style.color = parse_color(color).ok_or_else(|| {
+                format!(
+                    "Invalid color '{}' for status {}. Valid colors: {}, or 0-255",
+                    color,
+                    status,
+                    STATUS_COLORS.join(", ")
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a color name or ANSI color number
+fn parse_color(color: &str) -> Option<Color> {
+    let color = color.trim().to_lowercase();
+    if let Ok(number) = color.parse::<u8>() {
+        return Some(Color::Color256(number));
+    }
+    match color.as_str() {
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "white" => Some(Color::White),
+        _ => None,
+    }
+}
+
+pub fn format_issue_status<'a>(
+    status: &IssueStatus,
+    styles: &'a StatusStyles,
+) -> console::StyledObject<&'a str> {
+    let status_style = styles.get(status);
+    style(status_style.label.as_str()).fg(status_style.color)
+}
+
 pub fn format_priority(priority: &Priority) -> console::StyledObject<String> {
     let priority_str = match priority {
         Priority::None => "—".to_string(), // Em dash for no priority
@@ -88,7 +234,40 @@ pub fn format_priority(priority: &Priority) -> console::StyledObject<String> {
     }
 }
 
-pub fn format_issue_compact(issue: &Issue) -> String {
+/// Number of digits in the largest issue ID, for use with `--pad-ids`
+pub fn max_id_width(issues: &[Issue]) -> usize {
+    issues
+        .iter()
+        .map(|issue| issue.id.to_string().len())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Zero-pad an issue ID to `width` digits; a width of 0 leaves it unpadded
+fn pad_id(id: IssueId, width: usize) -> String {
+    format!("{:0width$}", id, width = width)
+}
+
+/// Wrap `text` in an OSC 8 terminal hyperlink to the issue, if a URL template is given
+///
+/// `{id}` in the template is replaced by the issue ID.
+fn link_issue_id(text: String, id: IssueId, url_template: Option<&str>) -> String {
+    match url_template {
+        Some(template) => format!(
+            "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\",
+            template.replace("{id}", &id.to_string()),
+            text
+        ),
+        None => text,
+    }
+}
+
+pub fn format_issue_compact(
+    issue: &Issue,
+    id_width: usize,
+    url_template: Option<&str>,
+    styles: &StatusStyles,
+) -> String {
     let priority_part = if issue.priority == Priority::None {
         String::new()
     } else {
@@ -96,47 +275,95 @@ pub fn format_issue_compact(issue: &Issue) -> String {
     };
 
     format!(
-        "#{} [{}]{} {}",
-        style(issue.id).bold(),
-        format_issue_status(&issue.status),
+        "{} [{}]{} {}",
+        link_issue_id(
+            format!("#{}", style(pad_id(issue.id, id_width)).bold()),
+            issue.id,
+            url_template
+        ),
+        format_issue_status(&issue.status, styles),
         priority_part,
         issue.title
     )
 }
 
-pub fn format_issue_detailed(issue: &Issue) -> String {
-    format_issue_internal(issue, false)
+/// Format an issue for `show`, headed by the same line `list --compact` prints
+pub fn format_issue_detailed(
+    issue: &Issue,
+    url_template: Option<&str>,
+    units: &TimeUnits,
+    styles: &StatusStyles,
+) -> String {
+    let header = format_issue_compact(issue, 0, url_template, styles);
+    format!(
+        "{}\n{}",
+        header,
+        format_issue_body(issue, false, units, styles)
+    )
 }
 
-pub fn format_issue_list_long(issue: &Issue) -> String {
-    format_issue_internal(issue, true)
+pub fn format_issue_list_long(
+    issue: &Issue,
+    id_width: usize,
+    url_template: Option<&str>,
+    units: &TimeUnits,
+    styles: &StatusStyles,
+) -> String {
+    let header = format!(
+        "Issue {}: {}",
+        link_issue_id(
+            style(format!("#{}", pad_id(issue.id, id_width)))
+                .bold()
+                .cyan()
+                .to_string(),
+            issue.id,
+            url_template
+        ),
+        style(&issue.title).bold()
+    );
+    format!(
+        "{}\n{}",
+        header,
+        format_issue_body(issue, true, units, styles)
+    )
+}
+
+/// " on behalf of Name (email)" for an author acting for someone else, or nothing
+fn on_behalf_of_suffix(on_behalf_of: Option<&Identity>) -> String {
+    on_behalf_of
+        .map(|identity| format!(" on behalf of {} ({})", identity.name, identity.email))
+        .unwrap_or_default()
 }
 
-fn format_issue_internal(issue: &Issue, truncate_description: bool) -> String {
+/// Everything below an issue's header line
+fn format_issue_body(
+    issue: &Issue,
+    truncate_description: bool,
+    units: &TimeUnits,
+    styles: &StatusStyles,
+) -> String {
     let mut output = String::new();
 
     output.push_str(&format!(
-        "Issue {}: {}\n",
-        style(format!("#{}", issue.id)).bold().cyan(),
-        style(&issue.title).bold()
+        "Status: {}\n",
+        format_issue_status(&issue.status, styles)
     ));
-
-    output.push_str(&format!("Status: {}\n", format_issue_status(&issue.status)));
     output.push_str(&format!("Priority: {}\n", format_priority(&issue.priority)));
 
     let created_time_since = Utc::now() - issue.created_at;
     output.push_str(&format!(
-        "Created by: {} ({}), {} ago ({})\n",
+        "Created by: {} ({}){}, {} ({})\n",
         style(&issue.created_by.name).green(),
         issue.created_by.email,
-        format_time_ago(created_time_since.to_std().unwrap_or_default()),
+        on_behalf_of_suffix(issue.created_on_behalf_of.as_ref()),
+        format_time_ago(created_time_since.to_std().unwrap_or_default(), units),
         issue.created_at.format("%Y-%m-%d %H:%M:%S")
     ));
 
     let updated_time_since = Utc::now() - issue.updated_at;
     output.push_str(&format!(
-        "Last updated: {} ago ({})\n",
-        format_time_ago(updated_time_since.to_std().unwrap_or_default()),
+        "Last updated: {} ({})\n",
+        format_time_ago(updated_time_since.to_std().unwrap_or_default(), units),
         issue.updated_at.format("%Y-%m-%d %H:%M:%S")
     ));
 
@@ -172,6 +399,13 @@ fn format_issue_internal(issue: &Issue, truncate_description: bool) -> String {
         ));
     }
 
+    if !issue.fields.is_empty() {
+        output.push_str("Fields:\n");
+        for (key, value) in &issue.fields {
+            output.push_str(&format!("  {}: {}\n", style(key).bold(), value));
+        }
+    }
+
     if !issue.description.is_empty() {
         output.push_str("\nDescription:\n");
         if truncate_description {
@@ -198,18 +432,160 @@ fn format_issue_internal(issue: &Issue, truncate_description: bool) -> String {
         for comment in &issue.comments {
             let time_since = Utc::now() - comment.created_at;
             output.push_str(&format!(
-                "  {} by {}, {} ago ({}):\n",
+                "  {} by {}{}, {} ({}):\n",
                 style(&comment.id).dim(),
                 style(&comment.author.name).green(),
-                format_time_ago(time_since.to_std().unwrap_or_default()),
+                on_behalf_of_suffix(comment.on_behalf_of.as_ref()),
+                format_time_ago(time_since.to_std().unwrap_or_default(), units),
                 comment.created_at.format("%Y-%m-%d %H:%M")
             ));
             output.push_str(&format!("    {}\n", comment.content));
         }
     }
 
-    output.push_str("\n");
+    output.push('\n');
+
+    output
+}
+
+/// Format an issue as a markdown document, e.g. for pasting into a pull request
+///
+/// Comments appear in the order they were made, each under a
+/// `**Author Name** (email) — 2 days ago:` header.
+pub fn format_issue_markdown(issue: &Issue, units: &TimeUnits) -> String {
+    let mut output = format!("# #{} {}\n\n", issue.id, issue.title);
+
+    output.push_str(&format!("- **Status:** {}\n", issue.status));
+    output.push_str(&format!("- **Priority:** {}\n", issue.priority));
+    let created_time_since = Utc::now() - issue.created_at;
+    output.push_str(&format!(
+        "- **Created by:** {} ({}){}, {}\n",
+        issue.created_by.name,
+        issue.created_by.email,
+        on_behalf_of_suffix(issue.created_on_behalf_of.as_ref()),
+        format_time_ago(created_time_since.to_std().unwrap_or_default(), units)
+    ));
+    if !issue.assignees.is_empty() {
+        let assignees: Vec<String> = issue
+            .assignees
+            .iter()
+            .map(|assignee| format!("{} ({})", assignee.name, assignee.email))
+            .collect();
+        output.push_str(&format!("- **Assigned to:** {}\n", assignees.join(", ")));
+    }
+    if !issue.labels.is_empty() {
+        output.push_str(&format!("- **Labels:** {}\n", issue.labels.join(", ")));
+    }
+
+    if !issue.description.is_empty() {
+        output.push_str(&format!("\n{}\n", issue.description.trim_end()));
+    }
+
+    if !issue.comments.is_empty() {
+        output.push_str("\n## Comments\n");
+        for comment in &issue.comments {
+            let time_since = Utc::now() - comment.created_at;
+            output.push_str(&format!(
+                "\n**{}** ({}){} — {}:\n\n{}\n",
+                comment.author.name,
+                comment.author.email,
+                on_behalf_of_suffix(comment.on_behalf_of.as_ref()),
+                format_time_ago(time_since.to_std().unwrap_or_default(), units),
+                comment.content.trim_end()
+            ));
+        }
+    }
+
+    output
+}
+
+/// Format the "References:" footer for issues mentioned as `#N` in a description
+/// Placeholders understood by `--template`
+pub const TEMPLATE_FIELDS: [&str; 8] = [
+    "id",
+    "title",
+    "status",
+    "priority",
+    "assignees",
+    "labels",
+    "created",
+    "updated",
+];
+
+/// Check that `template` only uses known placeholders, without rendering it
+pub fn validate_issue_template(template: &str) -> Result<(), String> {
+    expand_template(template, |_| String::new()).map(|_| ())
+}
+
+/// Expand the `{field}` placeholders of `template` for one issue, like `git log --format`
+///
+/// Lists are comma-separated and times are RFC 3339. `{{` and `}}` give literal braces.
+pub fn format_issue_template(issue: &Issue, template: &str) -> Result<String, String> {
+    expand_template(template, |field| match field {
+        "id" => issue.id.to_string(),
+        "title" => issue.title.clone(),
+        "status" => issue.status.to_string(),
+        "priority" => issue.priority.to_string(),
+        "assignees" => issue
+            .assignees
+            .iter()
+            .map(|assignee| assignee.email.as_str())
+            .collect::<Vec<_>>()
+            .join(","),
+        "labels" => issue.labels.join(","),
+        "created" => issue.created_at.to_rfc3339(),
+        "updated" => issue.updated_at.to_rfc3339(),
+        _ => unreachable!("placeholders are checked against TEMPLATE_FIELDS"),
+    })
+}
+
+fn expand_template(template: &str, value: impl Fn(&str) -> String) -> Result<String, String> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(index) = rest.find(['{', '}']) {
+        output.push_str(&rest[..index]);
+        let tail = &rest[index..];
+        if let Some(after) = tail.strip_prefix("{{") {
+            output.push('{');
+            rest = after;
+        } else if let Some(after) = tail.strip_prefix("}}") {
+            output.push('}');
+            rest = after;
+        } else if let Some(after) = tail.strip_prefix('{') {
+            let end = after
+                .find('}')
+                .ok_or_else(|| format!("Unclosed placeholder in template '{}'", template))?;
+            let field = &after[..end];
+            if !TEMPLATE_FIELDS.contains(&field) {
+                return Err(format!(
+                    "Unknown placeholder '{{{}}}'. Valid placeholders: {}",
+                    field,
+                    TEMPLATE_FIELDS.join(", ")
+                ));
+            }
+            output.push_str(&value(field));
+            rest = &after[end + 1..];
+        } else {
+            return Err(format!(
+                "Unmatched '}}' in template '{}'; use '}}}}' for a literal brace",
+                template
+            ));
+        }
+    }
+    output.push_str(rest);
+    Ok(output)
+}
 
+pub fn format_issue_references(references: &[Issue]) -> String {
+    let mut output = String::from("References:\n");
+    for issue in references {
+        output.push_str(&format!(
+            "  {} ({}) [{}]\n",
+            style(format!("#{}", issue.id)).cyan(),
+            issue.title,
+            issue.status
+        ));
+    }
     output
 }
 
@@ -240,18 +616,22 @@ mod tests {
             status: IssueStatus::Todo,
             priority: Priority::None,
             created_by: author,
+            created_on_behalf_of: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             assignees: Vec::new(),
             labels: vec!["test".to_string(), "formatting".to_string()],
             comments: vec![],
+            closed_at: None,
+            fields: Default::default(),
+            reopen_count: 0,
         }
     }
 
     #[test]
     fn test_format_issue_compact() {
         let issue = create_test_issue();
-        let formatted = format_issue_compact(&issue);
+        let formatted = format_issue_compact(&issue, 0, None, &StatusStyles::default());
 
         // Should contain the ID, status in brackets, and title
         assert!(formatted.contains("#42"));
@@ -303,7 +683,8 @@ mod tests {
         issue.description =
             "First paragraph here.\n\nSecond paragraph with additional information.".to_string();
 
-        let formatted = format_issue_detailed(&issue);
+        let formatted =
+            format_issue_detailed(&issue, None, &ENGLISH_UNITS, &StatusStyles::default());
 
         // Should contain the full description in detailed view
         assert!(formatted.contains("First paragraph here."));
@@ -320,7 +701,8 @@ mod tests {
         issue.description =
             "First paragraph here.\n\nSecond paragraph with additional information.".to_string();
 
-        let formatted = format_issue_list_long(&issue);
+        let formatted =
+            format_issue_list_long(&issue, 0, None, &ENGLISH_UNITS, &StatusStyles::default());
 
         // Should contain the first paragraph
         assert!(formatted.contains("First paragraph here."));
@@ -336,7 +718,8 @@ mod tests {
     #[test]
     fn test_format_issue_detailed_single_paragraph() {
         let issue = create_test_issue();
-        let formatted = format_issue_detailed(&issue);
+        let formatted =
+            format_issue_detailed(&issue, None, &ENGLISH_UNITS, &StatusStyles::default());
 
         // Should contain the full description
         assert!(formatted.contains("Single paragraph description"));
@@ -367,7 +750,7 @@ mod tests {
     fn test_format_issue_compact_with_priority() {
         let mut issue = create_test_issue();
         issue.priority = Priority::High;
-        let formatted = format_issue_compact(&issue);
+        let formatted = format_issue_compact(&issue, 0, None, &StatusStyles::default());
 
         // Should contain the ID, status, priority, and title
         assert!(formatted.contains("#42"));
@@ -386,7 +769,7 @@ mod tests {
     #[test]
     fn test_format_issue_compact_no_priority() {
         let issue = create_test_issue(); // Priority::None by default
-        let formatted = format_issue_compact(&issue);
+        let formatted = format_issue_compact(&issue, 0, None, &StatusStyles::default());
 
         // Should contain the ID, status, and title
         assert!(formatted.contains("#42"));
@@ -405,13 +788,53 @@ mod tests {
     fn test_format_issue_detailed_with_priority() {
         let mut issue = create_test_issue();
         issue.priority = Priority::Medium;
-        let formatted = format_issue_detailed(&issue);
+        let formatted =
+            format_issue_detailed(&issue, None, &ENGLISH_UNITS, &StatusStyles::default());
 
         // Should contain priority information
         assert!(formatted.contains("Priority: MED"));
         assert!(formatted.contains("Status: TODO"));
     }
 
+    #[test]
+    fn test_format_issue_detailed_shows_comment_on_behalf_of() {
+        use crate::common::Comment;
+
+        let mut issue = create_test_issue();
+        let mut comment = Comment::new(
+            "42-1".to_string(),
+            "Reported via the form".to_string(),
+            Identity::new("Triage Bot", "bot@example.com"),
+        );
+        comment.on_behalf_of = Some(Identity::new("Alice", "alice@example.com"));
+        issue.comments.push(comment);
+
+        let formatted =
+            format_issue_detailed(&issue, None, &ENGLISH_UNITS, &StatusStyles::default());
+        assert!(
+            console::strip_ansi_codes(&formatted)
+                .contains("42-1 by Triage Bot on behalf of Alice (alice@example.com), ")
+        );
+    }
+
+    #[test]
+    fn test_format_issue_detailed_starts_with_compact_header() {
+        let mut issue = create_test_issue();
+        issue.priority = Priority::High;
+        let formatted =
+            format_issue_detailed(&issue, None, &ENGLISH_UNITS, &StatusStyles::default());
+
+        let header = formatted.lines().next().unwrap();
+        assert_eq!(
+            console::strip_ansi_codes(header),
+            "#42 [TODO] [HIGH] Test Issue Title"
+        );
+        assert_eq!(
+            header,
+            format_issue_compact(&issue, 0, None, &StatusStyles::default())
+        );
+    }
+
     #[test]
     fn test_all_priority_levels_format() {
         let priorities = [
@@ -433,4 +856,136 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_format_issue_compact_padded_ids_align() {
+        let mut small = create_test_issue();
+        small.id = 7;
+        let mut large = create_test_issue();
+        large.id = 1234;
+
+        let width = max_id_width(&[small.clone(), large.clone()]);
+        assert_eq!(width, 4);
+
+        let small_line = console::strip_ansi_codes(&format_issue_compact(
+            &small,
+            width,
+            None,
+            &StatusStyles::default(),
+        ))
+        .to_string();
+        let large_line = console::strip_ansi_codes(&format_issue_compact(
+            &large,
+            width,
+            None,
+            &StatusStyles::default(),
+        ))
+        .to_string();
+        assert!(small_line.starts_with("#0007 [TODO]"));
+        assert!(large_line.starts_with("#1234 [TODO]"));
+        assert_eq!(small_line.find('['), large_line.find('['));
+
+        // Without padding IDs are printed as-is
+        let unpadded = console::strip_ansi_codes(&format_issue_compact(
+            &small,
+            0,
+            None,
+            &StatusStyles::default(),
+        ))
+        .to_string();
+        assert!(unpadded.starts_with("#7 [TODO]"));
+    }
+
+    #[test]
+    fn test_issue_id_hyperlink_only_with_template() {
+        let issue = create_test_issue();
+        let osc8 = "\x1b]8;;https://tracker/42\x1b\\";
+
+        let linked = format_issue_compact(
+            &issue,
+            0,
+            Some("https://tracker/{id}"),
+            &StatusStyles::default(),
+        );
+        assert!(linked.contains(osc8));
+        assert!(linked.contains("\x1b]8;;\x1b\\"));
+        let linked = format_issue_detailed(
+            &issue,
+            Some("https://tracker/{id}"),
+            &ENGLISH_UNITS,
+            &StatusStyles::default(),
+        );
+        assert!(linked.contains(osc8));
+
+        let plain = format_issue_compact(&issue, 0, None, &StatusStyles::default());
+        assert!(!plain.contains("\x1b]8;"));
+    }
+
+    #[test]
+    fn test_format_time_ago_localized() {
+        let two_days = Duration::from_secs(2 * 24 * 60 * 60);
+        assert_eq!(format_time_ago(two_days, &ENGLISH_UNITS), "2 days ago");
+        assert_eq!(format_time_ago(two_days, &FRENCH_UNITS), "il y a 2 jours");
+        assert_eq!(
+            format_time_ago(Duration::from_secs(3600), &FRENCH_UNITS),
+            "il y a 1 heure"
+        );
+    }
+
+    #[test]
+    fn test_format_issue_markdown_comment_headers() {
+        use crate::common::Comment;
+
+        let mut issue = create_test_issue();
+        let alice = Identity::new("Alice", "alice@example.com");
+        let bob = Identity::new("Bob", "bob@example.com");
+        issue.comments = vec![
+            Comment {
+                id: "c1".to_string(),
+                content: "First!".to_string(),
+                author: alice,
+                on_behalf_of: None,
+                created_at: Utc::now() - chrono::Duration::days(2),
+            },
+            Comment {
+                id: "c2".to_string(),
+                content: "Second.".to_string(),
+                author: bob,
+                on_behalf_of: None,
+                created_at: Utc::now() - chrono::Duration::hours(3),
+            },
+        ];
+
+        let markdown = format_issue_markdown(&issue, &ENGLISH_UNITS);
+        assert!(markdown.starts_with("# #42 Test Issue Title\n"));
+        let first = markdown
+            .find("**Alice** (alice@example.com) — 2 days ago:\n\nFirst!\n")
+            .expect("first comment header");
+        let second = markdown
+            .find("**Bob** (bob@example.com) — 3 hours ago:\n\nSecond.\n")
+            .expect("second comment header");
+        assert!(first < second, "comments should be chronological");
+    }
+
+    #[test]
+    fn test_format_issue_template() {
+        let mut issue = create_test_issue();
+        issue.status = IssueStatus::InProgress;
+        issue.assignees = vec![Identity::new("Ada", "ada@example.com")];
+
+        assert_eq!(
+            format_issue_template(&issue, "#{id} [{status}] {title} ({labels}) @{assignees}")
+                .unwrap(),
+            "#42 [in-progress] Test Issue Title (test,formatting) @ada@example.com"
+        );
+        assert_eq!(
+            format_issue_template(&issue, "{{{priority}}}").unwrap(),
+            "{none}"
+        );
+
+        let err = validate_issue_template("{id} {author}").unwrap_err();
+        assert!(err.starts_with("Unknown placeholder '{author}'"));
+        assert!(validate_issue_template("{id").is_err());
+        assert!(validate_issue_template("id}").is_err());
+    }
 }
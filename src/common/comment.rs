@@ -8,6 +8,9 @@ pub struct Comment {
     pub id: CommentId,
     pub content: String,
     pub author: Identity,
+    /// The person an automated `author` commented for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Identity>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -19,6 +22,7 @@ impl Comment {
             id,
             content,
             author,
+            on_behalf_of: None,
             created_at: Utc::now(),
         }
     }
@@ -1,11 +1,11 @@
+#[cfg(test)]
+use std::collections::HashMap;
+
 /// Trait for accessing environment variables in a testable way
 ///
 /// This trait provides an abstraction over environment variable access,
 /// allowing for dependency injection and mocking in tests without using
 /// unsafe global state manipulation.
-
-#[cfg(test)]
-use std::collections::HashMap;
 pub trait EnvProvider {
     /// Get the value of an environment variable
     fn get_var(&self, key: &str) -> Option<String>;
@@ -26,6 +26,13 @@ pub struct MockEnvProvider {
     vars: HashMap<String, String>,
 }
 
+#[cfg(test)]
+impl Default for MockEnvProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 impl MockEnvProvider {
     /// Create a new empty mock environment provider
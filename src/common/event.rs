@@ -3,80 +3,131 @@ use serde::{Deserialize, Serialize};
 
 use crate::common::{CommentId, Identity, IssueStatus, Priority};
 
+/// Version of the stored event format, written as `schema_version` next to each event
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IssueEvent {
     Created {
         title: String,
         description: String,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     StatusChanged {
         from: IssueStatus,
         to: IssueStatus,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     CommentAdded {
         comment_id: CommentId,
         content: String,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     LabelAdded {
         label: String,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     LabelRemoved {
         label: String,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     TitleChanged {
         old_title: String,
         new_title: String,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     AssigneeChanged {
         old_assignee: Option<Identity>,
         new_assignee: Option<Identity>,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     AssigneesChanged {
         old_assignees: Vec<Identity>,
         new_assignees: Vec<Identity>,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     DescriptionChanged {
         old_description: String,
         new_description: String,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     PriorityChanged {
         old_priority: Priority,
         new_priority: Priority,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
     CreatedByChanged {
         old_created_by: Identity,
         new_created_by: Identity,
         author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
+        timestamp: DateTime<Utc>,
+    },
+    /// A custom field was set, or cleared when `value` is `None`
+    FieldSet {
+        key: String,
+        value: Option<String>,
+        author: Identity,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        on_behalf_of: Option<Identity>,
         timestamp: DateTime<Utc>,
     },
 }
 
 impl IssueEvent {
+    /// Names of the event types this version knows, as used for the tag of a stored event
+    pub const KINDS: [&'static str; 12] = [
+        "Created",
+        "StatusChanged",
+        "CommentAdded",
+        "LabelAdded",
+        "LabelRemoved",
+        "TitleChanged",
+        "AssigneeChanged",
+        "AssigneesChanged",
+        "DescriptionChanged",
+        "PriorityChanged",
+        "CreatedByChanged",
+        "FieldSet",
+    ];
+
     pub fn created(title: String, description: String, author: Identity) -> Self {
         IssueEvent::Created {
             title,
             description,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -86,6 +137,7 @@ impl IssueEvent {
             from,
             to,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -97,6 +149,7 @@ impl IssueEvent {
             comment_id,
             content,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -105,6 +158,7 @@ impl IssueEvent {
         IssueEvent::LabelAdded {
             label,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -113,6 +167,7 @@ impl IssueEvent {
         IssueEvent::LabelRemoved {
             label,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -122,6 +177,7 @@ impl IssueEvent {
             old_title,
             new_title,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -135,6 +191,7 @@ impl IssueEvent {
             old_assignee,
             new_assignee,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -148,6 +205,7 @@ impl IssueEvent {
             old_assignees,
             new_assignees,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -161,6 +219,7 @@ impl IssueEvent {
             old_description,
             new_description,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -174,6 +233,7 @@ impl IssueEvent {
             old_priority,
             new_priority,
             author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -187,6 +247,17 @@ impl IssueEvent {
             old_created_by,
             new_created_by,
             author,
+            on_behalf_of: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    pub fn field_set(key: String, value: Option<String>, author: Identity) -> Self {
+        IssueEvent::FieldSet {
+            key,
+            value,
+            author,
+            on_behalf_of: None,
             timestamp: Utc::now(),
         }
     }
@@ -204,6 +275,108 @@ impl IssueEvent {
             IssueEvent::DescriptionChanged { author, .. } => author,
             IssueEvent::PriorityChanged { author, .. } => author,
             IssueEvent::CreatedByChanged { author, .. } => author,
+            IssueEvent::FieldSet { author, .. } => author,
+        }
+    }
+
+    /// The person the author acted for, when the author is a bot or service account
+    ///
+    /// Every variant carries this as an optional `on_behalf_of` field, which is left
+    /// out of the stored JSON when unset.
+    pub fn on_behalf_of(&self) -> Option<&Identity> {
+        match self {
+            IssueEvent::Created { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::StatusChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::CommentAdded { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::LabelAdded { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::LabelRemoved { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::TitleChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::AssigneeChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::AssigneesChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::DescriptionChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::PriorityChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::CreatedByChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::FieldSet { on_behalf_of, .. } => on_behalf_of,
+        }
+        .as_ref()
+    }
+
+    /// Record that the author acted for `identity`
+    pub fn with_on_behalf_of(mut self, identity: Identity) -> Self {
+        let on_behalf_of = match &mut self {
+            IssueEvent::Created { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::StatusChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::CommentAdded { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::LabelAdded { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::LabelRemoved { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::TitleChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::AssigneeChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::AssigneesChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::DescriptionChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::PriorityChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::CreatedByChanged { on_behalf_of, .. } => on_behalf_of,
+            IssueEvent::FieldSet { on_behalf_of, .. } => on_behalf_of,
+        };
+        *on_behalf_of = Some(identity);
+        self
+    }
+
+    pub fn timestamp(&self) -> &DateTime<Utc> {
+        match self {
+            IssueEvent::Created { timestamp, .. } => timestamp,
+            IssueEvent::StatusChanged { timestamp, .. } => timestamp,
+            IssueEvent::CommentAdded { timestamp, .. } => timestamp,
+            IssueEvent::LabelAdded { timestamp, .. } => timestamp,
+            IssueEvent::LabelRemoved { timestamp, .. } => timestamp,
+            IssueEvent::TitleChanged { timestamp, .. } => timestamp,
+            IssueEvent::AssigneeChanged { timestamp, .. } => timestamp,
+            IssueEvent::AssigneesChanged { timestamp, .. } => timestamp,
+            IssueEvent::DescriptionChanged { timestamp, .. } => timestamp,
+            IssueEvent::PriorityChanged { timestamp, .. } => timestamp,
+            IssueEvent::CreatedByChanged { timestamp, .. } => timestamp,
+            IssueEvent::FieldSet { timestamp, .. } => timestamp,
+        }
+    }
+
+    /// One-line description of the event, used as its commit message
+    pub fn summary(&self) -> String {
+        match self {
+            IssueEvent::Created { title, .. } => format!("Created: {}", title),
+            IssueEvent::StatusChanged { from, to, .. } => {
+                format!("StatusChanged: {} → {}", from, to)
+            }
+            IssueEvent::CommentAdded { comment_id, .. } => format!("CommentAdded: {}", comment_id),
+            IssueEvent::LabelAdded { label, .. } => format!("LabelAdded: {}", label),
+            IssueEvent::LabelRemoved { label, .. } => format!("LabelRemoved: {}", label),
+            IssueEvent::TitleChanged { new_title, .. } => format!("TitleChanged: {}", new_title),
+            IssueEvent::AssigneeChanged { new_assignee, .. } => match new_assignee {
+                Some(identity) => format!("AssigneeChanged: {}", identity.name),
+                None => "AssigneeChanged: unassigned".to_string(),
+            },
+            IssueEvent::AssigneesChanged { new_assignees, .. } => {
+                if new_assignees.is_empty() {
+                    "AssigneesChanged: unassigned all".to_string()
+                } else if new_assignees.len() == 1 {
+                    format!("AssigneesChanged: {}", new_assignees[0].name)
+                } else {
+                    format!("AssigneesChanged: {} assignees", new_assignees.len())
+                }
+            }
+            IssueEvent::DescriptionChanged { .. } => "DescriptionChanged".to_string(),
+            IssueEvent::PriorityChanged {
+                old_priority,
+                new_priority,
+                ..
+            } => {
+                format!("PriorityChanged: {} → {}", old_priority, new_priority)
+            }
+            IssueEvent::CreatedByChanged { new_created_by, .. } => {
+                format!("CreatedByChanged: {}", new_created_by.email)
+            }
+            IssueEvent::FieldSet { key, value, .. } => match value {
+                Some(value) => format!("FieldSet: {} = {}", key, value),
+                None => format!("FieldSet: {} cleared", key),
+            },
         }
     }
 }
@@ -13,6 +13,43 @@ impl Identity {
             email: email.to_string(),
         }
     }
+
+    /// Check whether this identity has the given email, ignoring ASCII case
+    pub fn has_email(&self, email: &str) -> bool {
+        self.email.eq_ignore_ascii_case(email)
+    }
+
+    /// Check whether two identities refer to the same person
+    ///
+    /// Identities are matched by email, case-insensitively; names are ignored.
+    pub fn same_person(&self, other: &Identity) -> bool {
+        self.has_email(&other.email)
+    }
+}
+
+/// Parse `Name <email>`, or a bare email with an empty name
+impl core::str::FromStr for Identity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, email) = match s.split_once('<') {
+            Some((name, rest)) => {
+                let email = rest
+                    .strip_suffix('>')
+                    .ok_or_else(|| format!("Invalid identity '{}': missing closing '>'", s))?;
+                (name.trim(), email.trim())
+            }
+            None => ("", s),
+        };
+        if !email.contains('@') {
+            return Err(format!(
+                "Invalid identity '{}': expected 'Name <email>' or an email",
+                s
+            ));
+        }
+        Ok(Identity::new(name, email))
+    }
 }
 
 impl core::fmt::Display for Identity {
@@ -20,3 +57,40 @@ impl core::fmt::Display for Identity {
         write!(f, "{} <{}>", self.name, self.email)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_person_ignores_email_case_and_name() {
+        let a = Identity::new("User", "User@Example.com");
+        let b = Identity::new("", "user@example.com");
+        assert!(a.same_person(&b));
+        assert!(!a.same_person(&Identity::new("User", "other@example.com")));
+    }
+
+    #[test]
+    fn test_parse_identity() {
+        assert_eq!(
+            "Alice Smith <alice@example.com>".parse::<Identity>(),
+            Ok(Identity::new("Alice Smith", "alice@example.com"))
+        );
+        assert_eq!(
+            " alice@example.com ".parse::<Identity>(),
+            Ok(Identity::new("", "alice@example.com"))
+        );
+        assert!("Alice <alice@example.com".parse::<Identity>().is_err());
+        assert!("Alice".parse::<Identity>().is_err());
+    }
+
+    #[test]
+    fn test_serialization_preserves_email_case() {
+        let identity = Identity::new("User", "User@Example.com");
+        let json = serde_json::to_string(&identity).unwrap();
+        assert!(json.contains("User@Example.com"));
+
+        let parsed: Identity = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.email, "User@Example.com");
+    }
+}
@@ -1,5 +1,6 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 use crate::common::{Comment, Identity, IssueEvent, Priority};
@@ -49,7 +50,23 @@ pub struct Issue {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub created_by: Identity,
+    /// The person an automated `created_by` opened the issue for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_on_behalf_of: Option<Identity>,
     pub assignees: Vec<Identity>,
+    /// When the issue last moved to Done; derived from events, cleared on reopen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Custom key/value metadata such as "severity" or "customer"
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+    /// How many times the issue moved from done back to an open status; derived from events
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub reopen_count: usize,
+}
+
+fn is_zero(count: &usize) -> bool {
+    *count == 0
 }
 
 impl Issue {
@@ -68,7 +85,11 @@ impl Issue {
             created_at,
             updated_at: created_at,
             created_by,
+            created_on_behalf_of: None,
             assignees: Vec::new(),
+            closed_at: None,
+            fields: BTreeMap::new(),
+            reopen_count: 0,
         }
     }
 
@@ -88,6 +109,7 @@ impl Issue {
         if self.status != new_status {
             self.status = new_status;
             self.updated_at = Utc::now();
+            self.closed_at = (new_status == IssueStatus::Done).then_some(self.updated_at);
         }
     }
 
@@ -147,6 +169,16 @@ impl Issue {
 }
 
 impl Issue {
+    /// How long the issue has existed as of `now`
+    pub fn age(&self, now: DateTime<Utc>) -> Duration {
+        now - self.created_at
+    }
+
+    /// How long the issue took to close, or `None` if it is not closed
+    pub fn time_to_close(&self) -> Option<Duration> {
+        self.closed_at.map(|closed_at| closed_at - self.created_at)
+    }
+
     pub fn from_events(issue_id: IssueId, events: &[IssueEvent]) -> anyhow::Result<Self> {
         if events.is_empty() {
             return Err(anyhow::anyhow!("Cannot create issue from empty event list"));
@@ -157,12 +189,14 @@ impl Issue {
                 title,
                 description,
                 author,
+                on_behalf_of,
                 timestamp,
             } => (
                 title.clone(),
                 description.clone(),
                 author.clone(),
                 *timestamp,
+                on_behalf_of.clone(),
             ),
             _ => return Err(anyhow::anyhow!("First event must be Created")),
         };
@@ -178,16 +212,43 @@ impl Issue {
             created_at: created_event.3,
             updated_at: created_event.3,
             created_by: created_event.2,
+            created_on_behalf_of: created_event.4,
             assignees: Vec::new(),
+            closed_at: None,
+            fields: BTreeMap::new(),
+            reopen_count: 0,
         };
 
-        for event in events.iter().skip(1) {
-            issue.apply_event(event)?;
-        }
-
+        issue.replay(&events[1..])?;
         Ok(issue)
     }
 
+    /// Apply events that follow the ones this issue was built from, oldest first
+    pub fn replay(&mut self, events: &[IssueEvent]) -> anyhow::Result<()> {
+        for event in events {
+            let previous_update = self.updated_at;
+            self.apply_event(event)?;
+
+            // Clock skew must not make `updated_at` go backwards; events keep their timestamps
+            if self.updated_at < previous_update {
+                log::warn!(
+                    "Event on issue #{} is dated {} but follows an event dated {}",
+                    self.id,
+                    self.updated_at,
+                    previous_update
+                );
+                self.updated_at = previous_update;
+            }
+            // Nor make an issue close before it was created
+            if let Some(closed_at) = self.closed_at
+                && closed_at < self.created_at
+            {
+                self.closed_at = Some(self.created_at);
+            }
+        }
+        Ok(())
+    }
+
     pub fn apply_event(&mut self, event: &IssueEvent) -> anyhow::Result<()> {
         match event {
             IssueEvent::Created { .. } => {
@@ -195,7 +256,20 @@ impl Issue {
                     "Cannot apply Created event to existing issue"
                 ));
             }
-            IssueEvent::StatusChanged { to, timestamp, .. } => {
+            IssueEvent::StatusChanged {
+                from,
+                to,
+                timestamp,
+                ..
+            } => {
+                if *from == IssueStatus::Done && *to != IssueStatus::Done {
+                    self.reopen_count += 1;
+                }
+                if *to != IssueStatus::Done {
+                    self.closed_at = None;
+                } else if self.status != IssueStatus::Done {
+                    self.closed_at = Some(*timestamp);
+                }
                 self.status = *to;
                 self.updated_at = *timestamp;
             }
@@ -203,12 +277,14 @@ impl Issue {
                 comment_id,
                 content,
                 author,
+                on_behalf_of,
                 timestamp,
             } => {
                 let comment = Comment {
                     id: comment_id.clone(),
                     content: content.clone(),
                     author: author.clone(),
+                    on_behalf_of: on_behalf_of.clone(),
                     created_at: *timestamp,
                 };
                 self.comments.push(comment);
@@ -280,6 +356,18 @@ impl Issue {
                 self.created_by = new_created_by.clone();
                 self.updated_at = *timestamp;
             }
+            IssueEvent::FieldSet {
+                key,
+                value,
+                timestamp,
+                ..
+            } => {
+                match value {
+                    Some(value) => self.fields.insert(key.clone(), value.clone()),
+                    None => self.fields.remove(key),
+                };
+                self.updated_at = *timestamp;
+            }
         }
         Ok(())
     }
@@ -428,6 +516,38 @@ mod tests {
         assert!(issue.labels.contains(&"bug".to_string()));
     }
 
+    #[test]
+    fn test_issue_counts_reopens() {
+        let author = test_identity();
+        let mut events = vec![IssueEvent::created(
+            "Test Issue".to_string(),
+            "Description".to_string(),
+            author.clone(),
+        )];
+        for (from, to) in [
+            (IssueStatus::Todo, IssueStatus::Done),
+            (IssueStatus::Done, IssueStatus::InProgress),
+            (IssueStatus::InProgress, IssueStatus::Done),
+            (IssueStatus::Done, IssueStatus::Todo),
+        ] {
+            events.push(IssueEvent::status_changed(from, to, author.clone()));
+        }
+
+        let issue = Issue::from_events(1, &events).unwrap();
+        assert_eq!(issue.reopen_count, 2);
+        assert_eq!(serde_json::to_value(&issue).unwrap()["reopen_count"], 2);
+
+        // Issues that were never reopened serialize without the count
+        let issue = Issue::from_events(1, &events[..2]).unwrap();
+        assert_eq!(issue.reopen_count, 0);
+        assert!(
+            serde_json::to_value(&issue)
+                .unwrap()
+                .get("reopen_count")
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_issue_from_empty_events() {
         let result = Issue::from_events(1, &[]);
@@ -463,4 +583,116 @@ mod tests {
 
         assert_eq!(issue, deserialized);
     }
+
+    #[test]
+    fn test_updated_at_is_monotonic_under_clock_skew() {
+        let start = Utc::now();
+        let skewed = start - chrono::Duration::hours(1);
+        let later = start + chrono::Duration::hours(1);
+        let events = vec![
+            IssueEvent::Created {
+                title: "Skewed".to_string(),
+                description: "".to_string(),
+                author: test_identity(),
+                on_behalf_of: None,
+                timestamp: start,
+            },
+            IssueEvent::LabelAdded {
+                label: "bug".to_string(),
+                author: test_identity(),
+                on_behalf_of: None,
+                timestamp: skewed,
+            },
+        ];
+
+        let issue = Issue::from_events(1, &events).unwrap();
+        assert_eq!(issue.updated_at, start);
+        assert_eq!(issue.labels, vec!["bug".to_string()]);
+        assert_eq!(events[1].timestamp(), &skewed);
+
+        let mut events = events;
+        events.push(IssueEvent::StatusChanged {
+            from: IssueStatus::Todo,
+            to: IssueStatus::Done,
+            author: test_identity(),
+            on_behalf_of: None,
+            timestamp: later,
+        });
+        let issue = Issue::from_events(1, &events).unwrap();
+        assert_eq!(issue.updated_at, later);
+
+        // A close dated before the creation counts as closing right away
+        events.push(IssueEvent::StatusChanged {
+            from: IssueStatus::Done,
+            to: IssueStatus::Todo,
+            author: test_identity(),
+            on_behalf_of: None,
+            timestamp: later,
+        });
+        events.push(IssueEvent::StatusChanged {
+            from: IssueStatus::Todo,
+            to: IssueStatus::Done,
+            author: test_identity(),
+            on_behalf_of: None,
+            timestamp: skewed,
+        });
+        let issue = Issue::from_events(1, &events).unwrap();
+        assert_eq!(issue.closed_at, Some(start));
+        assert_eq!(issue.time_to_close(), Some(chrono::Duration::zero()));
+    }
+
+    #[test]
+    fn test_age_and_time_to_close() {
+        let author = test_identity();
+        let created = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let status_change = |from, to, hours| IssueEvent::StatusChanged {
+            from,
+            to,
+            author: author.clone(),
+            on_behalf_of: None,
+            timestamp: created + Duration::hours(hours),
+        };
+        let mut events = vec![
+            IssueEvent::Created {
+                title: "Title".to_string(),
+                description: String::new(),
+                author: author.clone(),
+                on_behalf_of: None,
+                timestamp: created,
+            },
+            status_change(IssueStatus::Todo, IssueStatus::Done, 5),
+            status_change(IssueStatus::Done, IssueStatus::Todo, 10),
+        ];
+
+        let reopened = Issue::from_events(1, &events).unwrap();
+        assert_eq!(reopened.age(created + Duration::days(2)), Duration::days(2));
+        assert_eq!(reopened.time_to_close(), None);
+
+        events.push(status_change(IssueStatus::Todo, IssueStatus::Done, 30));
+        let closed = Issue::from_events(1, &events).unwrap();
+        assert_eq!(closed.time_to_close(), Some(Duration::hours(30)));
+    }
+
+    #[test]
+    fn test_issue_fields_from_events() {
+        let author = test_identity();
+        let field = |key: &str, value: Option<&str>| {
+            IssueEvent::field_set(key.to_string(), value.map(str::to_string), author.clone())
+        };
+        let events = vec![
+            IssueEvent::created("Title".to_string(), String::new(), author.clone()),
+            field("severity", Some("low")),
+            field("customer", Some("ACME")),
+            field("severity", Some("high")),
+            field("customer", None),
+        ];
+
+        let issue = Issue::from_events(1, &events).unwrap();
+        assert_eq!(
+            issue.fields,
+            BTreeMap::from([("severity".to_string(), "high".to_string())])
+        );
+    }
 }
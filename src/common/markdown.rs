@@ -0,0 +1,283 @@
+use crate::common::IssueId;
+
+/// Parse a markdown ATX heading line into its level and text
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') && !rest.starts_with('\t') {
+        return None;
+    }
+
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+/// Extract the content under `heading` from a markdown document
+///
+/// The section runs until the next heading of the same or a higher level.
+/// Headings are matched case-insensitively; fenced code blocks are skipped
+/// when looking for headings.
+pub fn extract_section(markdown: &str, heading: &str) -> Option<String> {
+    let wanted = heading.trim().trim_start_matches('#').trim();
+    let mut section_level = None;
+    let mut in_fence = false;
+    let mut lines = Vec::new();
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+
+        let heading = if in_fence { None } else { parse_heading(line) };
+
+        match (section_level, heading) {
+            (None, Some((level, text))) if text.eq_ignore_ascii_case(wanted) => {
+                section_level = Some(level);
+            }
+            (Some(current), Some((level, _))) if level <= current => break,
+            (Some(_), _) => lines.push(line),
+            (None, _) => {}
+        }
+    }
+
+    section_level.map(|_| lines.join("\n").trim().to_string())
+}
+
+/// Find `#N` issue references in text, in order of first appearance
+///
+/// A `#` only starts a reference when it is not preceded by an alphanumeric
+/// character, so `C#7` or `page#3` are ignored.
+pub fn extract_issue_references(text: &str) -> Vec<IssueId> {
+    let mut references = Vec::new();
+    let mut prev: Option<char> = None;
+
+    for (i, c) in text.char_indices() {
+        if c == '#' && !prev.is_some_and(|p| p.is_alphanumeric() || p == '#') {
+            let digits: String = text[i + 1..]
+                .chars()
+                .take_while(|d| d.is_ascii_digit())
+                .collect();
+            let followed_by_word = text[i + 1 + digits.len()..]
+                .chars()
+                .next()
+                .is_some_and(|n| n.is_alphanumeric() || n == '_');
+
+            if !digits.is_empty()
+                && !followed_by_word
+                && let Ok(id) = digits.parse::<IssueId>()
+                && !references.contains(&id)
+            {
+                references.push(id);
+            }
+        }
+        prev = Some(c);
+    }
+
+    references
+}
+
+/// Check a markdown document for common mistakes, returning one message per problem
+///
+/// Reports code fences left open and reference links (`[text][ref]` or
+/// `[ref][]`) whose reference has no `[ref]: url` definition. Reference
+/// labels are matched case-insensitively, and nothing inside code is checked.
+pub fn lint(markdown: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let mut fence: Option<(usize, &str)> = None;
+    let mut definitions = Vec::new();
+    let mut references = Vec::new();
+
+    for (index, line) in markdown.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker));
+
+        match (fence, marker) {
+            (None, Some(marker)) => fence = Some((index + 1, marker)),
+            (Some((_, open)), Some(marker)) if open == marker => fence = None,
+            (Some(_), _) => {}
+            (None, None) => {
+                if let Some(label) = parse_link_definition(trimmed) {
+                    definitions.push(label);
+                } else {
+                    references.extend(
+                        find_reference_links(line)
+                            .into_iter()
+                            .map(|label| (index + 1, label)),
+                    );
+                }
+            }
+        }
+    }
+
+    if let Some((line, marker)) = fence {
+        warnings.push(format!(
+            "line {}: code fence {} is never closed",
+            line, marker
+        ));
+    }
+
+    for (line, label) in references {
+        if !definitions.iter().any(|defined| defined == &label) {
+            warnings.push(format!(
+                "line {}: reference link [{}] has no definition",
+                line, label
+            ));
+        }
+    }
+
+    warnings
+}
+
+/// Normalize a reference label for comparison, like markdown does
+fn normalize_reference_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Parse a `[label]: destination` reference definition, returning the label
+fn parse_link_definition(line: &str) -> Option<String> {
+    let (label, rest) = line.strip_prefix('[')?.split_once("]:")?;
+    (!label.trim().is_empty() && !rest.trim().is_empty()).then(|| normalize_reference_label(label))
+}
+
+/// Find the labels of full (`[text][label]`) and collapsed (`[label][]`) reference links
+///
+/// Inline code spans are skipped.
+fn find_reference_links(line: &str) -> Vec<String> {
+    let mut labels = Vec::new();
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['[', '`']) {
+        rest = &rest[start..];
+        if let Some(code) = rest.strip_prefix('`') {
+            rest = code.split_once('`').map_or("", |(_, after)| after);
+            continue;
+        }
+
+        let Some((text, after)) = rest[1..].split_once(']') else {
+            break;
+        };
+        match after
+            .strip_prefix('[')
+            .and_then(|label| label.split_once(']'))
+        {
+            Some((label, after_label)) => {
+                let label = if label.is_empty() { text } else { label };
+                if !label.trim().is_empty() {
+                    labels.push(normalize_reference_label(label));
+                }
+                rest = after_label;
+            }
+            None => rest = after,
+        }
+    }
+
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = "\
+# Design
+
+Intro text.
+
+## Background
+
+Some background.
+
+## Proposal
+
+First paragraph.
+
+### Details
+
+```sh
+# not a heading
+git issue list
+```
+
+## Alternatives
+
+None considered.
+";
+
+    #[test]
+    fn test_extract_middle_section() {
+        let section = extract_section(DOC, "Proposal").expect("Section should exist");
+        assert_eq!(
+            section,
+            "First paragraph.\n\n### Details\n\n```sh\n# not a heading\ngit issue list\n```"
+        );
+    }
+
+    #[test]
+    fn test_extract_section_stops_at_higher_level() {
+        let section = extract_section(DOC, "## Alternatives").expect("Section should exist");
+        assert_eq!(section, "None considered.");
+    }
+
+    #[test]
+    fn test_extract_section_case_insensitive() {
+        assert_eq!(
+            extract_section(DOC, "background").as_deref(),
+            Some("Some background.")
+        );
+    }
+
+    #[test]
+    fn test_extract_missing_section() {
+        assert!(extract_section(DOC, "Nope").is_none());
+        assert!(extract_section(DOC, "not a heading").is_none());
+    }
+
+    #[test]
+    fn test_extract_issue_references() {
+        let text = "Blocked by #7 and #12; see also (#7), C#9, page#3, #x and #4b.";
+        assert_eq!(extract_issue_references(text), vec![7, 12]);
+        assert!(extract_issue_references("## Heading 1").is_empty());
+    }
+
+    #[test]
+    fn test_lint_accepts_valid_markdown() {
+        let text = "\
+See the [design doc][design] and [RFC][] for details.
+
+```rust
+let x = [a][b];
+```
+
+Use `[not][a-link]` inline.
+
+[design]: https://example.com/design
+[rfc]: https://example.com/rfc
+";
+        assert!(lint(text).is_empty(), "{:?}", lint(text));
+    }
+
+    #[test]
+    fn test_lint_reports_unbalanced_fence() {
+        let text = "Intro\n\n```sh\ngit issue list\n~~~\n";
+        assert_eq!(lint(text), vec!["line 3: code fence ``` is never closed"]);
+    }
+
+    #[test]
+    fn test_lint_reports_broken_reference_link() {
+        let text = "See [the docs][docs] and [Missing][].\n\n[docs]: https://example.com\n";
+        assert_eq!(
+            lint(text),
+            vec!["line 1: reference link [missing] has no definition"]
+        );
+    }
+}
@@ -3,7 +3,9 @@ pub mod env;
 pub mod event;
 pub mod identity;
 pub mod issue;
+pub mod markdown;
 pub mod priority;
+pub mod suggest;
 
 pub use comment::*;
 pub use env::{EnvProvider, SystemEnvProvider};
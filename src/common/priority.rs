@@ -1,11 +1,13 @@
+use super::suggest::did_you_mean;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
 /// Issue priority levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum Priority {
     /// No priority set (default)
+    #[default]
     None = 0,
     /// Urgent priority
     Urgent = 1,
@@ -48,11 +50,27 @@ impl Priority {
             Priority::Low,
         ]
     }
-}
 
-impl Default for Priority {
-    fn default() -> Self {
-        Priority::None
+    /// One level more important, staying at urgent
+    ///
+    /// Levels run none → low → medium → high → urgent.
+    pub fn next(self) -> Self {
+        match self {
+            Priority::None => Priority::Low,
+            Priority::Low => Priority::Medium,
+            Priority::Medium => Priority::High,
+            Priority::High | Priority::Urgent => Priority::Urgent,
+        }
+    }
+
+    /// One level less important, staying at none
+    pub fn prev(self) -> Self {
+        match self {
+            Priority::Urgent => Priority::High,
+            Priority::High => Priority::Medium,
+            Priority::Medium => Priority::Low,
+            Priority::Low | Priority::None => Priority::None,
+        }
     }
 }
 
@@ -79,8 +97,9 @@ impl FromStr for Priority {
             "medium" | "3" => Ok(Priority::Medium),
             "low" | "4" => Ok(Priority::Low),
             _ => Err(format!(
-                "Invalid priority '{}'. Valid options: none, urgent, high, medium, low (or 0-4)",
-                s
+                "Invalid priority '{}'{}. Valid options: none, urgent, high, medium, low (or 0-4)",
+                s,
+                did_you_mean(s, &["none", "urgent", "high", "medium", "low"])
             )),
         }
     }
@@ -157,4 +176,32 @@ mod tests {
         assert!(all.contains(&Priority::Medium));
         assert!(all.contains(&Priority::Low));
     }
+
+    #[test]
+    fn test_priority_next_and_prev() {
+        let ladder = [
+            Priority::None,
+            Priority::Low,
+            Priority::Medium,
+            Priority::High,
+            Priority::Urgent,
+        ];
+        for pair in ladder.windows(2) {
+            assert_eq!(pair[0].next(), pair[1]);
+            assert_eq!(pair[1].prev(), pair[0]);
+        }
+
+        // Clamped at both ends
+        assert_eq!(Priority::Urgent.next(), Priority::Urgent);
+        assert_eq!(Priority::None.prev(), Priority::None);
+    }
+
+    #[test]
+    fn test_priority_from_str_suggests_close_match() {
+        let err = "urgnet".parse::<Priority>().unwrap_err();
+        assert!(err.contains("did you mean 'urgent'?"), "{}", err);
+
+        let err = "qwertyuiop".parse::<Priority>().unwrap_err();
+        assert!(!err.contains("did you mean"), "{}", err);
+    }
 }
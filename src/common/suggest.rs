@@ -0,0 +1,64 @@
+//! Nearest-match suggestions for mistyped command-line values
+
+/// Compute the Levenshtein edit distance between two strings
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Find the candidate closest to `input`, if any is close enough to be a likely typo
+///
+/// A candidate qualifies when its edit distance is at most a third of the
+/// longer string's length (but always allowing a single edit).
+pub fn suggest<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let input = input.to_lowercase();
+    candidates
+        .iter()
+        .map(|candidate| (levenshtein(&input, candidate), *candidate))
+        .filter(|(distance, candidate)| {
+            let longest = input.chars().count().max(candidate.chars().count());
+            *distance <= (longest / 3).max(1)
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Render a " (did you mean 'x'?)" hint for an error message, or nothing
+pub fn did_you_mean(input: &str, candidates: &[&str]) -> String {
+    suggest(input, candidates)
+        .map(|candidate| format!(" (did you mean '{}'?)", candidate))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("inprogres", "in-progress"), 2);
+    }
+
+    #[test]
+    fn test_suggest() {
+        let candidates = ["todo", "in-progress", "done"];
+        assert_eq!(suggest("inprogres", &candidates), Some("in-progress"));
+        assert_eq!(suggest("DNE", &candidates), Some("done"));
+        assert_eq!(suggest("xyzzy", &candidates), None);
+    }
+}
@@ -0,0 +1,280 @@
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use super::repo::GitRepository;
+use crate::common::{EVENT_SCHEMA_VERSION, EnvProvider, Issue, IssueId};
+
+/// Environment variable naming a directory to cache reconstructed issues in
+pub const CACHE_DIR_VAR: &str = "GIT_ISSUE_CACHE_DIR";
+
+/// Namespace of the in-repo cache refs, one per issue
+pub const CACHE_REF_PREFIX: &str = "refs/git-issue/cache";
+
+/// A store of reconstructed issues, keyed by the head commit they were built from
+///
+/// Entries never go stale: a new event moves the issue's head, so the old entry is
+/// simply never looked up again. Cache failures are logged and otherwise ignored,
+/// since the issue can always be rebuilt from its events.
+pub trait IssueCache {
+    /// The issue as reconstructed from `head`, if it is cached
+    fn load(&self, repo: &GitRepository, issue_id: IssueId, head: gix::ObjectId) -> Option<Issue>;
+
+    /// Remember `issue` as reconstructed from `head`
+    fn save(&self, repo: &GitRepository, issue_id: IssueId, head: gix::ObjectId, issue: &Issue);
+}
+
+/// Pick the cache backend for `repo`
+///
+/// `GIT_ISSUE_CACHE_DIR` selects a directory outside the repository; otherwise
+/// `storage.issueCache = refs` keeps the cache under `refs/git-issue/cache`.
+/// Without either, issues are always rebuilt from their events.
+pub fn configured_cache(
+    repo: &GitRepository,
+    env_provider: &impl EnvProvider,
+) -> Option<Box<dyn IssueCache>> {
+    if let Some(dir) = env_provider
+        .get_var(CACHE_DIR_VAR)
+        .filter(|dir| !dir.is_empty())
+    {
+        return Some(Box::new(DirCache::new(dir)));
+    }
+
+    match repo.get_config("storage.issueCache").as_deref() {
+        Some("refs") => Some(Box::new(RefCache)),
+        _ => None,
+    }
+}
+
+/// Version of the code that reconstructed a cached issue
+///
+/// It is part of every cache key, so an upgrade that changes how events replay
+/// never reads issues cached by an older version.
+fn cache_version() -> String {
+    format!(
+        "schema{}-{}",
+        EVENT_SCHEMA_VERSION,
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// A cached issue together with the head and version it was reconstructed by
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    version: String,
+    head: String,
+    issue: Issue,
+}
+
+impl CacheEntry {
+    fn new(head: gix::ObjectId, issue: &Issue) -> Self {
+        Self {
+            version: cache_version(),
+            head: head.to_string(),
+            issue: issue.clone(),
+        }
+    }
+
+    /// The cached issue, if it was reconstructed from `head` by this version
+    fn issue_at(self, head: gix::ObjectId) -> Option<Issue> {
+        (self.version == cache_version() && self.head == head.to_string()).then_some(self.issue)
+    }
+}
+
+/// Cache kept in the repository, as a blob per issue under `refs/git-issue/cache`
+pub struct RefCache;
+
+impl RefCache {
+    fn ref_name(issue_id: IssueId) -> String {
+        format!("{}/{}", CACHE_REF_PREFIX, issue_id)
+    }
+}
+
+impl IssueCache for RefCache {
+    fn load(&self, repo: &GitRepository, issue_id: IssueId, head: gix::ObjectId) -> Option<Issue> {
+        let oid = repo.read_ref(&Self::ref_name(issue_id)).ok()??;
+        let entry: CacheEntry = serde_json::from_slice(&repo.read_blob(oid).ok()?).ok()?;
+        entry.issue_at(head)
+    }
+
+    fn save(&self, repo: &GitRepository, issue_id: IssueId, head: gix::ObjectId, issue: &Issue) {
+        let result = serde_json::to_vec(&CacheEntry::new(head, issue))
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                repo.write_cache_ref(&Self::ref_name(issue_id), &content)
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::debug!("Failed to cache issue #{}: {}", issue_id, e);
+        }
+    }
+}
+
+/// Cache kept in a directory outside the repository, shared by every repository
+///
+/// Entries live at `<dir>/<repository key>/<version>/<issue id>-<head>.json`, where
+/// the repository key is a hash of the repository path. Superseded entries are not
+/// removed; the directory can be deleted at any time.
+pub struct DirCache {
+    dir: PathBuf,
+}
+
+impl DirCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn entry_path(&self, repo: &GitRepository, issue_id: IssueId, head: gix::ObjectId) -> PathBuf {
+        self.dir
+            .join(repository_key(repo.path()))
+            .join(cache_version())
+            .join(format!("{}-{}.json", issue_id, head))
+    }
+}
+
+impl IssueCache for DirCache {
+    fn load(&self, repo: &GitRepository, issue_id: IssueId, head: gix::ObjectId) -> Option<Issue> {
+        let content = std::fs::read(self.entry_path(repo, issue_id, head)).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&content).ok()?;
+        entry.issue_at(head)
+    }
+
+    fn save(&self, repo: &GitRepository, issue_id: IssueId, head: gix::ObjectId, issue: &Issue) {
+        let path = self.entry_path(repo, issue_id, head);
+        let result = serde_json::to_vec(&CacheEntry::new(head, issue))
+            .map_err(std::io::Error::other)
+            .and_then(|content| {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&path, content)
+            });
+        if let Err(e) = result {
+            log::debug!(
+                "Failed to cache issue #{} in {}: {}",
+                issue_id,
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Directory name identifying a repository within an external cache
+fn repository_key(path: &Path) -> String {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    path.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::IssueStatus;
+    use crate::storage::IssueStore;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_warm_dir_cache_avoids_traversal() {
+        let (temp_dir, _repo) = setup_temp_repo();
+        let cache_dir = TempDir::new().unwrap();
+        let author = create_test_identity();
+
+        let mut store = IssueStore::open(temp_dir.path()).unwrap();
+        let (cache, counts) = CountingCache::new(Some(Box::new(DirCache::new(cache_dir.path()))));
+        store.set_cache(Some(cache));
+        let issue_id = store
+            .create_issue("Cached".to_string(), String::new(), author.clone())
+            .unwrap();
+        store
+            .update_issue_status(issue_id, IssueStatus::InProgress, author)
+            .unwrap();
+
+        // The first read walks the event chain and fills the cache
+        let hits_before = counts.hits.get();
+        let issue = store.get_issue(issue_id).unwrap();
+        assert_eq!(counts.hits.get(), hits_before);
+
+        // A fresh store sharing the cache directory takes the issue from the cache
+        let mut store = IssueStore::open(temp_dir.path()).unwrap();
+        let (cache, counts) = CountingCache::new(Some(Box::new(DirCache::new(cache_dir.path()))));
+        store.set_cache(Some(cache));
+        assert_eq!(store.get_issue(issue_id).unwrap(), issue);
+        assert_eq!(counts.hits.get(), 1);
+
+        // Nothing was written into the repository
+        assert!(
+            store
+                .read_ref(&RefCache::ref_name(issue_id))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_ref_cache_misses_after_new_event() {
+        let (temp_dir, _repo) = setup_temp_repo();
+        let author = create_test_identity();
+
+        let mut store = IssueStore::open(temp_dir.path()).unwrap();
+        let (cache, counts) = CountingCache::new(Some(Box::new(RefCache)));
+        store.set_cache(Some(cache));
+        let issue_id = store
+            .create_issue("Cached".to_string(), String::new(), author.clone())
+            .unwrap();
+        store.get_issue(issue_id).unwrap();
+        assert!(
+            store
+                .read_ref(&RefCache::ref_name(issue_id))
+                .unwrap()
+                .is_some()
+        );
+
+        let hits_before = counts.hits.get();
+        store.get_issue(issue_id).unwrap();
+        assert_eq!(counts.hits.get(), hits_before + 1);
+
+        store
+            .update_issue_status(issue_id, IssueStatus::Done, author)
+            .unwrap();
+        assert_eq!(store.get_issue(issue_id).unwrap().status, IssueStatus::Done);
+    }
+
+    #[test]
+    fn test_entries_from_another_version_are_ignored() {
+        let (_temp_dir, mut repo) = setup_temp_repo();
+        let head = repo.write_blob(b"stand-in head").unwrap();
+        let issue = Issue::new(
+            1,
+            "Cached".to_string(),
+            String::new(),
+            create_test_identity(),
+        );
+
+        RefCache.save(&repo, 1, head, &issue);
+        assert_eq!(RefCache.load(&repo, 1, head), Some(issue.clone()));
+
+        let mut entry = CacheEntry::new(head, &issue);
+        entry.version = "schema0-0.0.0".to_string();
+        let content = serde_json::to_vec(&entry).unwrap();
+        repo.write_cache_ref(&RefCache::ref_name(1), &content)
+            .unwrap();
+        assert_eq!(RefCache.load(&repo, 1, head), None);
+    }
+
+    #[test]
+    fn test_cache_dir_comes_from_the_environment() {
+        use crate::common::MockEnvProvider;
+
+        let (_temp_dir, repo) = setup_temp_repo();
+        assert!(configured_cache(&repo, &MockEnvProvider::new()).is_none());
+
+        let mut env = MockEnvProvider::new();
+        env.set_var(CACHE_DIR_VAR, "");
+        assert!(configured_cache(&repo, &env).is_none());
+        env.set_var(CACHE_DIR_VAR, "/tmp/git-issue-cache");
+        assert!(configured_cache(&repo, &env).is_some());
+    }
+}
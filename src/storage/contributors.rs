@@ -0,0 +1,112 @@
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use super::errors::{StorageError, StorageResult};
+
+/// Location of the contributors allowlist, relative to the work tree
+pub const CONTRIBUTORS_PATH: &str = ".git-issue/contributors.toml";
+
+/// Emails that issues may be assigned to, from `.git-issue/contributors.toml`
+///
+/// The file holds a single array of quoted addresses:
+///
+/// ```toml
+/// emails = [
+///     "ada@example.com",
+///     "grace@example.com",  # on leave
+/// ]
+/// ```
+///
+/// Emails are compared case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Contributors {
+    emails: BTreeSet<String>,
+}
+
+/// The allowlist file as written
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ContributorsFile {
+    emails: Vec<String>,
+}
+
+impl Contributors {
+    /// Load the allowlist of the work tree at `workdir`, or `None` if there is none
+    ///
+    /// Only a missing file means there is no allowlist; any other read error is reported.
+    pub fn load(workdir: &Path) -> StorageResult<Option<Self>> {
+        let path = workdir.join(CONTRIBUTORS_PATH);
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(StorageError::invalid_project_file(&path, err)),
+        };
+
+        Self::parse(&text)
+            .map(Some)
+            .map_err(|err| StorageError::invalid_project_file(&path, err))
+    }
+
+    /// Parse the allowlist from TOML text
+    pub fn parse(text: &str) -> Result<Self, toml::de::Error> {
+        let file: ContributorsFile = toml::from_str(text)?;
+        Ok(Self {
+            emails: file
+                .emails
+                .into_iter()
+                .map(|email| email.to_lowercase())
+                .collect(),
+        })
+    }
+
+    /// Whether `email` is a known contributor
+    pub fn contains(&self, email: &str) -> bool {
+        self.emails.contains(&email.to_lowercase())
+    }
+
+    /// Every known email, lowercased
+    pub fn emails(&self) -> impl Iterator<Item = &str> {
+        self.emails.iter().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_contributors() {
+        let contributors = Contributors::parse(
+            r#"
+# Who may be assigned issues
+emails = [
+    "Ada@Example.com",
+    'grace@example.com',  # on leave
+]
+"#,
+        )
+        .unwrap();
+
+        assert!(contributors.contains("ada@example.com"));
+        assert!(contributors.contains("GRACE@example.com"));
+        assert!(!contributors.contains("adda@example.com"));
+        assert_eq!(contributors.emails().count(), 2);
+
+        assert!(Contributors::parse("").is_err());
+        assert!(Contributors::parse("names = []").is_err());
+        assert!(Contributors::parse("emails = \"ada@example.com\"").is_err());
+        assert!(Contributors::parse("emails = [ada@example.com]").is_err());
+    }
+
+    #[test]
+    fn test_load_reports_unreadable_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(Contributors::load(temp_dir.path()).unwrap(), None);
+
+        // A directory in place of the file is an error, not a missing allowlist
+        std::fs::create_dir_all(temp_dir.path().join(CONTRIBUTORS_PATH)).unwrap();
+        let err = Contributors::load(temp_dir.path()).unwrap_err();
+        assert!(err.to_string().contains(CONTRIBUTORS_PATH), "{}", err);
+    }
+}
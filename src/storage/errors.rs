@@ -8,6 +8,12 @@ pub enum StorageError {
     #[error("Issue not found: {issue_id}")]
     IssueNotFound { issue_id: u64 },
 
+    #[error("Issue already exists: {issue_id}")]
+    IssueAlreadyExists { issue_id: u64 },
+
+    #[error("No issue matches '{reference}'")]
+    UnknownIssueRef { reference: String },
+
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
@@ -17,6 +23,14 @@ pub enum StorageError {
     #[error("Invalid issue ID format: expected u64, got '{value}'")]
     InvalidIssueId { value: String },
 
+    #[error(
+        "Event is {size} bytes, over the {limit}-byte limit (raise storage.maxEventSize to allow it)"
+    )]
+    EventTooLarge { size: usize, limit: usize },
+
+    #[error("Invalid {path}: {message}")]
+    InvalidProjectFile { path: String, message: String },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -77,17 +91,19 @@ pub enum GitError {
     #[error("Invalid reference name: {ref_name}")]
     InvalidReferenceName { ref_name: String },
 
-    #[allow(unused)]
     #[error("Concurrent reference update: {ref_name}")]
     ConcurrentReferenceUpdate { ref_name: String },
 
-    #[allow(unused)]
     #[error("Repository locked: {message}")]
     RepositoryLocked { message: String },
 
-    #[allow(unused)]
     #[error("Git operation failed: {operation} - {message}")]
     OperationFailed { operation: String, message: String },
+
+    #[error(
+        "authentication failed for {remote}; check your credential helper or SSH keys ({message})"
+    )]
+    AuthenticationFailed { remote: String, message: String },
 }
 
 impl From<gix::open::Error> for GitError {
@@ -129,6 +145,16 @@ impl StorageError {
         StorageError::IssueNotFound { issue_id }
     }
 
+    pub fn issue_already_exists(issue_id: u64) -> Self {
+        StorageError::IssueAlreadyExists { issue_id }
+    }
+
+    pub fn unknown_issue_ref(reference: impl AsRef<str>) -> Self {
+        StorageError::UnknownIssueRef {
+            reference: reference.as_ref().to_string(),
+        }
+    }
+
     pub fn invalid_event_sequence(message: impl AsRef<str>) -> Self {
         StorageError::InvalidEventSequence {
             message: message.as_ref().to_string(),
@@ -140,6 +166,13 @@ impl StorageError {
             value: value.as_ref().to_string(),
         }
     }
+
+    pub fn invalid_project_file(path: &std::path::Path, message: impl ToString) -> Self {
+        StorageError::InvalidProjectFile {
+            path: path.display().to_string(),
+            message: message.to_string(),
+        }
+    }
 }
 
 // Result type alias for convenience
@@ -0,0 +1,129 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::common::{Issue, IssueEvent, IssueId, IssueStatus, Priority};
+
+/// Local-only ref holding the issue index blob
+pub const INDEX_REF: &str = "refs/git-issue/meta/index";
+
+/// Summary of an issue as of a given head commit
+///
+/// Entries are updated as events are appended, and rebuilt from the event
+/// chain whenever `head_oid` no longer matches the issue ref.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub head_oid: String,
+    /// Human-friendly name derived from the title the issue was created with
+    #[serde(default)]
+    pub slug: String,
+    pub status: IssueStatus,
+    pub priority: Priority,
+    pub updated_at: DateTime<Utc>,
+    pub labels: Vec<String>,
+}
+
+/// Index of all issues, keyed by issue ID
+pub type IssueIndex = BTreeMap<IssueId, IndexEntry>;
+
+impl IndexEntry {
+    /// Summarize a fully reconstructed issue
+    pub fn from_issue(issue: &Issue, slug: String, head_oid: gix::ObjectId) -> Self {
+        Self {
+            head_oid: head_oid.to_string(),
+            slug,
+            status: issue.status,
+            priority: issue.priority,
+            updated_at: issue.updated_at,
+            labels: issue.labels.clone(),
+        }
+    }
+
+    /// Summarize a new issue from its `Created` event
+    pub fn from_created(event: &IssueEvent, head_oid: gix::ObjectId) -> Option<Self> {
+        match event {
+            IssueEvent::Created {
+                title, timestamp, ..
+            } => Some(Self {
+                head_oid: head_oid.to_string(),
+                slug: slugify(title),
+                status: IssueStatus::Todo,
+                priority: Priority::default(),
+                updated_at: *timestamp,
+                labels: Vec::new(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Apply an event appended on top of this entry's head, moving the head to `head_oid`
+    pub fn apply(&mut self, event: &IssueEvent, head_oid: gix::ObjectId) {
+        match event {
+            IssueEvent::StatusChanged { to, .. } => self.status = *to,
+            IssueEvent::PriorityChanged { new_priority, .. } => self.priority = *new_priority,
+            IssueEvent::LabelAdded { label, .. } if !self.labels.contains(label) => {
+                self.labels.push(label.clone());
+            }
+            IssueEvent::LabelRemoved { label, .. } => self.labels.retain(|l| l != label),
+            _ => {}
+        }
+        // Never move backwards, matching `Issue::from_events` under clock skew
+        self.updated_at = self.updated_at.max(*event.timestamp());
+        self.head_oid = head_oid.to_string();
+    }
+}
+
+/// Derive a slug from a title: lowercase alphanumerics separated by single hyphens
+///
+/// Letters outside ASCII are kept, so `Über café` becomes `über-café`. Slugs never
+/// parse as a number, so they cannot be mistaken for an issue ID.
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+
+    if slug.is_empty() {
+        "issue".to_string()
+    } else if slug.parse::<IssueId>().is_ok() {
+        format!("issue-{}", slug)
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Make `slug` unique among the other issues in `index` by appending `-2`, `-3`, ...
+pub fn unique_slug(index: &IssueIndex, slug: &str, issue_id: IssueId) -> String {
+    let taken = |candidate: &str| {
+        index
+            .iter()
+            .any(|(id, entry)| *id != issue_id && entry.slug == candidate)
+    };
+
+    let mut candidate = slug.to_string();
+    let mut suffix = 2;
+    while taken(&candidate) {
+        candidate = format!("{}-{}", slug, suffix);
+        suffix += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fix login bug"), "fix-login-bug");
+        assert_eq!(slugify("  Crash: on *startup*!  "), "crash-on-startup");
+        assert_eq!(slugify("Über café"), "über-café");
+        assert_eq!(slugify("2024"), "issue-2024");
+        assert_eq!(slugify("!!!"), "issue");
+    }
+}
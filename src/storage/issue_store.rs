@@ -1,8 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
-use super::errors::{StorageError, StorageResult};
-use super::repo::{GitRepository, TreeEntry};
-use crate::common::{Identity, Issue, IssueEvent, IssueId, IssueStatus, Priority};
+use super::cache::{IssueCache, configured_cache};
+use super::contributors::Contributors;
+use super::errors::{GitError, StorageError, StorageResult};
+use super::index::{INDEX_REF, IndexEntry, IssueIndex, slugify, unique_slug};
+use super::project_config::ProjectConfig;
+use super::repo::{
+    CommitData, GitRepository, LEGACY_REFS_NAMESPACE, ObjectCounts, REFS_NAMESPACE, TreeEntry,
+    WriteLock,
+};
+use super::search::{SEARCH_INDEX_REF, SearchIndex, SearchQuery};
+use super::snapshot::{SNAPSHOT_REF_PREFIX, Snapshot, configured_interval};
+use crate::common::{
+    EVENT_SCHEMA_VERSION, Identity, Issue, IssueEvent, IssueId, IssueStatus, Priority,
+    SystemEnvProvider,
+};
+
+/// Local-only ref recording refs pushed by an unfinished sync
+pub const SYNC_JOURNAL_REF: &str = "refs/git-issue/meta/sync-journal";
+
+/// Local-only ref recording when the last native export was written
+pub const EXPORT_MARKER_REF: &str = "refs/git-issue/meta/last-export";
+
+/// Largest serialized event accepted unless `storage.maxEventSize` says otherwise
+const DEFAULT_MAX_EVENT_SIZE: usize = 1024 * 1024;
+
+/// Refspec fetching every issue ref as-is
+const ISSUES_REFSPEC: &str = "refs/git-issue/issues/*:refs/git-issue/issues/*";
+
+/// A ref successfully pushed during a sync that has not completed yet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncJournalEntry {
+    pub remote: String,
+    pub ref_name: String,
+    pub oid: String,
+}
+
+/// Outcome of reconciling the issue counter with a remote's
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CounterReconciliation {
+    /// Nothing to push; a higher remote counter has been adopted locally
+    UpToDate,
+    /// The local counter is higher and replaces the remote one, which was at
+    /// `remote_oid` (or absent) when compared
+    Ahead { remote_oid: Option<String> },
+}
+
+/// An issue's full event history, as written by `export --format native`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueDump {
+    pub id: IssueId,
+    pub events: Vec<IssueEvent>,
+}
 
 /// High-level issue CRUD operations using git-issue's event-sourced storage
 ///
@@ -25,7 +77,7 @@ use crate::common::{Identity, Issue, IssueEvent, IssueId, IssueStatus, Priority}
 /// use std::path::Path;
 ///
 /// let mut store = IssueStore::open(Path::new("."))?;
-/// let author = Identity::new("Alice".to_string(), "alice@example.com".to_string());
+/// let author = Identity::new("Alice", "alice@example.com");
 ///
 /// // Create a new issue
 /// let issue_id = store.create_issue(
@@ -44,25 +96,71 @@ use crate::common::{Identity, Issue, IssueEvent, IssueId, IssueStatus, Priority}
 /// ```
 pub struct IssueStore {
     repo: GitRepository,
+    project: ProjectConfig,
+    cache: Option<Box<dyn IssueCache>>,
+    strict: bool,
+    on_behalf_of: Option<Identity>,
 }
 
 impl IssueStore {
     /// Open an existing git repository for issue storage
     pub fn open<P: AsRef<Path>>(path: P) -> StorageResult<Self> {
         let repo = GitRepository::open(path)?;
-        Ok(Self { repo })
+        Ok(Self::with_repo(repo))
     }
 
     /// Initialize a new git repository for issue storage
     pub fn init<P: AsRef<Path>>(path: P) -> StorageResult<Self> {
         let repo = GitRepository::init(path)?;
-        Ok(Self { repo })
+        Ok(Self::with_repo(repo))
+    }
+
+    fn with_repo(repo: GitRepository) -> Self {
+        let project = repo.workdir().map(ProjectConfig::load).unwrap_or_default();
+        let cache = configured_cache(&repo, &SystemEnvProvider);
+        Self {
+            repo,
+            project,
+            cache,
+            strict: false,
+            on_behalf_of: None,
+        }
+    }
+
+    /// Fetch the issues of a remote repository into a fresh repository at `path`
+    ///
+    /// Only issue refs are fetched, so the result is meant for reading; the
+    /// caller owns `path` and is responsible for cleaning it up.
+    pub fn open_remote<P: AsRef<Path>>(url: &str, path: P) -> StorageResult<Self> {
+        let store = Self::init(path)?;
+        store.repo.fetch_from(url, ISSUES_REFSPEC)?;
+        Ok(store)
+    }
+
+    /// Fail on events this version does not understand instead of skipping them
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Record every event written from now on as made on behalf of `identity`
+    ///
+    /// The event and commit author stay the identity passed to each write, such
+    /// as a bot account.
+    pub fn set_on_behalf_of(&mut self, identity: Option<Identity>) {
+        self.on_behalf_of = identity;
+    }
+
+    /// Use `cache` for reconstructed issues instead of the configured backend
+    #[cfg(test)]
+    pub fn set_cache(&mut self, cache: Option<Box<dyn IssueCache>>) {
+        self.cache = cache;
     }
 
     /// Create a new issue and return its ID
     ///
     /// This generates a new sequential issue ID, creates an initial "Created" event,
-    /// and stores it as the first commit in the issue's event chain.
+    /// and stores it as the first commit in the issue's event chain. The title is
+    /// stored trimmed.
     pub fn create_issue(
         &mut self,
         title: String,
@@ -73,7 +171,8 @@ impl IssueStore {
         let issue_id = self.repo.increment_issue_id()?;
 
         // Create the initial "Created" event
-        let created_event = IssueEvent::created(title.clone(), description.clone(), author.clone());
+        let created_event =
+            IssueEvent::created(normalize_title(&title), description.clone(), author.clone());
 
         // Store the event as the first commit in the issue chain
         self.append_event(issue_id, created_event, None)?;
@@ -81,23 +180,95 @@ impl IssueStore {
         Ok(issue_id)
     }
 
+    /// Create an issue at a specific ID, bypassing the counter
+    ///
+    /// Fails if the ID is already taken unless `force` is set, in which case the
+    /// existing event chain is replaced. The counter is advanced past `issue_id`
+    /// so later issues never collide with it.
+    pub fn create_issue_with_id(
+        &mut self,
+        issue_id: IssueId,
+        title: String,
+        description: String,
+        author: Identity,
+        force: bool,
+    ) -> StorageResult<()> {
+        if issue_id == 0 {
+            return Err(StorageError::invalid_issue_id("0"));
+        }
+
+        let ref_name = self.repo.issue_ref_name(issue_id);
+        let replaced = self.repo.read_ref(&ref_name)?;
+        if replaced.is_some() && !force {
+            return Err(StorageError::issue_already_exists(issue_id));
+        }
+
+        // An overwrite moves the ref to the new history in one update
+        let created_event = IssueEvent::created(normalize_title(&title), description, author);
+        self.write_event(issue_id, created_event, None, replaced)?;
+
+        self.repo.advance_issue_id_past(issue_id)?;
+
+        Ok(())
+    }
+
     /// Retrieve an issue by ID
     ///
-    /// Reconstructs the current issue state by replaying all events in its commit chain.
+    /// Reconstructs the current issue state by replaying the events in its commit chain,
+    /// starting from the issue's latest snapshot when there is one.
     /// Returns `StorageError::IssueNotFound` if the issue doesn't exist.
     pub fn get_issue(&self, issue_id: IssueId) -> StorageResult<Issue> {
-        let events = self.get_issue_events(issue_id)?;
+        self.get_issue_with_head(issue_id)
+            .map(|(issue, _head)| issue)
+    }
 
-        if events.is_empty() {
-            return Err(StorageError::issue_not_found(issue_id));
+    /// Retrieve an issue together with the head commit it was reconstructed from
+    ///
+    /// Mutations use the head as the parent of the next event, so the ref is read
+    /// only once and the new event is guaranteed to follow the state it was based on.
+    pub fn get_issue_with_head(&self, issue_id: IssueId) -> StorageResult<(Issue, gix::ObjectId)> {
+        let head = self.get_issue_head_commit(issue_id)?;
+
+        // A cached issue may have been built skipping unknown events, which strict mode rejects
+        let cache = self.cache.as_deref().filter(|_| !self.strict);
+        if let Some(issue) = cache.and_then(|cache| cache.load(&self.repo, issue_id, head)) {
+            return Ok((issue, head));
         }
 
-        Issue::from_events(issue_id, &events)
-            .map_err(|e| StorageError::invalid_event_sequence(e.to_string()))
+        // Snapshots are skipped in strict mode for the same reason as the cache
+        let interval = configured_interval(&self.repo).filter(|_| !self.strict);
+        let (events, snapshot) = match interval.and_then(|_| Snapshot::load(&self.repo, issue_id)) {
+            Some((snapshot_head, issue)) => {
+                // If the snapshot's head is not in the history, the walk went back to the start
+                let (events, found) = self.collect_events(issue_id, head, Some(snapshot_head))?;
+                (events, found.then_some(issue))
+            }
+            None => (self.collect_events(issue_id, head, None)?.0, None),
+        };
+        let events: Vec<IssueEvent> = events.into_iter().map(|(_oid, event)| event).collect();
+
+        let issue = match snapshot {
+            Some(mut issue) => {
+                issue
+                    .replay(&events)
+                    .map_err(|e| StorageError::invalid_event_sequence(e.to_string()))?;
+                issue
+            }
+            None if events.is_empty() => return Err(StorageError::issue_not_found(issue_id)),
+            None => Issue::from_events(issue_id, &events)
+                .map_err(|e| StorageError::invalid_event_sequence(e.to_string()))?,
+        };
+
+        if interval.is_some_and(|interval| events.len() >= interval) {
+            Snapshot::save(&self.repo, issue_id, head, &issue);
+        }
+        if let Some(cache) = cache {
+            cache.save(&self.repo, issue_id, head, &issue);
+        }
+        Ok((issue, head))
     }
 
     /// Check if an issue exists
-    #[allow(unused)]
     pub fn issue_exists(&self, issue_id: IssueId) -> StorageResult<bool> {
         let ref_name = self.repo.issue_ref_name(issue_id);
         let ref_exists = self.repo.read_ref(&ref_name)?.is_some();
@@ -114,7 +285,7 @@ impl IssueStore {
         author: Identity,
     ) -> StorageResult<()> {
         // Verify the issue exists and get current status
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
 
         if current_issue.status == new_status {
             // Status unchanged, no-op
@@ -124,9 +295,6 @@ impl IssueStore {
         // Create status change event
         let status_event = IssueEvent::status_changed(current_issue.status, new_status, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
         self.append_event(issue_id, status_event, Some(parent_commit))?;
 
@@ -136,9 +304,6 @@ impl IssueStore {
     /// Add a comment to an issue
     ///
     /// Creates a new "CommentAdded" event with a sequential comment ID.
-    ///
-    /// FIXME(sshine): Resolve issue #3 to remove this #[allow(unused)].
-    #[allow(unused)]
     pub fn add_comment(
         &mut self,
         issue_id: IssueId,
@@ -146,15 +311,12 @@ impl IssueStore {
         author: Identity,
     ) -> StorageResult<String> {
         // Verify the issue exists and get current comment count
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
         let comment_id = format!("{}-{}", issue_id, current_issue.comments.len() + 1);
 
         // Create comment event
         let comment_event = IssueEvent::comment_added(comment_id.clone(), content, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
         self.append_event(issue_id, comment_event, Some(parent_commit))?;
 
@@ -169,7 +331,7 @@ impl IssueStore {
         author: Identity,
     ) -> StorageResult<()> {
         // Verify the issue exists and check if label already exists
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
 
         if current_issue.labels.contains(&label) {
             // Label already exists, no-op
@@ -179,9 +341,6 @@ impl IssueStore {
         // Create label added event
         let label_event = IssueEvent::label_added(label, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
         self.append_event(issue_id, label_event, Some(parent_commit))?;
 
@@ -196,7 +355,7 @@ impl IssueStore {
         author: Identity,
     ) -> StorageResult<()> {
         // Verify the issue exists and check if label exists
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
 
         if !current_issue.labels.contains(&label) {
             // Label doesn't exist, no-op
@@ -206,24 +365,22 @@ impl IssueStore {
         // Create label removed event
         let label_event = IssueEvent::label_removed(label, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
         self.append_event(issue_id, label_event, Some(parent_commit))?;
 
         Ok(())
     }
 
-    /// Update an issue's title
+    /// Update an issue's title, which is stored trimmed
     pub fn update_title(
         &mut self,
         issue_id: IssueId,
         new_title: String,
         author: Identity,
     ) -> StorageResult<()> {
+        let new_title = normalize_title(&new_title);
         // Verify the issue exists and get current title
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
 
         if current_issue.title == new_title {
             // Title unchanged, no-op
@@ -233,9 +390,6 @@ impl IssueStore {
         // Create title changed event
         let title_event = IssueEvent::title_changed(current_issue.title, new_title, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
         self.append_event(issue_id, title_event, Some(parent_commit))?;
 
@@ -250,10 +404,15 @@ impl IssueStore {
         author: Identity,
     ) -> StorageResult<()> {
         // Verify the issue exists and get current assignee
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
 
         let current_assignee = current_issue.assignees.first().cloned();
-        if current_assignee == new_assignee {
+        let unchanged = match (&current_assignee, &new_assignee) {
+            (Some(current), Some(new)) => current.same_person(new),
+            (None, None) => true,
+            _ => false,
+        };
+        if unchanged {
             // Assignee unchanged, no-op
             return Ok(());
         }
@@ -261,9 +420,6 @@ impl IssueStore {
         // Create assignee changed event
         let assignee_event = IssueEvent::assignee_changed(current_assignee, new_assignee, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
         self.append_event(issue_id, assignee_event, Some(parent_commit))?;
 
@@ -271,6 +427,9 @@ impl IssueStore {
     }
 
     /// Update an issue's assignees (multiple assignees support)
+    ///
+    /// With `compat.singleAssignee` set, a change to at most one assignee is also
+    /// recorded as a singular `AssigneeChanged` event for older tooling.
     pub fn update_assignees(
         &mut self,
         issue_id: IssueId,
@@ -278,22 +437,40 @@ impl IssueStore {
         author: Identity,
     ) -> StorageResult<()> {
         // Verify the issue exists and get current assignees
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
 
-        if current_issue.assignees == new_assignees {
+        let unchanged = current_issue.assignees.len() == new_assignees.len()
+            && current_issue
+                .assignees
+                .iter()
+                .zip(&new_assignees)
+                .all(|(current, new)| current.same_person(new));
+        if unchanged {
             // Assignees unchanged, no-op
             return Ok(());
         }
 
+        // Tools that predate multiple assignees only understand the singular event
+        let compat_event = (self.get_config_bool("compat.singleAssignee") == Some(true)
+            && new_assignees.len() <= 1)
+            .then(|| {
+                IssueEvent::assignee_changed(
+                    current_issue.assignees.first().cloned(),
+                    new_assignees.first().cloned(),
+                    author.clone(),
+                )
+            });
+
         // Create assignees changed event
         let assignees_event =
             IssueEvent::assignees_changed(current_issue.assignees, new_assignees, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
-        self.append_event(issue_id, assignees_event, Some(parent_commit))?;
+        let head = self.append_event(issue_id, assignees_event, Some(parent_commit))?;
+
+        if let Some(compat_event) = compat_event {
+            self.append_event(issue_id, compat_event, Some(head))?;
+        }
 
         Ok(())
     }
@@ -344,9 +521,37 @@ impl IssueStore {
         self.repo.path()
     }
 
-    /// Get a git config value
+    /// Get a setting, from the project's `.git-issue/config.toml` if it sets the
+    /// key and otherwise from git config
     pub fn get_config(&self, key: &str) -> Option<String> {
-        self.repo.get_config(key)
+        self.project.get(key).or_else(|| self.repo.get_config(key))
+    }
+
+    /// Get a boolean setting, with the same precedence as `get_config`
+    pub fn get_config_bool(&self, key: &str) -> Option<bool> {
+        self.project
+            .get_bool(key)
+            .or_else(|| self.repo.get_config_bool(key))
+    }
+
+    /// Get an integer setting, with the same precedence as `get_config`
+    pub fn get_config_integer(&self, key: &str) -> Option<i64> {
+        self.project
+            .get_integer(key)
+            .or_else(|| self.repo.get_config_integer(key))
+    }
+
+    /// The work tree of the repository, or `None` for a bare repository
+    pub fn workdir(&self) -> Option<&Path> {
+        self.repo.workdir()
+    }
+
+    /// The project's `.git-issue/contributors.toml` allowlist, if it has one
+    pub fn contributors(&self) -> StorageResult<Option<Contributors>> {
+        match self.repo.workdir() {
+            Some(workdir) => Contributors::load(workdir),
+            None => Ok(None),
+        }
     }
 
     /// Update an issue's description
@@ -356,15 +561,12 @@ impl IssueStore {
         new_description: String,
         author: Identity,
     ) -> StorageResult<()> {
-        let issue = self.get_issue(issue_id)?;
+        let (issue, parent_commit) = self.get_issue_with_head(issue_id)?;
         let old_description = issue.description.clone();
 
         if old_description != new_description {
             let event = IssueEvent::description_changed(old_description, new_description, author);
 
-            // Get the current HEAD commit to use as parent
-            let parent_commit = self.get_issue_head_commit(issue_id)?;
-
             self.append_event(issue_id, event, Some(parent_commit))?;
         }
 
@@ -379,7 +581,7 @@ impl IssueStore {
         author: Identity,
     ) -> StorageResult<()> {
         // Verify the issue exists and get current priority
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
 
         if current_issue.priority == new_priority {
             // Priority unchanged, no-op
@@ -390,9 +592,6 @@ impl IssueStore {
         let priority_event =
             IssueEvent::priority_changed(current_issue.priority, new_priority, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
         self.append_event(issue_id, priority_event, Some(parent_commit))?;
 
@@ -407,7 +606,7 @@ impl IssueStore {
         author: Identity,
     ) -> StorageResult<()> {
         // Verify the issue exists and get current creator
-        let current_issue = self.get_issue(issue_id)?;
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
 
         if current_issue.created_by == new_created_by {
             // Creator unchanged, no-op
@@ -418,19 +617,47 @@ impl IssueStore {
         let creator_event =
             IssueEvent::created_by_changed(current_issue.created_by, new_created_by, author);
 
-        // Get the current HEAD commit to use as parent
-        let parent_commit = self.get_issue_head_commit(issue_id)?;
-
         // Append the event to the issue chain
         self.append_event(issue_id, creator_event, Some(parent_commit))?;
 
         Ok(())
     }
 
+    /// Set a custom field on an issue, or clear it when `value` is `None`
+    pub fn set_field(
+        &mut self,
+        issue_id: IssueId,
+        key: String,
+        value: Option<String>,
+        author: Identity,
+    ) -> StorageResult<()> {
+        let (current_issue, parent_commit) = self.get_issue_with_head(issue_id)?;
+
+        if current_issue.fields.get(&key) == value.as_ref() {
+            // Field unchanged, no-op
+            return Ok(());
+        }
+
+        let field_event = IssueEvent::field_set(key, value, author);
+        self.append_event(issue_id, field_event, Some(parent_commit))?;
+
+        Ok(())
+    }
+
     // Private helper methods
 
     /// Get all events for an issue in chronological order
     pub fn get_issue_events(&self, issue_id: IssueId) -> StorageResult<Vec<IssueEvent>> {
+        let events = self.get_issue_events_with_oids(issue_id)?;
+        Ok(events.into_iter().map(|(_oid, event)| event).collect())
+    }
+
+    /// Get all events for an issue in chronological order, paired with the OID
+    /// of the commit that stores each event
+    pub fn get_issue_events_with_oids(
+        &self,
+        issue_id: IssueId,
+    ) -> StorageResult<Vec<(gix::ObjectId, IssueEvent)>> {
         let ref_name = self.repo.issue_ref_name(issue_id);
 
         // Get the HEAD commit for this issue
@@ -439,35 +666,58 @@ impl IssueStore {
             None => return Ok(Vec::new()), // Issue doesn't exist
         };
 
-        // Traverse the commit chain to collect all events
-        let mut events = Vec::new();
-        let mut current_commit_oid = Some(head_commit_oid);
-
-        while let Some(commit_oid) = current_commit_oid {
-            // Read the commit
-            let commit_data = self.repo.read_commit(commit_oid)?;
+        let (events, _) = self.collect_events(issue_id, head_commit_oid, None)?;
+        Ok(events)
+    }
 
-            // Read the tree to get the event.json blob
-            let tree_oid = commit_data.tree.parse().map_err(|_| {
-                StorageError::invalid_event_sequence("Invalid tree OID in commit".to_string())
-            })?;
-            let tree_entries = self.repo.read_tree(tree_oid)?;
+    /// Get the events of an issue that are newer than `since`, a previously seen head
+    ///
+    /// Walks back from the current head and stops at `since`, so only the new part of
+    /// the chain is read. Fails if `since` is not part of the issue's history.
+    pub fn events_since(
+        &self,
+        issue_id: IssueId,
+        since: gix::ObjectId,
+    ) -> StorageResult<Vec<(gix::ObjectId, IssueEvent)>> {
+        let head_commit_oid = self.get_issue_head_commit(issue_id)?;
+        let (events, found) = self.collect_events(issue_id, head_commit_oid, Some(since))?;
+        if !found {
+            return Err(StorageError::invalid_event_sequence(format!(
+                "Commit {} is not in the history of issue #{}",
+                since, issue_id
+            )));
+        }
+        Ok(events)
+    }
 
-            // Find the event.json entry
-            let event_blob_oid = tree_entries
-                .iter()
-                .find(|entry| entry.name == "event.json")
-                .map(|entry| entry.oid)
-                .ok_or_else(|| {
-                    StorageError::invalid_event_sequence("No event.json in commit tree".to_string())
-                })?;
+    /// Walk an issue's commit chain from `head`, returning events oldest first
+    ///
+    /// The walk stops before `stop_at` if given; the flag tells whether it was reached.
+    fn collect_events(
+        &self,
+        issue_id: IssueId,
+        head: gix::ObjectId,
+        stop_at: Option<gix::ObjectId>,
+    ) -> StorageResult<(Vec<(gix::ObjectId, IssueEvent)>, bool)> {
+        let mut events = Vec::new();
+        let mut reached_stop = false;
+        let mut current_commit_oid = Some(head);
 
-            // Read and deserialize the event
-            let event_json = self.repo.read_blob(event_blob_oid)?;
-            let event: IssueEvent =
-                serde_json::from_slice(&event_json).map_err(|e| StorageError::Serialization(e))?;
+        while let Some(commit_oid) = current_commit_oid {
+            if stop_at == Some(commit_oid) {
+                reached_stop = true;
+                break;
+            }
 
-            events.push(event);
+            let (commit_data, event) = self.read_event(commit_oid)?;
+            match event {
+                Some(event) => events.push((commit_oid, event)),
+                None => log::warn!(
+                    "Skipping unknown event in issue #{} (commit {})",
+                    issue_id,
+                    commit_oid
+                ),
+            }
 
             // Move to parent commit (earlier in history)
             current_commit_oid = commit_data
@@ -478,26 +728,130 @@ impl IssueStore {
 
         // Reverse to get chronological order (oldest first)
         events.reverse();
-        Ok(events)
+        Ok((events, reached_stop))
+    }
+
+    /// Read the commit storing an event together with the event itself
+    ///
+    /// The event is `None` when it was written by a newer version and is skipped.
+    fn read_event(
+        &self,
+        commit_oid: gix::ObjectId,
+    ) -> StorageResult<(CommitData, Option<IssueEvent>)> {
+        let commit_data = self.repo.read_commit(commit_oid)?;
+
+        // Read the tree to get the event.json blob
+        let tree_oid = commit_data
+            .tree
+            .parse()
+            .map_err(|_| StorageError::invalid_event_sequence("Invalid tree OID in commit"))?;
+        let tree_entries = self.repo.read_tree(tree_oid)?;
+
+        // Find the event.json entry
+        let event_blob_oid = tree_entries
+            .iter()
+            .find(|entry| entry.name == "event.json")
+            .map(|entry| entry.oid)
+            .ok_or_else(|| StorageError::invalid_event_sequence("No event.json in commit tree"))?;
+
+        // Read and deserialize the event, skipping ones written by newer versions
+        let event_json = self.repo.read_blob(event_blob_oid)?;
+        let event = decode_event(&event_json, self.strict)?;
+        Ok((commit_data, event))
+    }
+
+    /// The most recent event of every issue, oldest first
+    ///
+    /// Only each issue's head commit is read, so this is cheap even for long
+    /// histories. Issues whose head event is unknown to this version are left out.
+    pub fn latest_activity(&self) -> StorageResult<Vec<(IssueId, IssueEvent)>> {
+        let mut activity = Vec::new();
+        for issue_id in self.list_issue_ids()? {
+            let head = self.get_issue_head_commit(issue_id)?;
+            if let (_, Some(event)) = self.read_event(head)? {
+                activity.push((issue_id, event));
+            }
+        }
+        activity
+            .sort_by(|(a_id, a), (b_id, b)| a.timestamp().cmp(b.timestamp()).then(a_id.cmp(b_id)));
+        Ok(activity)
+    }
+
+    /// Everyone who authored an event on an issue, in order of first appearance
+    pub fn issue_participants(&self, issue_id: IssueId) -> StorageResult<Vec<Identity>> {
+        let mut participants: Vec<Identity> = Vec::new();
+        for event in self.get_issue_events(issue_id)? {
+            let author = event.author();
+            if !participants.iter().any(|p| p.same_person(author)) {
+                participants.push(author.clone());
+            }
+        }
+        Ok(participants)
+    }
+
+    /// Read the commit object storing an event
+    pub fn read_commit(&self, oid: gix::ObjectId) -> StorageResult<CommitData> {
+        Ok(self.repo.read_commit(oid)?)
     }
 
     /// Get the HEAD commit OID for an issue
-    fn get_issue_head_commit(&self, issue_id: IssueId) -> StorageResult<gix::ObjectId> {
+    pub fn get_issue_head_commit(&self, issue_id: IssueId) -> StorageResult<gix::ObjectId> {
         let ref_name = self.repo.issue_ref_name(issue_id);
         self.repo
             .read_ref(&ref_name)?
             .ok_or_else(|| StorageError::issue_not_found(issue_id))
     }
 
-    /// Append an event to an issue's commit chain
+    /// Append an event to an issue's commit chain, returning the new head commit
     fn append_event(
         &mut self,
         issue_id: IssueId,
         event: IssueEvent,
         parent_commit: Option<gix::ObjectId>,
-    ) -> StorageResult<()> {
-        // Serialize the event to JSON
-        let event_json = serde_json::to_string(&event).map_err(StorageError::Serialization)?;
+    ) -> StorageResult<gix::ObjectId> {
+        self.write_event(issue_id, event, parent_commit, parent_commit)
+    }
+
+    /// Mark an event as made on behalf of the store's `on_behalf_of` identity, if any
+    fn attribute_event(&self, event: IssueEvent) -> IssueEvent {
+        match (&self.on_behalf_of, event.on_behalf_of()) {
+            (Some(identity), None) => event.with_on_behalf_of(identity.clone()),
+            _ => event,
+        }
+    }
+
+    /// Serialize an event to JSON, rejecting it if it exceeds `storage.maxEventSize`
+    ///
+    /// Huge pastes would otherwise bloat every reconstruction of the issue.
+    fn encode_event_within_limit(&self, event: &IssueEvent) -> StorageResult<String> {
+        let event_json = encode_event(event)?;
+
+        let limit = self
+            .get_config_integer("storage.maxEventSize")
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(DEFAULT_MAX_EVENT_SIZE);
+        if event_json.len() > limit {
+            return Err(StorageError::EventTooLarge {
+                size: event_json.len(),
+                limit,
+            });
+        }
+        Ok(event_json)
+    }
+
+    /// Commit an event on top of `parent_commit` and move the issue ref from `current_head`
+    ///
+    /// The ref is created when `current_head` is `None`, and otherwise only updated
+    /// if it still points at `current_head`.
+    fn write_event(
+        &mut self,
+        issue_id: IssueId,
+        event: IssueEvent,
+        parent_commit: Option<gix::ObjectId>,
+        current_head: Option<gix::ObjectId>,
+    ) -> StorageResult<gix::ObjectId> {
+        let event = self.attribute_event(event);
+        let event_json = self.encode_event_within_limit(&event)?;
 
         // Create a blob for the event
         let blob_oid = self.repo.write_blob(event_json.as_bytes())?;
@@ -511,40 +865,7 @@ impl IssueStore {
         let tree_oid = self.repo.write_tree(tree_entries)?;
 
         // Create a commit message describing the event
-        let commit_message = match &event {
-            IssueEvent::Created { title, .. } => format!("Created: {}", title),
-            IssueEvent::StatusChanged { from, to, .. } => {
-                format!("StatusChanged: {} → {}", from, to)
-            }
-            IssueEvent::CommentAdded { comment_id, .. } => format!("CommentAdded: {}", comment_id),
-            IssueEvent::LabelAdded { label, .. } => format!("LabelAdded: {}", label),
-            IssueEvent::LabelRemoved { label, .. } => format!("LabelRemoved: {}", label),
-            IssueEvent::TitleChanged { new_title, .. } => format!("TitleChanged: {}", new_title),
-            IssueEvent::AssigneeChanged { new_assignee, .. } => match new_assignee {
-                Some(identity) => format!("AssigneeChanged: {}", identity.name),
-                None => "AssigneeChanged: unassigned".to_string(),
-            },
-            IssueEvent::AssigneesChanged { new_assignees, .. } => {
-                if new_assignees.is_empty() {
-                    "AssigneesChanged: unassigned all".to_string()
-                } else if new_assignees.len() == 1 {
-                    format!("AssigneesChanged: {}", new_assignees[0].name)
-                } else {
-                    format!("AssigneesChanged: {} assignees", new_assignees.len())
-                }
-            }
-            IssueEvent::DescriptionChanged { .. } => "DescriptionChanged".to_string(),
-            IssueEvent::PriorityChanged {
-                old_priority,
-                new_priority,
-                ..
-            } => {
-                format!("PriorityChanged: {} → {}", old_priority, new_priority)
-            }
-            IssueEvent::CreatedByChanged { new_created_by, .. } => {
-                format!("CreatedByChanged: {}", new_created_by.email)
-            }
-        };
+        let commit_message = self.commit_message(issue_id, &event);
 
         // Create the commit
         let parents = parent_commit.map(|oid| vec![oid]).unwrap_or_default();
@@ -554,11 +875,11 @@ impl IssueStore {
 
         // Update the issue reference to point to the new commit
         let ref_name = self.repo.issue_ref_name(issue_id);
-        match parent_commit {
-            Some(expected_parent) => {
+        match current_head {
+            Some(expected_head) => {
                 // Update existing reference with expected old value for concurrency safety
                 self.repo
-                    .update_ref(&ref_name, commit_oid, Some(expected_parent))?;
+                    .update_ref(&ref_name, commit_oid, Some(expected_head))?;
             }
             None => {
                 // Create new reference for first commit
@@ -566,183 +887,773 @@ impl IssueStore {
             }
         }
 
-        Ok(())
-    }
+        // The event is stored once the ref has moved, so a failing cache update must not
+        // report the write as failed; stale entries are rebuilt on the next read or by doctor
+        if let Err(e) = self.update_index_entry(issue_id, &event, parent_commit, commit_oid) {
+            log::warn!("Failed to update the issue index for #{}: {}", issue_id, e);
+        }
+        if let Err(e) = self.update_search_index(issue_id, &event, parent_commit, commit_oid) {
+            log::warn!("Failed to update the search index for #{}: {}", issue_id, e);
+        }
 
-    /// Get default push remote using git's standard resolution
-    pub fn get_default_push_remote(&self) -> StorageResult<String> {
-        self.repo
-            .get_default_push_remote()
-            .map_err(StorageError::from)
+        Ok(commit_oid)
     }
 
-    /// Check if a remote exists
-    pub fn remote_exists(&self, remote_name: &str) -> StorageResult<bool> {
-        self.repo
-            .remote_exists(remote_name)
-            .map_err(StorageError::from)
+    /// Commit message for an event, from the `issue.commitMessage` template if set
+    ///
+    /// The template may use `{id}`, `{author}`, `{email}` and `{summary}`; the
+    /// default is just `{summary}`.
+    fn commit_message(&self, issue_id: IssueId, event: &IssueEvent) -> String {
+        match self.get_config("issue.commitMessage") {
+            // The summary goes last so text inside it is never treated as a placeholder
+            Some(template) => template
+                .replace("{id}", &issue_id.to_string())
+                .replace("{author}", &event.author().name)
+                .replace("{email}", &event.author().email)
+                .replace("{summary}", &event.summary()),
+            None => event.summary(),
+        }
     }
 
-    /// List all issue refs that should be synced
-    pub fn list_issue_refs(&self) -> StorageResult<Vec<String>> {
-        let refs = self.repo.list_refs("refs/git-issue/issues/")?;
-        Ok(refs.into_iter().map(|(ref_name, _oid)| ref_name).collect())
-    }
+    /// Keep the index entry for `issue_id` in step with a newly appended event
+    ///
+    /// An entry that was already stale is dropped and rebuilt on the next read.
+    fn update_index_entry(
+        &mut self,
+        issue_id: IssueId,
+        event: &IssueEvent,
+        parent_commit: Option<gix::ObjectId>,
+        commit_oid: gix::ObjectId,
+    ) -> StorageResult<()> {
+        let mut index = self.read_index()?;
 
-    /// List all metadata refs that should be synced
-    pub fn list_meta_refs(&self) -> StorageResult<Vec<String>> {
-        let refs = self.repo.list_refs("refs/git-issue/meta/")?;
-        Ok(refs.into_iter().map(|(ref_name, _oid)| ref_name).collect())
+        match (parent_commit, index.get_mut(&issue_id)) {
+            (None, _) => match IndexEntry::from_created(event, commit_oid) {
+                Some(mut entry) => {
+                    entry.slug = unique_slug(&index, &entry.slug, issue_id);
+                    index.insert(issue_id, entry);
+                }
+                None => {
+                    index.remove(&issue_id);
+                }
+            },
+            (Some(parent), Some(entry)) if entry.head_oid == parent.to_string() => {
+                entry.apply(event, commit_oid);
+            }
+            _ => {
+                // The entry was already out of date, so bring the whole index up to date
+                return self.refresh_index().map(|_| ());
+            }
+        }
+
+        self.write_index(&index)
     }
 
-    /// Check if a ref exists
-    pub fn ref_exists(&self, ref_name: &str) -> StorageResult<bool> {
-        Ok(self.repo.read_ref(ref_name)?.is_some())
+    /// Read the issue index as stored, without checking it against the issue refs
+    pub fn read_index(&self) -> StorageResult<IssueIndex> {
+        let Some(oid) = self.repo.read_ref(INDEX_REF)? else {
+            return Ok(IssueIndex::new());
+        };
+
+        let blob = self.repo.read_blob(oid)?;
+        match serde_json::from_slice(&blob) {
+            Ok(index) => Ok(index),
+            Err(e) => {
+                // The index is only a cache; rebuild it rather than fail
+                log::warn!("Discarding unreadable issue index: {}", e);
+                Ok(IssueIndex::new())
+            }
+        }
     }
 
-    /// Fetch refs from a remote for comparison
-    pub fn fetch_refs_from_remote(
-        &self,
-        remote_name: &str,
-        refs: &[String],
-    ) -> StorageResult<std::collections::HashMap<String, String>> {
-        self.repo
-            .fetch_refs_from_remote(remote_name, refs)
-            .map_err(StorageError::from)
+    fn write_index(&mut self, index: &IssueIndex) -> StorageResult<()> {
+        let json = serde_json::to_vec(index).map_err(StorageError::Serialization)?;
+        Ok(self.repo.write_cache_ref(INDEX_REF, &json)?)
     }
 
-    /// Push a ref to a remote
-    pub fn push_ref_to_remote(
-        &self,
-        remote_name: &str,
-        ref_name: &str,
-        force: bool,
+    /// Index the text of a newly appended event for `search`
+    ///
+    /// An issue that was already stale is left alone and reindexed on the next search.
+    fn update_search_index(
+        &mut self,
+        issue_id: IssueId,
+        event: &IssueEvent,
+        parent_commit: Option<gix::ObjectId>,
+        commit_oid: gix::ObjectId,
     ) -> StorageResult<()> {
-        self.repo
-            .push_ref_to_remote(remote_name, ref_name, force)
-            .map_err(StorageError::from)
+        let mut index = self.read_search_index()?;
+        let parent = parent_commit.map(|oid| oid.to_string());
+        if index.head(issue_id) != parent.as_deref() {
+            // The issue was already out of date, so bring the whole index up to date
+            return self.refresh_search_index().map(|_| ());
+        }
+
+        index.apply(issue_id, event, commit_oid);
+        self.write_search_index(&index)
     }
 
-    /// Sync all issues to remote repository
-    pub fn sync_to_remote(
-        &mut self,
-        remote_name: &str,
-        specific_issues: Option<&[IssueId]>,
-        force: bool,
-    ) -> StorageResult<crate::cli::commands::SyncSummary> {
-        use crate::cli::commands::{RefComparisonResult, SyncRef, SyncSummary};
-
-        let mut summary = SyncSummary::default();
-
-        // Discover refs to sync
-        let refs_to_sync = if let Some(issue_ids) = specific_issues {
-            // Sync only specific issues
-            let mut refs = Vec::new();
-            for &issue_id in issue_ids {
-                let ref_name = format!("refs/git-issue/issues/{}", issue_id);
-                if self.ref_exists(&ref_name)? {
-                    refs.push(ref_name);
-                } else {
-                    return Err(StorageError::IssueNotFound { issue_id });
-                }
-            }
-            refs
-        } else {
-            // Sync all issue refs
-            let mut refs = self.list_issue_refs()?;
-            refs.extend(self.list_meta_refs()?);
-            refs
+    /// Read the search index as stored, without checking it against the issue refs
+    pub fn read_search_index(&self) -> StorageResult<SearchIndex> {
+        let Some(oid) = self.repo.read_ref(SEARCH_INDEX_REF)? else {
+            return Ok(SearchIndex::default());
         };
 
-        if refs_to_sync.is_empty() {
-            return Ok(summary);
-        }
-
-        // Fetch remote refs for comparison
-        let remote_refs = self.fetch_refs_from_remote(remote_name, &refs_to_sync)?;
-
-        // Compare and sync each ref
-        for ref_name in refs_to_sync {
-            let local_oid = self.repo.read_ref(&ref_name)?.map(|oid| oid.to_string());
-            let remote_oid = remote_refs.get(&ref_name).cloned();
-
-            let comparison = match (&local_oid, &remote_oid) {
-                (Some(_), None) => RefComparisonResult::NewRef,
-                (Some(local), Some(remote)) if local == remote => RefComparisonResult::UpToDate,
-                (Some(local), Some(remote)) => {
-                    // Use git to determine relationship
-                    let (local_commits, remote_commits) = self.repo.compare_refs(local, remote)?;
-                    if remote_commits == 0 {
-                        RefComparisonResult::FastForward { local_commits }
-                    } else if local_commits == 0 {
-                        RefComparisonResult::Behind { remote_commits }
-                    } else {
-                        RefComparisonResult::Diverged {
-                            local_commits,
-                            remote_commits,
-                        }
-                    }
-                }
-                (None, Some(_)) => RefComparisonResult::LocallyDeleted,
-                (None, None) => continue, // Skip non-existent refs
-            };
+        match serde_json::from_slice(&self.repo.read_blob(oid)?) {
+            Ok(index) => Ok(index),
+            Err(e) => {
+                // Like the issue index, this is only a cache
+                log::warn!("Discarding unreadable search index: {}", e);
+                Ok(SearchIndex::default())
+            }
+        }
+    }
 
-            // Extract issue ID from ref name if it's an issue ref
-            let issue_id = if ref_name.starts_with("refs/git-issue/issues/") {
-                ref_name
-                    .strip_prefix("refs/git-issue/issues/")
-                    .and_then(|s| s.parse().ok())
-            } else {
-                None
-            };
+    fn write_search_index(&mut self, index: &SearchIndex) -> StorageResult<()> {
+        let json = serde_json::to_vec(index).map_err(StorageError::Serialization)?;
+        Ok(self.repo.write_cache_ref(SEARCH_INDEX_REF, &json)?)
+    }
 
-            let _sync_ref = SyncRef {
-                ref_name: ref_name.clone(),
-                local_oid,
-                remote_oid,
-                comparison: comparison.clone(),
-                issue_id,
-            };
+    /// Get the search index, reindexing issues whose head no longer matches the issue ref
+    ///
+    /// Nothing is written, so read-only commands never create commits; use
+    /// `refresh_search_index` to store the result.
+    pub fn current_search_index(&self) -> StorageResult<SearchIndex> {
+        Ok(self.rebuild_search_index()?.0)
+    }
 
-            // Decide whether to sync this ref
-            match comparison {
-                RefComparisonResult::UpToDate => {
-                    summary.skipped_refs.push(ref_name);
-                }
-                RefComparisonResult::FastForward { .. } | RefComparisonResult::NewRef => {
-                    // Safe to push
-                    match self.push_ref_to_remote(remote_name, &ref_name, false) {
-                        Ok(_) => summary.pushed_refs.push(ref_name),
-                        Err(e) => summary.failed_refs.push((ref_name, e.to_string())),
-                    }
-                }
-                RefComparisonResult::Diverged { .. } | RefComparisonResult::Behind { .. } => {
-                    if force {
-                        // Force push (with lease by default)
-                        match self.push_ref_to_remote(remote_name, &ref_name, true) {
-                            Ok(_) => summary.pushed_refs.push(ref_name),
-                            Err(e) => summary.failed_refs.push((ref_name, e.to_string())),
-                        }
-                    } else {
-                        summary.conflicts.push(ref_name);
+    /// Bring the stored search index up to date with the issue refs and return it
+    pub fn refresh_search_index(&mut self) -> StorageResult<SearchIndex> {
+        let (index, changed) = self.rebuild_search_index()?;
+        if changed {
+            self.write_search_index(&index)?;
+        }
+        Ok(index)
+    }
+
+    /// Reindex stale issues in memory, returning the index and whether it changed
+    fn rebuild_search_index(&self) -> StorageResult<(SearchIndex, bool)> {
+        let mut index = self.read_search_index()?;
+        let mut changed = false;
+
+        let mut heads = BTreeMap::new();
+        for (ref_name, head_oid) in self.repo.list_refs("refs/git-issue/issues/")? {
+            if let Some(issue_id) = ref_name
+                .strip_prefix("refs/git-issue/issues/")
+                .and_then(|id| id.parse::<IssueId>().ok())
+            {
+                heads.insert(issue_id, head_oid);
+            }
+        }
+
+        let removed: Vec<_> = index
+            .issue_ids()
+            .filter(|issue_id| !heads.contains_key(issue_id))
+            .collect();
+        for issue_id in removed {
+            index.remove(issue_id);
+            changed = true;
+        }
+
+        for (issue_id, head_oid) in heads {
+            let indexed_head = index.head(issue_id).and_then(|oid| oid.parse().ok());
+            if indexed_head == Some(head_oid) {
+                continue;
+            }
+
+            // Events appended since the issue was indexed only add words, so read just those
+            match indexed_head.map(|since| self.events_since(issue_id, since)) {
+                Some(Ok(events)) => {
+                    for (_, event) in events {
+                        index.apply(issue_id, &event, head_oid);
                     }
                 }
-                RefComparisonResult::LocallyDeleted => {
-                    // Could implement ref deletion, but skip for now
-                    summary.skipped_refs.push(ref_name);
+                _ => {
+                    let events = self.get_issue_events(issue_id)?;
+                    index.reindex(issue_id, &events, head_oid);
                 }
             }
+            changed = true;
         }
 
-        Ok(summary)
+        Ok((index, changed))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::storage::test_helpers::*;
-    use tempfile::TempDir;
+    /// Get the issue index, rebuilding entries whose head no longer matches the issue ref
+    ///
+    /// Nothing is written, so read-only commands never create commits; use
+    /// `refresh_index` to store the result.
+    pub fn current_index(&self) -> StorageResult<IssueIndex> {
+        Ok(self.rebuild_index()?.0)
+    }
+
+    /// Bring the stored issue index up to date with the issue refs and return it
+    pub fn refresh_index(&mut self) -> StorageResult<IssueIndex> {
+        let (index, changed) = self.rebuild_index()?;
+        if changed {
+            self.write_index(&index)?;
+        }
+        Ok(index)
+    }
+
+    /// Rebuild stale index entries in memory, returning the index and whether it changed
+    fn rebuild_index(&self) -> StorageResult<(IssueIndex, bool)> {
+        let stored = self.read_index()?;
+        let mut index = IssueIndex::new();
+        let mut stale = Vec::new();
+
+        for (ref_name, head_oid) in self.repo.list_refs("refs/git-issue/issues/")? {
+            let Some(issue_id) = ref_name
+                .strip_prefix("refs/git-issue/issues/")
+                .and_then(|id| id.parse::<IssueId>().ok())
+            else {
+                continue;
+            };
+
+            match stored.get(&issue_id) {
+                Some(entry) if entry.head_oid == head_oid.to_string() && !entry.slug.is_empty() => {
+                    index.insert(issue_id, entry.clone());
+                }
+                _ => stale.push((issue_id, head_oid)),
+            }
+        }
+
+        // Rebuild in ID order so slug collisions resolve the same way every time
+        stale.sort();
+        let changed = !stale.is_empty();
+        for (issue_id, head_oid) in stale {
+            let events = self.get_issue_events(issue_id)?;
+            let Some(IssueEvent::Created { title, .. }) = events.first() else {
+                continue;
+            };
+            let issue = Issue::from_events(issue_id, &events)
+                .map_err(|e| StorageError::invalid_event_sequence(e.to_string()))?;
+
+            let slug = unique_slug(&index, &slugify(title), issue_id);
+            index.insert(issue_id, IndexEntry::from_issue(&issue, slug, head_oid));
+        }
+
+        let changed = changed || index.len() != stored.len();
+        Ok((index, changed))
+    }
+
+    /// Drop index entries for issues whose ref no longer exists, returning their IDs
+    pub fn prune_index(&mut self) -> StorageResult<Vec<IssueId>> {
+        let mut index = self.read_index()?;
+        let issue_ids = self.list_issue_ids()?;
+
+        let dangling: Vec<IssueId> = index
+            .keys()
+            .copied()
+            .filter(|issue_id| !issue_ids.contains(issue_id))
+            .collect();
+        if !dangling.is_empty() {
+            index.retain(|issue_id, _| !dangling.contains(issue_id));
+            self.write_index(&index)?;
+        }
+
+        Ok(dangling)
+    }
+
+    /// Get the ID the next created issue will receive
+    pub fn next_issue_id(&self) -> StorageResult<IssueId> {
+        Ok(self.repo.get_next_issue_id()?)
+    }
+
+    /// Move the issue counter past `issue_id` if it is not already
+    pub fn advance_issue_id_past(&mut self, issue_id: IssueId) -> StorageResult<()> {
+        Ok(self.repo.advance_issue_id_past(issue_id)?)
+    }
+
+    /// Name of the meta ref holding the next issue ID
+    pub fn next_issue_id_ref(&self) -> String {
+        self.repo.next_issue_id_ref()
+    }
+
+    /// Reconcile the issue counter with the remote's, keeping the higher one
+    ///
+    /// The counter must never move backwards, or IDs get handed out twice: a
+    /// higher remote counter is adopted locally instead of being overwritten.
+    pub fn reconcile_issue_counter(
+        &mut self,
+        remote_name: &str,
+    ) -> StorageResult<CounterReconciliation> {
+        let local = self.repo.get_next_issue_id()?;
+        match self.repo.remote_next_issue_id(remote_name)? {
+            Some((remote, _)) if remote > local => {
+                self.repo.advance_issue_id_past(remote - 1)?;
+                Ok(CounterReconciliation::UpToDate)
+            }
+            Some((remote, _)) if remote == local => Ok(CounterReconciliation::UpToDate),
+            Some((_, remote_oid)) => Ok(CounterReconciliation::Ahead {
+                remote_oid: Some(remote_oid),
+            }),
+            None => Ok(CounterReconciliation::Ahead { remote_oid: None }),
+        }
+    }
+
+    /// Push the issue counter over the remote one, if that is still at `remote_oid`
+    ///
+    /// Fails with `GitError::ConcurrentReferenceUpdate` when the remote counter
+    /// moved since it was reconciled.
+    pub fn push_issue_counter(
+        &self,
+        remote_name: &str,
+        remote_oid: Option<&str>,
+    ) -> StorageResult<()> {
+        let counter_ref = self.repo.next_issue_id_ref();
+        Ok(self
+            .repo
+            .push_ref_with_lease(remote_name, &counter_ref, remote_oid)?)
+    }
+
+    /// Delete an issue's ref, dropping its whole event chain
+    pub fn delete_issue(&mut self, issue_id: IssueId) -> StorageResult<()> {
+        let ref_name = self.repo.issue_ref_name(issue_id);
+        if self.repo.read_ref(&ref_name)?.is_none() {
+            return Err(StorageError::issue_not_found(issue_id));
+        }
+        self.repo.delete_ref(&ref_name)?;
+        self.prune_index()?;
+        Ok(())
+    }
+
+    /// Resolve an issue reference given on the command line to an issue ID
+    ///
+    /// Accepts a numeric ID, optionally prefixed with `#`, or an issue slug.
+    pub fn resolve_ref(&self, reference: &str) -> StorageResult<IssueId> {
+        let reference = reference.trim();
+        if let Ok(issue_id) = reference.trim_start_matches('#').parse::<IssueId>() {
+            return Ok(issue_id);
+        }
+
+        let slug = reference.to_lowercase();
+        self.current_index()?
+            .into_iter()
+            .find(|(_, entry)| entry.slug == slug)
+            .map(|(issue_id, _)| issue_id)
+            .ok_or_else(|| StorageError::unknown_issue_ref(reference))
+    }
+
+    /// Get the issues whose index entry matches `keep`, only reconstructing those
+    pub fn list_issues_where(
+        &self,
+        keep: impl Fn(&IndexEntry) -> bool,
+    ) -> StorageResult<Vec<Issue>> {
+        let index = self.current_index()?;
+        let mut issues = Vec::new();
+
+        for (issue_id, entry) in index {
+            if keep(&entry) {
+                issues.push(self.get_issue(issue_id)?);
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Get the issues matching a full-text search
+    ///
+    /// The search index and the status and label filters are consulted first, so
+    /// issues they rule out are never reconstructed.
+    pub fn search(&self, query: &SearchQuery) -> StorageResult<Vec<Issue>> {
+        let candidates = self.current_search_index()?.candidates(&query.pattern);
+        let mut issues = Vec::new();
+
+        for (issue_id, entry) in self.current_index()? {
+            let candidate = candidates
+                .as_ref()
+                .is_none_or(|candidates| candidates.contains(&issue_id));
+            if candidate && query.keeps(&entry) {
+                let issue = self.get_issue(issue_id)?;
+                if query.matches(&issue) {
+                    issues.push(issue);
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Get default push remote using git's standard resolution
+    pub fn get_default_push_remote(&self) -> StorageResult<String> {
+        self.repo
+            .get_default_push_remote()
+            .map_err(StorageError::from)
+    }
+
+    /// Check if a remote exists
+    pub fn remote_exists(&self, remote_name: &str) -> StorageResult<bool> {
+        self.repo
+            .remote_exists(remote_name)
+            .map_err(StorageError::from)
+    }
+
+    /// Serialize local writers: hold the returned lock for the duration of a mutating command
+    pub fn lock_for_writing(&self) -> StorageResult<WriteLock> {
+        Ok(self.repo.lock_for_writing()?)
+    }
+
+    /// List all configured remotes
+    pub fn list_remotes(&self) -> StorageResult<Vec<String>> {
+        self.repo.list_remotes().map_err(StorageError::from)
+    }
+
+    /// List all issue refs that should be synced
+    pub fn list_issue_refs(&self) -> StorageResult<Vec<String>> {
+        let refs = self.repo.list_refs("refs/git-issue/issues/")?;
+        Ok(refs.into_iter().map(|(ref_name, _oid)| ref_name).collect())
+    }
+
+    /// Count the repository's loose and packed objects
+    pub fn object_counts(&self) -> StorageResult<ObjectCounts> {
+        Ok(self.repo.object_counts()?)
+    }
+
+    /// Pack the loose objects of every git-issue ref, so a push sends fewer objects
+    pub fn repack(&self) -> StorageResult<()> {
+        let refs: Vec<String> = self
+            .repo
+            .list_refs(&format!("{}/", REFS_NAMESPACE))?
+            .into_iter()
+            .map(|(ref_name, _oid)| ref_name)
+            .collect();
+        if refs.is_empty() {
+            return Ok(());
+        }
+        Ok(self.repo.repack_refs(&refs)?)
+    }
+
+    /// List all metadata refs that should be synced
+    pub fn list_meta_refs(&self) -> StorageResult<Vec<String>> {
+        let refs = self.repo.list_refs("refs/git-issue/meta/")?;
+        Ok(refs
+            .into_iter()
+            .map(|(ref_name, _oid)| ref_name)
+            .filter(|ref_name| {
+                ref_name != SYNC_JOURNAL_REF
+                    && ref_name != INDEX_REF
+                    && ref_name != SEARCH_INDEX_REF
+                    && !ref_name.starts_with(SNAPSHOT_REF_PREFIX)
+                    && ref_name != EXPORT_MARKER_REF
+            })
+            .collect())
+    }
+
+    /// Refs that older versions wrote under the legacy `refs/git-tracker` namespace
+    pub fn legacy_refs(&self) -> StorageResult<Vec<String>> {
+        let refs = self
+            .repo
+            .list_refs(&format!("{}/", LEGACY_REFS_NAMESPACE))?;
+        Ok(refs.into_iter().map(|(ref_name, _oid)| ref_name).collect())
+    }
+
+    /// Move a legacy ref to the same name under `refs/git-issue`
+    ///
+    /// Returns the new ref name, or `None` when a ref pointing elsewhere already
+    /// exists there and both are left alone. Migrating an issue ref advances the
+    /// issue counter past its ID.
+    pub fn migrate_legacy_ref(&mut self, ref_name: &str) -> StorageResult<Option<String>> {
+        let (Some(suffix), Some(oid)) = (
+            ref_name.strip_prefix(LEGACY_REFS_NAMESPACE),
+            self.repo.read_ref(ref_name)?,
+        ) else {
+            return Ok(None);
+        };
+
+        let target = format!("{}{}", REFS_NAMESPACE, suffix);
+        match self.repo.read_ref(&target)? {
+            None => self.repo.create_ref(&target, oid)?,
+            Some(existing) if existing == oid => {}
+            Some(_) => return Ok(None),
+        }
+        self.repo.delete_ref(ref_name)?;
+
+        if let Some(issue_id) = suffix
+            .strip_prefix("/issues/")
+            .and_then(|id| id.parse().ok())
+        {
+            self.repo.advance_issue_id_past(issue_id)?;
+        }
+        Ok(Some(target))
+    }
+
+    /// Read the object a ref points to
+    pub fn read_ref(&self, ref_name: &str) -> StorageResult<Option<gix::ObjectId>> {
+        Ok(self.repo.read_ref(ref_name)?)
+    }
+
+    /// Export all issue and meta refs into a git bundle at `output`
+    ///
+    /// The sync journal is local state and is left out. Returns the number of
+    /// exported refs.
+    pub fn export_bundle(&self, output: &Path) -> StorageResult<usize> {
+        let mut refs: Vec<String> = self
+            .list_issue_ids()?
+            .into_iter()
+            .map(|issue_id| self.repo.issue_ref_name(issue_id))
+            .collect();
+        refs.extend(self.list_meta_refs()?);
+
+        self.repo.create_bundle(output, &refs)?;
+        Ok(refs.len())
+    }
+
+    /// Import issues from a git bundle written by `export_bundle`
+    ///
+    /// Issue refs are fast-forwarded, and the issue counter is advanced past
+    /// the highest imported ID. Returns the IDs of issues that were added or
+    /// updated.
+    pub fn import_bundle(&mut self, bundle: &Path) -> StorageResult<Vec<IssueId>> {
+        let before: HashMap<String, gix::ObjectId> = self
+            .repo
+            .list_refs("refs/git-issue/issues/")?
+            .into_iter()
+            .collect();
+
+        self.repo.fetch_from(bundle, ISSUES_REFSPEC)?;
+
+        let mut changed = Vec::new();
+        for issue_id in self.list_issue_ids()? {
+            let ref_name = self.repo.issue_ref_name(issue_id);
+            if self.repo.read_ref(&ref_name)? != before.get(&ref_name).copied() {
+                changed.push(issue_id);
+            }
+        }
+
+        if let Some(&max_id) = self.list_issue_ids()?.last() {
+            self.repo.advance_issue_id_past(max_id)?;
+        }
+
+        Ok(changed)
+    }
+
+    /// Collect every issue's full event list, for a native JSON export
+    pub fn dump_issues(&self) -> StorageResult<Vec<IssueDump>> {
+        let mut dumps = Vec::new();
+        for issue_id in self.list_issue_ids()? {
+            dumps.push(IssueDump {
+                id: issue_id,
+                events: self.get_issue_events(issue_id)?,
+            });
+        }
+        Ok(dumps)
+    }
+
+    /// When the last native export was written, if one was recorded
+    pub fn last_export(&self) -> StorageResult<Option<DateTime<Utc>>> {
+        let Some(oid) = self.repo.read_ref(EXPORT_MARKER_REF)? else {
+            return Ok(None);
+        };
+        let blob = self.repo.read_blob(oid)?;
+        let marker = String::from_utf8_lossy(&blob);
+        DateTime::parse_from_rfc3339(marker.trim())
+            .map(|at| Some(at.with_timezone(&Utc)))
+            .map_err(|e| {
+                GitError::InvalidObjectData {
+                    message: format!("Invalid export marker '{}': {}", marker.trim(), e),
+                }
+                .into()
+            })
+    }
+
+    /// Remember `at` as the time of the latest native export
+    ///
+    /// Unlike a cache, the marker must not be lost, so callers hold the write lock.
+    pub fn record_export(&self, at: DateTime<Utc>) -> StorageResult<()> {
+        Ok(self
+            .repo
+            .write_blob_ref(EXPORT_MARKER_REF, at.to_rfc3339().as_bytes())?)
+    }
+
+    /// Recreate issues from a native export by replaying their events
+    ///
+    /// Events keep their original authors and timestamps. All dumps are checked
+    /// before anything is written, so an ID that is taken, an event list that
+    /// does not form a valid issue or an event over `storage.maxEventSize`
+    /// leaves the store untouched. Returns the restored IDs.
+    pub fn restore_issues(&mut self, dumps: &[IssueDump]) -> StorageResult<Vec<IssueId>> {
+        let mut seen = std::collections::HashSet::new();
+        for dump in dumps {
+            if dump.id == 0 {
+                return Err(StorageError::invalid_issue_id("0"));
+            }
+            if !seen.insert(dump.id) || self.issue_exists(dump.id)? {
+                return Err(StorageError::issue_already_exists(dump.id));
+            }
+            Issue::from_events(dump.id, &dump.events).map_err(|e| {
+                StorageError::invalid_event_sequence(format!("issue #{}: {}", dump.id, e))
+            })?;
+            for event in &dump.events {
+                self.encode_event_within_limit(&self.attribute_event(event.clone()))?;
+            }
+        }
+
+        let mut restored = Vec::new();
+        for dump in dumps {
+            let mut parent = None;
+            for event in &dump.events {
+                parent = Some(self.append_event(dump.id, event.clone(), parent)?);
+            }
+            self.repo.advance_issue_id_past(dump.id)?;
+            restored.push(dump.id);
+        }
+        Ok(restored)
+    }
+
+    /// Read the entries of the sync journal, oldest first
+    pub fn sync_journal(&self) -> StorageResult<Vec<SyncJournalEntry>> {
+        match self.repo.read_ref(SYNC_JOURNAL_REF)? {
+            Some(oid) => {
+                let blob = self.repo.read_blob(oid)?;
+                serde_json::from_slice(&blob).map_err(StorageError::Serialization)
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Record a pushed ref in the sync journal
+    pub fn append_sync_journal(&mut self, entry: SyncJournalEntry) -> StorageResult<()> {
+        let mut entries = self.sync_journal()?;
+        entries.push(entry);
+        self.write_sync_journal(&entries)
+    }
+
+    /// Drop the journal entries for `remote_name` after a sync to it has completed
+    pub fn clear_sync_journal(&mut self, remote_name: &str) -> StorageResult<()> {
+        let mut entries = self.sync_journal()?;
+        entries.retain(|entry| entry.remote != remote_name);
+        self.write_sync_journal(&entries)
+    }
+
+    /// Store the sync journal, removing the ref once it is empty
+    fn write_sync_journal(&mut self, entries: &[SyncJournalEntry]) -> StorageResult<()> {
+        let old_oid = self.repo.read_ref(SYNC_JOURNAL_REF)?;
+
+        if entries.is_empty() {
+            if old_oid.is_some() {
+                self.repo.delete_ref(SYNC_JOURNAL_REF)?;
+            }
+            return Ok(());
+        }
+
+        let json = serde_json::to_vec(entries).map_err(StorageError::Serialization)?;
+        let blob_oid = self.repo.write_blob(&json)?;
+
+        match old_oid {
+            Some(old_oid) => self
+                .repo
+                .update_ref(SYNC_JOURNAL_REF, blob_oid, Some(old_oid))?,
+            None => self.repo.create_ref(SYNC_JOURNAL_REF, blob_oid)?,
+        }
+
+        Ok(())
+    }
+
+    /// Check if a ref exists
+    pub fn ref_exists(&self, ref_name: &str) -> StorageResult<bool> {
+        Ok(self.repo.read_ref(ref_name)?.is_some())
+    }
+
+    /// Fetch refs from a remote for comparison
+    pub fn fetch_refs_from_remote(
+        &self,
+        remote_name: &str,
+        refs: &[String],
+    ) -> StorageResult<std::collections::HashMap<String, String>> {
+        self.repo
+            .fetch_refs_from_remote(remote_name, refs)
+            .map_err(StorageError::from)
+    }
+
+    /// Count the commits only in `local_oid` and only in `remote_oid`
+    pub fn compare_refs(&self, local_oid: &str, remote_oid: &str) -> StorageResult<(u32, u32)> {
+        Ok(self.repo.compare_refs(local_oid, remote_oid)?)
+    }
+
+    /// Push a ref to a remote
+    pub fn push_ref_to_remote(
+        &self,
+        remote_name: &str,
+        ref_name: &str,
+        force: bool,
+    ) -> StorageResult<()> {
+        self.repo
+            .push_ref_to_remote(remote_name, ref_name, force)
+            .map_err(StorageError::from)
+    }
+
+    /// The refs a sync pushes: the given issues, or every issue and meta ref
+    ///
+    /// A repository without any issues, not even the meta refs, has nothing to sync.
+    pub fn sync_refs(&self, specific_issues: Option<&[IssueId]>) -> StorageResult<Vec<String>> {
+        let Some(issue_ids) = specific_issues else {
+            let mut refs = self.list_issue_refs()?;
+            refs.extend(self.list_meta_refs()?);
+            return Ok(refs);
+        };
+
+        let mut refs = Vec::new();
+        for &issue_id in issue_ids {
+            let ref_name = self.repo.issue_ref_name(issue_id);
+            if !self.ref_exists(&ref_name)? {
+                return Err(StorageError::IssueNotFound { issue_id });
+            }
+            refs.push(ref_name);
+        }
+        Ok(refs)
+    }
+}
+
+/// Titles are stored without surrounding whitespace so that equal titles compare equal
+fn normalize_title(title: &str) -> String {
+    title.trim().to_string()
+}
+
+/// Serialize an event for storage, tagged with the current schema version
+fn encode_event(event: &IssueEvent) -> StorageResult<String> {
+    let mut value = serde_json::to_value(event).map_err(StorageError::Serialization)?;
+    if let serde_json::Value::Object(map) = &mut value {
+        map.insert(
+            "schema_version".to_string(),
+            serde_json::Value::from(EVENT_SCHEMA_VERSION),
+        );
+    }
+    serde_json::to_string(&value).map_err(StorageError::Serialization)
+}
+
+/// Deserialize a stored event
+///
+/// Events without a `schema_version` predate versioning. Returns `Ok(None)` for
+/// events this version does not know about, unless `strict` is set.
+fn decode_event(bytes: &[u8], strict: bool) -> StorageResult<Option<IssueEvent>> {
+    let mut value: serde_json::Value =
+        serde_json::from_slice(bytes).map_err(StorageError::Serialization)?;
+    let schema_version = value
+        .as_object_mut()
+        .and_then(|map| map.remove("schema_version"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    // An event is an object keyed by its type; a known type that fails to parse is an error
+    let unknown_kind = value
+        .as_object()
+        .and_then(|map| map.keys().next())
+        .is_some_and(|kind| !IssueEvent::KINDS.contains(&kind.as_str()));
+
+    match serde_json::from_value(value) {
+        Ok(event) => Ok(Some(event)),
+        Err(_) if !strict && (schema_version > u64::from(EVENT_SCHEMA_VERSION) || unknown_kind) => {
+            Ok(None)
+        }
+        Err(err) => Err(StorageError::Serialization(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::search::SearchPattern;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
 
     fn setup_temp_store() -> (TempDir, IssueStore) {
         let temp_dir = TempDir::new().expect("Failed to create temporary directory");
@@ -822,4 +1733,881 @@ mod tests {
         let path = store.path();
         assert!(path.exists(), "Store path should exist");
     }
+
+    /// Append a raw event.json blob to an issue's chain, bypassing `IssueEvent`
+    fn append_raw_event(store: &mut IssueStore, issue_id: IssueId, json: &str) {
+        let parent = store
+            .get_issue_head_commit(issue_id)
+            .expect("Issue should exist");
+        let blob_oid = store
+            .repo
+            .write_blob(json.as_bytes())
+            .expect("Failed to write blob");
+        let tree_oid = store
+            .repo
+            .write_tree(vec![TreeEntry {
+                name: "event.json".to_string(),
+                oid: blob_oid,
+                mode: 0o100644,
+            }])
+            .expect("Failed to write tree");
+        let commit_oid = store
+            .repo
+            .write_commit(tree_oid, vec![parent], &create_test_identity(), "Future")
+            .expect("Failed to write commit");
+        let ref_name = store.repo.issue_ref_name(issue_id);
+        store
+            .repo
+            .update_ref(&ref_name, commit_oid, Some(parent))
+            .expect("Failed to update ref");
+    }
+
+    #[test]
+    fn test_events_are_written_with_schema_version() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), create_test_identity())
+            .expect("Failed to create issue");
+
+        let head = store.get_issue_head_commit(issue_id).unwrap();
+        let commit = store.read_commit(head).unwrap();
+        let tree = store.repo.read_tree(commit.tree.parse().unwrap()).unwrap();
+        let blob = store.repo.read_blob(tree[0].oid).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&blob).unwrap();
+
+        assert_eq!(value["schema_version"], EVENT_SCHEMA_VERSION);
+        assert!(value["Created"].is_object());
+    }
+
+    #[test]
+    fn test_on_behalf_of_round_trips_through_events() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let bot = Identity::new("Triage Bot", "bot@example.com");
+        let alice = Identity::new("Alice", "alice@example.com");
+
+        store.set_on_behalf_of(Some(alice.clone()));
+        let issue_id = store
+            .create_issue("From a form".to_string(), "".to_string(), bot.clone())
+            .expect("Failed to create issue");
+        store.set_on_behalf_of(None);
+        store
+            .add_label(issue_id, "triaged".to_string(), bot.clone())
+            .expect("Failed to add label");
+
+        let events = store.get_issue_events(issue_id).unwrap();
+        assert_eq!(events[0].author(), &bot);
+        assert_eq!(events[0].on_behalf_of(), Some(&alice));
+        assert_eq!(events[1].on_behalf_of(), None);
+
+        // The commit is authored by the bot, not the person it acted for
+        let head = store.get_issue_head_commit(issue_id).unwrap();
+        let first = store.read_commit(head).unwrap().parents[0].parse().unwrap();
+        assert_eq!(store.read_commit(first).unwrap().author, bot);
+
+        let issue = store.get_issue(issue_id).unwrap();
+        assert_eq!(issue.created_by, bot);
+        assert_eq!(issue.created_on_behalf_of, Some(alice));
+    }
+
+    #[test]
+    fn test_unversioned_events_still_decode() {
+        let event = IssueEvent::created("Old".to_string(), "".to_string(), create_test_identity());
+        let json = serde_json::to_vec(&event).unwrap();
+
+        assert_eq!(decode_event(&json, true).unwrap(), Some(event));
+    }
+
+    #[test]
+    fn test_unknown_future_event_is_skipped() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        append_raw_event(
+            &mut store,
+            issue_id,
+            r#"{"MilestoneSet":{"milestone":"v2","author":{"name":"Test User","email":"test@example.com"},"timestamp":"2030-01-01T00:00:00Z"},"schema_version":99}"#,
+        );
+        store
+            .update_issue_status(issue_id, IssueStatus::Done, author)
+            .expect("Should append after unknown event");
+
+        let events = store
+            .get_issue_events(issue_id)
+            .expect("Should skip unknown event");
+        assert_eq!(events.len(), 2);
+
+        let issue = store.get_issue(issue_id).expect("Should reconstruct issue");
+        assert_eq!(issue.status, IssueStatus::Done);
+        assert_eq!(store.list_issues().expect("Should list issues").len(), 1);
+
+        store.set_strict(true);
+        assert!(store.get_issue_events(issue_id).is_err());
+    }
+
+    #[test]
+    fn test_malformed_known_event_is_an_error() {
+        let json = br#"{"Created":{"title":42},"schema_version":1}"#;
+        assert!(decode_event(json, false).is_err());
+    }
+
+    #[test]
+    fn test_unknown_event_kind_is_skipped_at_the_current_version() {
+        let json = br#"{"MilestoneSet":{"milestone":"v2"},"schema_version":1}"#;
+        assert_eq!(decode_event(json, false).unwrap(), None);
+        assert!(decode_event(json, true).is_err());
+    }
+
+    #[test]
+    fn test_event_kinds_cover_every_event() {
+        let author = create_test_identity();
+        let events = [
+            IssueEvent::created("T".to_string(), "".to_string(), author.clone()),
+            IssueEvent::status_changed(IssueStatus::Todo, IssueStatus::Done, author.clone()),
+            IssueEvent::comment_added("1-1".to_string(), "Hi".to_string(), author.clone()),
+            IssueEvent::label_added("bug".to_string(), author.clone()),
+            IssueEvent::label_removed("bug".to_string(), author.clone()),
+            IssueEvent::title_changed("T".to_string(), "U".to_string(), author.clone()),
+            IssueEvent::assignee_changed(None, Some(author.clone()), author.clone()),
+            IssueEvent::assignees_changed(Vec::new(), vec![author.clone()], author.clone()),
+            IssueEvent::description_changed("".to_string(), "D".to_string(), author.clone()),
+            IssueEvent::priority_changed(Priority::Low, Priority::High, author.clone()),
+            IssueEvent::created_by_changed(author.clone(), author.clone(), author.clone()),
+            IssueEvent::field_set("severity".to_string(), None, author),
+        ];
+
+        // A new event type does not compile here until it is listed above and in KINDS
+        for event in &events {
+            match event {
+                IssueEvent::Created { .. }
+                | IssueEvent::StatusChanged { .. }
+                | IssueEvent::CommentAdded { .. }
+                | IssueEvent::LabelAdded { .. }
+                | IssueEvent::LabelRemoved { .. }
+                | IssueEvent::TitleChanged { .. }
+                | IssueEvent::AssigneeChanged { .. }
+                | IssueEvent::AssigneesChanged { .. }
+                | IssueEvent::DescriptionChanged { .. }
+                | IssueEvent::PriorityChanged { .. }
+                | IssueEvent::CreatedByChanged { .. }
+                | IssueEvent::FieldSet { .. } => {}
+            }
+        }
+
+        let kinds: Vec<String> = events
+            .iter()
+            .map(|event| {
+                let value = serde_json::to_value(event).unwrap();
+                value.as_object().unwrap().keys().next().unwrap().clone()
+            })
+            .collect();
+        assert_eq!(kinds, IssueEvent::KINDS);
+    }
+
+    #[test]
+    fn test_sync_journal_roundtrip() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        store
+            .create_issue("Title".to_string(), "".to_string(), create_test_identity())
+            .expect("Failed to create issue");
+
+        assert!(store.sync_journal().unwrap().is_empty());
+
+        let entry = SyncJournalEntry {
+            remote: "origin".to_string(),
+            ref_name: "refs/git-issue/issues/1".to_string(),
+            oid: "abc".to_string(),
+        };
+        store.append_sync_journal(entry.clone()).unwrap();
+        store.append_sync_journal(entry.clone()).unwrap();
+        assert_eq!(
+            store.sync_journal().unwrap(),
+            vec![entry.clone(), entry.clone()]
+        );
+
+        // The journal itself is never synced
+        let meta_refs = store.list_meta_refs().unwrap();
+        assert!(!meta_refs.iter().any(|r| r == SYNC_JOURNAL_REF));
+
+        // Clearing one remote keeps the entries of others
+        let backup_entry = SyncJournalEntry {
+            remote: "backup".to_string(),
+            ..entry
+        };
+        store.append_sync_journal(backup_entry.clone()).unwrap();
+        store.clear_sync_journal("origin").unwrap();
+        assert_eq!(store.sync_journal().unwrap(), vec![backup_entry]);
+
+        store.clear_sync_journal("backup").unwrap();
+        assert!(store.sync_journal().unwrap().is_empty());
+        assert!(!store.ref_exists(SYNC_JOURNAL_REF).unwrap());
+    }
+
+    #[test]
+    fn test_update_assignee_same_email_different_case_is_noop() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        store
+            .update_assignee(
+                issue_id,
+                Some(Identity::new("User", "User@Example.com")),
+                author.clone(),
+            )
+            .expect("Failed to assign");
+        store
+            .update_assignee(
+                issue_id,
+                Some(Identity::new("", "user@example.com")),
+                author,
+            )
+            .expect("Failed to reassign");
+
+        let events = store.get_issue_events(issue_id).unwrap();
+        assert_eq!(
+            events.len(),
+            2,
+            "Re-assigning with different casing is a no-op"
+        );
+
+        let issue = store.get_issue(issue_id).unwrap();
+        assert_eq!(issue.assignees[0].email, "User@Example.com");
+    }
+
+    #[test]
+    fn test_issue_participants() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let other = Identity::new("Other", "other@example.com");
+
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .add_label(issue_id, "bug".to_string(), other.clone())
+            .expect("Failed to add label");
+        store
+            .update_issue_status(issue_id, IssueStatus::Done, author.clone())
+            .expect("Failed to update status");
+
+        let participants = store.issue_participants(issue_id).unwrap();
+        assert_eq!(participants, vec![author, other]);
+    }
+
+    #[test]
+    fn test_create_issue_with_id() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+
+        store
+            .create_issue_with_id(
+                42,
+                "Imported".to_string(),
+                "".to_string(),
+                author.clone(),
+                false,
+            )
+            .expect("Failed to create issue at id");
+        assert_eq!(store.get_issue(42).unwrap().title, "Imported");
+
+        // The counter moves past the explicit id
+        let next_id = store
+            .create_issue("Next".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        assert_eq!(next_id, 43);
+
+        // Creating below the counter does not move it backwards
+        store
+            .create_issue_with_id(
+                7,
+                "Seven".to_string(),
+                "".to_string(),
+                author.clone(),
+                false,
+            )
+            .expect("Failed to create issue at id");
+        let next_id = store
+            .create_issue("After".to_string(), "".to_string(), author)
+            .expect("Failed to create issue");
+        assert_eq!(next_id, 44);
+    }
+
+    #[test]
+    fn test_create_issue_with_id_occupied() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Original".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        let result = store.create_issue_with_id(
+            issue_id,
+            "Other".to_string(),
+            "".to_string(),
+            author.clone(),
+            false,
+        );
+        assert!(matches!(
+            result,
+            Err(StorageError::IssueAlreadyExists { issue_id: 1 })
+        ));
+        assert_eq!(store.get_issue(issue_id).unwrap().title, "Original");
+
+        store
+            .create_issue_with_id(
+                issue_id,
+                "Replaced".to_string(),
+                "".to_string(),
+                author,
+                true,
+            )
+            .expect("Forced create should overwrite");
+        assert_eq!(store.get_issue(issue_id).unwrap().title, "Replaced");
+        assert_eq!(store.get_issue_events(issue_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_index_tracks_mutations() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .update_issue_status(issue_id, IssueStatus::InProgress, author.clone())
+            .expect("Failed to update status");
+        store
+            .add_label(issue_id, "bug".to_string(), author.clone())
+            .expect("Failed to add label");
+        store
+            .update_priority(issue_id, Priority::High, author)
+            .expect("Failed to update priority");
+
+        let index = store.read_index().expect("Should read index");
+        let entry = &index[&issue_id];
+        let head = store.get_issue_head_commit(issue_id).unwrap();
+        assert_eq!(entry.head_oid, head.to_string());
+        assert_eq!(entry.status, IssueStatus::InProgress);
+        assert_eq!(entry.priority, Priority::High);
+        assert_eq!(entry.labels, vec!["bug".to_string()]);
+
+        // The index is fresh, so refreshing it changes nothing
+        assert_eq!(store.refresh_index().unwrap(), index);
+
+        // The index is local state and is never synced
+        assert!(
+            !store
+                .list_meta_refs()
+                .unwrap()
+                .contains(&INDEX_REF.to_string())
+        );
+    }
+
+    #[test]
+    fn test_index_rebuilds_after_external_ref_change() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        // Another tool appends an event without touching the index
+        let event =
+            IssueEvent::status_changed(IssueStatus::Todo, IssueStatus::Done, author.clone());
+        append_raw_event(&mut store, issue_id, &encode_event(&event).unwrap());
+        assert_eq!(
+            store.read_index().unwrap()[&issue_id].status,
+            IssueStatus::Todo
+        );
+
+        let index = store.refresh_index().expect("Should refresh index");
+        let head = store.get_issue_head_commit(issue_id).unwrap();
+        assert_eq!(index[&issue_id].status, IssueStatus::Done);
+        assert_eq!(index[&issue_id].head_oid, head.to_string());
+        assert_eq!(store.read_index().unwrap(), index);
+
+        // Filtering goes through the rebuilt entry
+        let open = store
+            .list_issues_where(|entry| entry.status != IssueStatus::Done)
+            .expect("Should list issues");
+        assert!(open.is_empty());
+    }
+
+    #[test]
+    fn test_broken_index_does_not_fail_writes() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        // Point both index refs at a commit, so reading them as blobs fails
+        let head = store.get_issue_head_commit(issue_id).unwrap();
+        for ref_name in [INDEX_REF, SEARCH_INDEX_REF] {
+            let old = store.repo.read_ref(ref_name).unwrap().unwrap();
+            store.repo.update_ref(ref_name, head, Some(old)).unwrap();
+        }
+
+        store
+            .add_comment(issue_id, "Still stored".to_string(), author)
+            .expect("A failing index update should not fail the write");
+        let issue = store.get_issue(issue_id).unwrap();
+        assert_eq!(issue.comments.len(), 1);
+    }
+
+    #[test]
+    fn test_search_index_follows_appended_and_external_events() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let crash = store
+            .create_issue("Crash on start".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        let docs = store
+            .create_issue("Update docs".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .add_comment(
+                docs,
+                "Mention the crash reporter".to_string(),
+                author.clone(),
+            )
+            .unwrap();
+
+        // Appended events are indexed as they are written
+        let index = store.read_search_index().unwrap();
+        let head = store.get_issue_head_commit(docs).unwrap();
+        assert_eq!(index.head(docs), Some(head.to_string().as_str()));
+        let candidates = |index: &SearchIndex, query| index.candidates(&SearchPattern::text(query));
+        assert_eq!(candidates(&index, "crash"), Some([crash, docs].into()));
+        assert_eq!(candidates(&index, "ash on st"), Some([crash].into()));
+        assert_eq!(candidates(&index, "reporter"), Some([docs].into()));
+
+        // Another tool appends an event without touching the search index
+        let event = IssueEvent::comment_added(
+            "1-1".to_string(),
+            "Stack overflow".to_string(),
+            author.clone(),
+        );
+        append_raw_event(&mut store, crash, &encode_event(&event).unwrap());
+        assert_eq!(
+            candidates(&store.read_search_index().unwrap(), "overflow"),
+            Some([].into())
+        );
+
+        let index = store
+            .refresh_search_index()
+            .expect("Should refresh search index");
+        assert_eq!(candidates(&index, "overflow"), Some([crash].into()));
+        assert_eq!(store.read_search_index().unwrap(), index);
+
+        let query = SearchQuery::new(SearchPattern::text("OVERFLOW"));
+        let found = store.search(&query).expect("Should search");
+        assert_eq!(
+            found.iter().map(|issue| issue.id).collect::<Vec<_>>(),
+            vec![crash]
+        );
+
+        // A rewritten history is reindexed from scratch
+        store
+            .create_issue_with_id(
+                crash,
+                "Slow start".to_string(),
+                "".to_string(),
+                author,
+                true,
+            )
+            .unwrap();
+        let index = store
+            .refresh_search_index()
+            .expect("Should refresh search index");
+        assert_eq!(candidates(&index, "overflow"), Some([].into()));
+        assert_eq!(candidates(&index, "slow"), Some([crash].into()));
+
+        // The search index is local state and is never synced
+        assert!(
+            !store
+                .list_meta_refs()
+                .unwrap()
+                .contains(&SEARCH_INDEX_REF.to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_ref_by_id_and_slug() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let first = store
+            .create_issue("Fix login bug".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        let second = store
+            .create_issue("Fix login bug!".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        assert_eq!(store.resolve_ref("1").unwrap(), first);
+        assert_eq!(store.resolve_ref("#2").unwrap(), second);
+        assert_eq!(store.resolve_ref("fix-login-bug").unwrap(), first);
+        assert_eq!(store.resolve_ref("Fix-Login-Bug-2").unwrap(), second);
+        assert!(matches!(
+            store.resolve_ref("no-such-issue"),
+            Err(StorageError::UnknownIssueRef { .. })
+        ));
+
+        // Slugs follow the creation title, so renaming keeps them stable
+        store
+            .update_title(first, "Login works again".to_string(), author)
+            .expect("Failed to update title");
+        assert_eq!(store.resolve_ref("fix-login-bug").unwrap(), first);
+    }
+
+    #[test]
+    fn test_slugs_survive_index_rebuild() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        for _ in 0..3 {
+            store
+                .create_issue("Same title".to_string(), "".to_string(), author.clone())
+                .expect("Failed to create issue");
+        }
+        let before = store.refresh_index().unwrap();
+
+        store.repo.delete_ref(INDEX_REF).unwrap();
+        let rebuilt = store.refresh_index().unwrap();
+
+        let slugs: Vec<_> = rebuilt.values().map(|entry| entry.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["same-title", "same-title-2", "same-title-3"]);
+        assert_eq!(rebuilt, before);
+    }
+
+    #[test]
+    fn test_reads_do_not_write_the_indexes() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Fix login bug".to_string(), "".to_string(), author)
+            .expect("Failed to create issue");
+        store.repo.delete_ref(INDEX_REF).unwrap();
+        store.repo.delete_ref(SEARCH_INDEX_REF).unwrap();
+
+        assert_eq!(store.resolve_ref("fix-login-bug").unwrap(), issue_id);
+        assert_eq!(store.list_issues_where(|_| true).unwrap().len(), 1);
+        let query = SearchQuery::new(SearchPattern::text("login"));
+        assert_eq!(store.search(&query).unwrap().len(), 1);
+
+        assert_eq!(store.repo.read_ref(INDEX_REF).unwrap(), None);
+        assert_eq!(store.repo.read_ref(SEARCH_INDEX_REF).unwrap(), None);
+    }
+
+    #[test]
+    fn test_numeric_titles_get_slugs_that_are_not_ids() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let first = store
+            .create_issue("First".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        let numeric = store
+            .create_issue("1".to_string(), "".to_string(), author)
+            .expect("Failed to create issue");
+
+        assert_eq!(store.read_index().unwrap()[&numeric].slug, "issue-1");
+        assert_eq!(store.resolve_ref("1").unwrap(), first);
+        assert_eq!(store.resolve_ref("issue-1").unwrap(), numeric);
+    }
+
+    #[test]
+    fn test_cache_refs_are_not_written_while_another_process_holds_the_lock() {
+        let (temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        store
+            .create_issue("Title".to_string(), "".to_string(), author)
+            .expect("Failed to create issue");
+        store.repo.delete_ref(INDEX_REF).unwrap();
+
+        let mut other = IssueStore::open(temp_dir.path()).expect("Should open store");
+        let lock = other.lock_for_writing().expect("Should take the lock");
+        assert_eq!(store.refresh_index().unwrap().len(), 1);
+        assert_eq!(store.repo.read_ref(INDEX_REF).unwrap(), None);
+
+        // The lock holder still writes its own caches
+        other.refresh_index().unwrap();
+        assert!(store.repo.read_ref(INDEX_REF).unwrap().is_some());
+
+        drop(lock);
+        store.repo.delete_ref(INDEX_REF).unwrap();
+        store.refresh_index().unwrap();
+        assert!(store.repo.read_ref(INDEX_REF).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_symbolic_issue_ref_is_followed() {
+        let (temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        for title in ["First", "Second"] {
+            store
+                .create_issue(title.to_string(), "".to_string(), author.clone())
+                .expect("Failed to create issue");
+        }
+
+        // Move issue #2's head elsewhere and leave a hand-made symbolic ref behind
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(temp_dir.path())
+                .status()
+                .expect("Failed to run git");
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&[
+            "update-ref",
+            "refs/heads/issue-2",
+            "refs/git-issue/issues/2",
+        ]);
+        git(&["update-ref", "-d", "refs/git-issue/issues/2"]);
+        git(&[
+            "symbolic-ref",
+            "refs/git-issue/issues/2",
+            "refs/heads/issue-2",
+        ]);
+
+        let issue = store.get_issue(2).expect("Symbolic issue ref should load");
+        assert_eq!(issue.title, "Second");
+        let ids: Vec<_> = store
+            .list_issues()
+            .expect("Failed to list issues")
+            .iter()
+            .map(|issue| issue.id)
+            .collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_events_since_returns_only_newer_events() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        let (old_head, _) = store
+            .get_issue_events_with_oids(issue_id)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        store
+            .add_label(issue_id, "bug".to_string(), author.clone())
+            .unwrap();
+        store
+            .update_priority(issue_id, Priority::High, author)
+            .unwrap();
+
+        let events = store.events_since(issue_id, old_head).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0].1, IssueEvent::LabelAdded { .. }));
+        assert!(matches!(events[1].1, IssueEvent::PriorityChanged { .. }));
+
+        let head = events[1].0;
+        assert!(store.events_since(issue_id, head).unwrap().is_empty());
+
+        let unrelated = store.read_ref(INDEX_REF).unwrap().unwrap();
+        assert!(store.events_since(issue_id, unrelated).is_err());
+    }
+
+    #[test]
+    fn test_single_assignee_compat_events() {
+        let (temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        let status = std::process::Command::new("git")
+            .args(["config", "compat.singleAssignee", "true"])
+            .current_dir(temp_dir.path())
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+        let mut store = IssueStore::open(temp_dir.path()).expect("Failed to open store");
+
+        let alice = Identity::new("", "alice@example.com");
+        store
+            .update_assignees(issue_id, vec![alice.clone()], author.clone())
+            .unwrap();
+
+        let events = store.get_issue_events(issue_id).unwrap();
+        assert!(matches!(
+            &events[1],
+            IssueEvent::AssigneesChanged { new_assignees, .. } if new_assignees == &vec![alice.clone()]
+        ));
+        assert!(matches!(
+            &events[2],
+            IssueEvent::AssigneeChanged { new_assignee: Some(new), .. } if new == &alice
+        ));
+        assert_eq!(
+            store.get_issue(issue_id).unwrap().assignees,
+            vec![alice.clone()]
+        );
+
+        // Several assignees cannot be expressed with the singular event
+        let bob = Identity::new("", "bob@example.com");
+        store
+            .update_assignees(issue_id, vec![alice.clone(), bob.clone()], author)
+            .unwrap();
+        assert_eq!(store.get_issue_events(issue_id).unwrap().len(), 4);
+        assert_eq!(
+            store.get_issue(issue_id).unwrap().assignees,
+            vec![alice, bob]
+        );
+    }
+
+    #[test]
+    fn test_latest_activity_orders_issues_by_time() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let first = store
+            .create_issue("First".to_string(), "".to_string(), author.clone())
+            .unwrap();
+        let second = store
+            .create_issue("Second".to_string(), "".to_string(), author.clone())
+            .unwrap();
+        store.add_label(first, "bug".to_string(), author).unwrap();
+
+        let activity = store.latest_activity().unwrap();
+        let ids: Vec<_> = activity.iter().map(|(issue_id, _)| *issue_id).collect();
+        assert_eq!(ids, vec![second, first]);
+        assert!(matches!(activity[0].1, IssueEvent::Created { .. }));
+        assert_eq!(activity[1].1.summary(), "LabelAdded: bug");
+    }
+
+    #[test]
+    fn test_oversized_event_is_rejected() {
+        let (temp_dir, _store) = setup_temp_store();
+        let status = std::process::Command::new("git")
+            .args(["config", "storage.maxEventSize", "1k"])
+            .current_dir(temp_dir.path())
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+        let mut store = IssueStore::open(temp_dir.path()).expect("Failed to open store");
+        let author = create_test_identity();
+
+        let issue_id = store
+            .create_issue("Title".to_string(), "a".repeat(512), author.clone())
+            .expect("An event under the limit should be stored");
+
+        let result = store.add_comment(issue_id, "a".repeat(2048), author);
+        assert!(matches!(
+            result,
+            Err(StorageError::EventTooLarge { limit: 1024, .. })
+        ));
+        assert!(store.get_issue(issue_id).unwrap().comments.is_empty());
+    }
+
+    #[test]
+    fn test_restore_with_an_oversized_event_writes_nothing() {
+        let (_source_dir, mut source) = setup_temp_store();
+        let author = create_test_identity();
+        source
+            .create_issue("Small".to_string(), "".to_string(), author.clone())
+            .unwrap();
+        source
+            .create_issue("Large".to_string(), "a".repeat(2048), author)
+            .unwrap();
+        let dumps = source.dump_issues().unwrap();
+
+        let (temp_dir, _store) = setup_temp_store();
+        let status = std::process::Command::new("git")
+            .args(["config", "storage.maxEventSize", "1k"])
+            .current_dir(temp_dir.path())
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+        let mut store = IssueStore::open(temp_dir.path()).expect("Failed to open store");
+
+        let result = store.restore_issues(&dumps);
+        assert!(matches!(
+            result,
+            Err(StorageError::EventTooLarge { limit: 1024, .. })
+        ));
+        assert!(!store.issue_exists(1).unwrap());
+    }
+
+    #[test]
+    fn test_mutations_reconstruct_the_issue_once() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+
+        let (cache, counts) = CountingCache::new(None);
+        store.set_cache(Some(cache));
+        store
+            .update_issue_status(issue_id, IssueStatus::InProgress, author.clone())
+            .unwrap();
+        store
+            .add_label(issue_id, "bug".to_string(), author.clone())
+            .unwrap();
+        assert_eq!(counts.loads.get(), 2);
+
+        let (issue, head) = store.get_issue_with_head(issue_id).unwrap();
+        assert_eq!(issue.status, IssueStatus::InProgress);
+        assert_eq!(issue.labels, vec!["bug".to_string()]);
+        assert_eq!(head, store.get_issue_head_commit(issue_id).unwrap());
+    }
+
+    #[test]
+    fn test_titles_are_stored_trimmed() {
+        let (_temp_dir, mut store) = setup_temp_store();
+        let author = create_test_identity();
+        let issue_id = store
+            .create_issue("  Fix bug \n".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        assert_eq!(store.get_issue(issue_id).unwrap().title, "Fix bug");
+
+        store
+            .update_title(issue_id, "Fix bug  ".to_string(), author)
+            .unwrap();
+        assert_eq!(store.get_issue_events(issue_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_commit_message_template() {
+        let (temp_dir, _store) = setup_temp_store();
+        let status = std::process::Command::new("git")
+            .args([
+                "config",
+                "issue.commitMessage",
+                "[issue-{id}] {summary}\n\nIssue: {id}",
+            ])
+            .current_dir(temp_dir.path())
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+        let mut store = IssueStore::open(temp_dir.path()).expect("Failed to open store");
+        let author = create_test_identity();
+
+        let issue_id = store
+            .create_issue("Title".to_string(), "".to_string(), author.clone())
+            .expect("Failed to create issue");
+        store
+            .update_issue_status(issue_id, IssueStatus::Done, author)
+            .unwrap();
+
+        let head = store.get_issue_head_commit(issue_id).unwrap();
+        let commit = store.read_commit(head).unwrap();
+        assert_eq!(
+            commit.message.trim_end(),
+            format!(
+                "[issue-{}] StatusChanged: {} → {}\n\nIssue: {}",
+                issue_id,
+                IssueStatus::Todo,
+                IssueStatus::Done,
+                issue_id
+            )
+        );
+    }
 }
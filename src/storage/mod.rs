@@ -1,14 +1,23 @@
+pub mod cache;
+pub mod contributors;
 pub mod errors;
+pub mod index;
 pub mod issue_store;
+pub mod project_config;
 pub mod repo;
+pub mod search;
+pub mod snapshot;
 
 pub use issue_store::IssueStore;
 
 #[cfg(test)]
 pub mod test_helpers {
+    use super::cache::IssueCache;
     use super::repo::GitRepository;
-    use crate::common::Identity;
+    use crate::common::{Identity, Issue, IssueId};
+    use std::cell::Cell;
     use std::path::Path;
+    use std::rc::Rc;
     use tempfile::TempDir;
 
     /// Creates a temporary directory with an initialized Git repository and git-issue
@@ -24,6 +33,59 @@ pub mod test_helpers {
         Identity::new("Test User", "test@example.com")
     }
 
+    /// How often a `CountingCache` was asked for an issue, and how often it had it
+    #[derive(Default)]
+    pub struct CacheCounts {
+        pub loads: Cell<usize>,
+        pub hits: Cell<usize>,
+    }
+
+    /// An `IssueCache` that counts lookups, passing them on to `inner` if given
+    ///
+    /// Every reconstruction of an issue starts with a lookup, so the counts show
+    /// how often an issue was rebuilt and whether its events were read at all.
+    pub struct CountingCache {
+        inner: Option<Box<dyn IssueCache>>,
+        counts: Rc<CacheCounts>,
+    }
+
+    impl CountingCache {
+        pub fn new(inner: Option<Box<dyn IssueCache>>) -> (Box<Self>, Rc<CacheCounts>) {
+            let counts = Rc::new(CacheCounts::default());
+            let cache = CountingCache {
+                inner,
+                counts: Rc::clone(&counts),
+            };
+            (Box::new(cache), counts)
+        }
+    }
+
+    impl IssueCache for CountingCache {
+        fn load(
+            &self,
+            repo: &GitRepository,
+            issue_id: IssueId,
+            head: gix::ObjectId,
+        ) -> Option<Issue> {
+            self.counts.loads.set(self.counts.loads.get() + 1);
+            let issue = self.inner.as_ref()?.load(repo, issue_id, head)?;
+            self.counts.hits.set(self.counts.hits.get() + 1);
+            Some(issue)
+        }
+
+        fn save(
+            &self,
+            repo: &GitRepository,
+            issue_id: IssueId,
+            head: gix::ObjectId,
+            issue: &Issue,
+        ) {
+            if let Some(inner) = &self.inner {
+                inner.save(repo, issue_id, head, issue);
+            }
+        }
+    }
+
     /// Verifies that a git object exists in the repository
     #[allow(unused)]
     pub fn assert_git_object_exists(repo_path: &Path, oid: &gix::ObjectId) {
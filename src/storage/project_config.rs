@@ -0,0 +1,160 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Location of the project settings file, relative to the work tree
+pub const PROJECT_CONFIG_PATH: &str = ".git-issue/config.toml";
+
+/// Settings checked into the repository in `.git-issue/config.toml`
+///
+/// Tables map onto git config sections and hold string, integer and boolean
+/// values. Keys are flattened to git's dotted form, so
+///
+/// ```toml
+/// [status.todo]
+/// label = "Backlog"
+/// ```
+///
+/// is read back as `status.todo.label`. The `[user]` table is ignored: who
+/// wrote an event comes from the environment and git config, never the project.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectConfig {
+    values: BTreeMap<String, toml::Value>,
+}
+
+/// The file as written: every top-level key is a table of settings
+#[derive(Deserialize)]
+struct ProjectFile {
+    #[serde(flatten)]
+    tables: BTreeMap<String, toml::Table>,
+}
+
+impl ProjectConfig {
+    /// Load the project settings of the work tree at `workdir`
+    ///
+    /// A missing file yields empty settings; a malformed one is reported and ignored.
+    pub fn load(workdir: &Path) -> Self {
+        let path = workdir.join(PROJECT_CONFIG_PATH);
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        Self::parse(&text).unwrap_or_else(|err| {
+            log::warn!("Ignoring {}: {}", path.display(), err);
+            Self::default()
+        })
+    }
+
+    /// Parse settings from TOML text
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let file: ProjectFile = toml::from_str(text).map_err(|e| e.to_string())?;
+
+        let mut values = BTreeMap::new();
+        for (name, table) in file.tables {
+            if name.eq_ignore_ascii_case("user") {
+                log::warn!("Ignoring [user] in {}", PROJECT_CONFIG_PATH);
+                continue;
+            }
+            flatten(&name, table, &mut values)?;
+        }
+        Ok(Self { values })
+    }
+
+    /// Get a setting by its dotted key in git config's string form
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.value(key).map(|value| match value {
+            toml::Value::String(value) => value.clone(),
+            value => value.to_string(),
+        })
+    }
+
+    /// Get a boolean setting, or `None` if it is unset or not a boolean
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.value(key).and_then(toml::Value::as_bool)
+    }
+
+    /// Get an integer setting, or `None` if it is unset or not an integer
+    pub fn get_integer(&self, key: &str) -> Option<i64> {
+        self.value(key).and_then(toml::Value::as_integer)
+    }
+
+    /// Look a key up ignoring case, like git config does
+    fn value(&self, key: &str) -> Option<&toml::Value> {
+        self.values
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value)
+    }
+}
+
+/// Add the values of `table` under `prefix`, descending into subtables
+fn flatten(
+    prefix: &str,
+    table: toml::Table,
+    values: &mut BTreeMap<String, toml::Value>,
+) -> Result<(), String> {
+    for (key, value) in table {
+        let name = format!("{}.{}", prefix, key);
+        match value {
+            toml::Value::Table(table) => flatten(&name, table, values)?,
+            toml::Value::String(_) | toml::Value::Integer(_) | toml::Value::Boolean(_) => {
+                values.insert(name, value);
+            }
+            _ => return Err(format!("unsupported value for {}", name)),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flattens_tables_to_dotted_keys() {
+        let config = ProjectConfig::parse(
+            r#"
+# Project defaults
+[core]
+editor = "vim -c \"set ft=markdown # notes\""  # trailing comment
+
+[status.todo]
+label = 'Backlog'
+
+[storage]
+maxEventSize = 2048
+[labels]
+normalize = true
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.get("core.editor"),
+            Some("vim -c \"set ft=markdown # notes\"".to_string())
+        );
+        assert_eq!(config.get("STATUS.TODO.LABEL"), Some("Backlog".to_string()));
+        assert_eq!(config.get("storage.maxeventsize"), Some("2048".to_string()));
+        assert_eq!(config.get_integer("storage.maxEventSize"), Some(2048));
+        assert_eq!(config.get("labels.normalize"), Some("true".to_string()));
+        assert_eq!(config.get_bool("labels.normalize"), Some(true));
+        assert_eq!(config.get_bool("status.todo.label"), None);
+        assert_eq!(config.get("core.missing"), None);
+    }
+
+    #[test]
+    fn test_parse_ignores_identity() {
+        let config =
+            ProjectConfig::parse("[user]\nname = \"Ada\"\n\n[labels]\nnormalize = true\n").unwrap();
+        assert_eq!(config.get("user.name"), None);
+        assert_eq!(config.get_bool("labels.normalize"), Some(true));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_input() {
+        assert!(ProjectConfig::parse("name = \"x\"").is_err());
+        assert!(ProjectConfig::parse("[core\neditor = \"x\"").is_err());
+        assert!(ProjectConfig::parse("[core]\neditor").is_err());
+        assert!(ProjectConfig::parse("[core]\neditor = [1, 2]").is_err());
+    }
+}
@@ -1,11 +1,28 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use std::io::Write as _;
 use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use super::errors::{GitError, GitResult};
 use crate::common::Identity;
 use gix::prelude::{FindExt, Write};
 
+/// How long a writer waits for another git-issue process to release the write lock
+const WRITE_LOCK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How many symbolic references are followed before a ref is considered broken
+const MAX_SYMREF_DEPTH: usize = 5;
+
+/// Namespace under which all git-issue refs live
+pub const REFS_NAMESPACE: &str = "refs/git-issue";
+
+/// Namespace that older versions mistakenly wrote refs under
+pub const LEGACY_REFS_NAMESPACE: &str = "refs/git-tracker";
+
 /// A Git repository wrapper for git-issue's issue storage
 ///
 /// `GitRepository` provides a high-level interface for storing git-issue issues
@@ -42,6 +59,31 @@ use gix::prelude::{FindExt, Write};
 pub struct GitRepository {
     repo: gix::Repository,
     refs_namespace: String,
+    /// Whether this process holds the write lock, set while a `WriteLock` is alive
+    write_locked: Arc<AtomicBool>,
+}
+
+/// Object counts of a repository, as reported by `git count-objects`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ObjectCounts {
+    /// Objects stored one per file
+    pub loose: u64,
+    /// Objects stored in packs
+    pub packed: u64,
+    /// Number of pack files
+    pub packs: u64,
+}
+
+/// The advisory write lock taken by `GitRepository::lock_for_writing`, released when dropped
+pub struct WriteLock {
+    _marker: gix::lock::Marker,
+    held: Arc<AtomicBool>,
+}
+
+impl Drop for WriteLock {
+    fn drop(&mut self) {
+        self.held.store(false, Ordering::SeqCst);
+    }
 }
 
 /// Represents an entry in a Git tree object
@@ -115,7 +157,7 @@ pub struct TreeEntry {
 /// let commit = CommitData {
 ///     tree: "abc123...".to_string(),
 ///     parents: vec!["def456...".to_string()],
-///     author: Identity::new("Alice".to_string(), "alice@example.com".to_string()),
+///     author: Identity::new("Alice", "alice@example.com"),
 ///     message: "StatusChanged: todo → in-progress".to_string(),
 ///     timestamp: Utc::now(),
 /// };
@@ -129,6 +171,33 @@ pub struct CommitData {
     pub timestamp: DateTime<Utc>,
 }
 
+/// Messages git prints when a remote rejects or cannot obtain credentials
+const AUTH_FAILURE_MARKERS: [&str; 5] = [
+    "Authentication failed",
+    "could not read Username",
+    "could not read Password",
+    "Permission denied (publickey",
+    "Host key verification failed",
+];
+
+/// Turn a failed remote operation caused by missing or bad credentials into
+/// `GitError::AuthenticationFailed`, leaving other errors as they are
+fn authentication_error(remote_name: &str, err: GitError) -> GitError {
+    match &err {
+        GitError::OperationFailed { message, .. }
+            if AUTH_FAILURE_MARKERS
+                .iter()
+                .any(|marker| message.contains(marker)) =>
+        {
+            GitError::AuthenticationFailed {
+                remote: remote_name.to_string(),
+                message: message.clone(),
+            }
+        }
+        _ => err,
+    }
+}
+
 impl GitRepository {
     /// Open an existing git repository
     pub fn open<P: AsRef<Path>>(path: P) -> GitResult<Self> {
@@ -138,7 +207,8 @@ impl GitRepository {
 
         let git_repo = Self {
             repo,
-            refs_namespace: "refs/git-issue".to_string(),
+            refs_namespace: REFS_NAMESPACE.to_string(),
+            write_locked: Arc::default(),
         };
 
         Ok(git_repo)
@@ -150,7 +220,8 @@ impl GitRepository {
 
         let git_repo = Self {
             repo,
-            refs_namespace: "refs/git-issue".to_string(),
+            refs_namespace: REFS_NAMESPACE.to_string(),
+            write_locked: Arc::default(),
         };
 
         Ok(git_repo)
@@ -158,6 +229,10 @@ impl GitRepository {
 
     /// Write a blob object
     pub fn write_blob(&mut self, content: &[u8]) -> GitResult<gix::ObjectId> {
+        self.store_blob(content)
+    }
+
+    fn store_blob(&self, content: &[u8]) -> GitResult<gix::ObjectId> {
         let odb = self.repo.objects.clone();
         let blob = gix::objs::Blob {
             data: content.to_vec(),
@@ -191,7 +266,7 @@ impl GitRepository {
 
         for entry in entries {
             let tree_entry = gix::objs::tree::Entry {
-                mode: gix::object::tree::EntryMode::try_from(entry.mode as u32).map_err(|_| {
+                mode: gix::object::tree::EntryMode::try_from(entry.mode).map_err(|_| {
                     GitError::InvalidTreeStructure {
                         message: format!("Invalid file mode: {}", entry.mode),
                     }
@@ -292,7 +367,7 @@ impl GitRepository {
             &object.author.name.to_string(),
             &object.author.email.to_string(),
         );
-        let message = String::from_utf8_lossy(&object.message).to_string();
+        let message = String::from_utf8_lossy(object.message).to_string();
         // TODO: Parse the time from the git commit object properly
         let timestamp = Utc::now();
 
@@ -357,7 +432,7 @@ impl GitRepository {
         oid: gix::ObjectId,
         expected: Option<gix::ObjectId>,
     ) -> GitResult<()> {
-        use gix::refs::transaction::{Change, LogChange, PreviousValue, RefEdit};
+        use gix::refs::transaction::PreviousValue;
 
         let previous_value = match expected {
             Some(expected_oid) => {
@@ -365,6 +440,50 @@ impl GitRepository {
             }
             None => PreviousValue::Any,
         };
+        self.set_ref(name, oid, previous_value)
+    }
+
+    /// Point `name` at a new blob holding `content`, replacing whatever it pointed at
+    ///
+    /// Only meant for derived data such as caches, which may be overwritten freely.
+    pub fn write_blob_ref(&self, name: &str, content: &[u8]) -> GitResult<()> {
+        let oid = self.store_blob(content)?;
+        self.set_ref(name, oid, gix::refs::transaction::PreviousValue::Any)
+    }
+
+    /// Like `write_blob_ref`, but for caches that read-only commands also write
+    ///
+    /// The write happens under the write lock. If another process holds the lock
+    /// the write is skipped, as the cache is rebuilt on a later read anyway.
+    pub fn write_cache_ref(&self, name: &str, content: &[u8]) -> GitResult<()> {
+        if self.write_locked.load(Ordering::SeqCst) {
+            return self.write_blob_ref(name, content);
+        }
+
+        match gix::lock::Marker::acquire_to_hold_resource(
+            self.lock_resource(),
+            gix::lock::acquire::Fail::Immediately,
+            None,
+        ) {
+            Ok(_marker) => self.write_blob_ref(name, content),
+            Err(e) => {
+                log::debug!(
+                    "Not updating {} while the repository is locked: {}",
+                    name,
+                    e
+                );
+                Ok(())
+            }
+        }
+    }
+
+    fn set_ref(
+        &self,
+        name: &str,
+        oid: gix::ObjectId,
+        previous_value: gix::refs::transaction::PreviousValue,
+    ) -> GitResult<()> {
+        use gix::refs::transaction::{Change, LogChange, RefEdit};
 
         let edit = RefEdit {
             change: Change::Update {
@@ -405,16 +524,10 @@ impl GitRepository {
         Ok(())
     }
 
-    /// Read a reference
+    /// Read a reference, following symbolic references to the object they point at
     pub fn read_ref(&self, name: &str) -> GitResult<Option<gix::ObjectId>> {
         match self.repo.refs.find(name) {
-            Ok(reference) => {
-                if let Some(target_id) = reference.target.try_id() {
-                    Ok(Some(target_id.to_owned()))
-                } else {
-                    Ok(None)
-                }
-            }
+            Ok(reference) => self.resolve_target(name, reference.target),
             Err(gix::refs::file::find::existing::Error::NotFound { name: _ }) => Ok(None),
             Err(e) => Err(GitError::ReferenceReadFailed {
                 ref_name: name.to_string(),
@@ -423,8 +536,46 @@ impl GitRepository {
         }
     }
 
+    /// Follow a reference target through symbolic references to an object id
+    ///
+    /// Returns `None` for a dangling symbolic reference. `name` is only used in errors.
+    fn resolve_target(
+        &self,
+        name: &str,
+        mut target: gix::refs::Target,
+    ) -> GitResult<Option<gix::ObjectId>> {
+        for _ in 0..MAX_SYMREF_DEPTH {
+            let symbolic_name = match target {
+                gix::refs::Target::Object(oid) => return Ok(Some(oid)),
+                gix::refs::Target::Symbolic(symbolic_name) => symbolic_name,
+            };
+            match self
+                .repo
+                .refs
+                .find(symbolic_name.as_bstr().to_string().as_str())
+            {
+                Ok(reference) => target = reference.target,
+                Err(gix::refs::file::find::existing::Error::NotFound { name: _ }) => {
+                    return Ok(None);
+                }
+                Err(e) => {
+                    return Err(GitError::ReferenceReadFailed {
+                        ref_name: name.to_string(),
+                        message: e.to_string(),
+                    });
+                }
+            }
+        }
+        Err(GitError::ReferenceReadFailed {
+            ref_name: name.to_string(),
+            message: format!(
+                "symbolic reference chain is longer than {}",
+                MAX_SYMREF_DEPTH
+            ),
+        })
+    }
+
     /// Delete a reference
-    #[allow(unused)]
     pub fn delete_ref(&mut self, name: &str) -> GitResult<()> {
         use gix::refs::transaction::{Change, PreviousValue, RefEdit};
 
@@ -466,7 +617,9 @@ impl GitRepository {
         Ok(())
     }
 
-    /// List references with a prefix
+    /// List references with a prefix, sorted by name
+    ///
+    /// The order does not depend on how the refs are stored (loose or packed).
     pub fn list_refs(&self, prefix: &str) -> GitResult<Vec<(String, gix::ObjectId)>> {
         let mut refs = Vec::new();
 
@@ -486,7 +639,6 @@ impl GitRepository {
                 ref_name: prefix.to_string(),
                 message: e.to_string(),
             })?
-            .into_iter()
             .collect::<Result<Vec<_>, _>>()
             .map_err(|e| GitError::ReferenceReadFailed {
                 ref_name: prefix.to_string(),
@@ -497,36 +649,26 @@ impl GitRepository {
             let ref_name = reference.name.as_bstr().to_string();
 
             // Filter by prefix
-            if ref_name.starts_with(prefix) {
-                if let Some(target_id) = reference.target.try_id() {
-                    refs.push((ref_name, target_id.to_owned()));
-                }
+            if ref_name.starts_with(prefix)
+                && let Some(target_id) = self.resolve_target(&ref_name, reference.target)?
+            {
+                refs.push((ref_name, target_id));
             }
         }
 
+        refs.sort_by(|a, b| a.0.cmp(&b.0));
         Ok(refs)
     }
 
+    /// Name of the meta ref holding the next issue ID
+    pub fn next_issue_id_ref(&self) -> String {
+        format!("{}/meta/next-issue-id", self.refs_namespace)
+    }
+
     /// Get the next issue ID
     pub fn get_next_issue_id(&self) -> GitResult<u64> {
-        let meta_ref = format!("{}/meta/next-issue-id", self.refs_namespace);
-
-        match self.read_ref(&meta_ref)? {
-            Some(oid) => {
-                // Read the blob containing the next issue ID
-                let blob_data = self.read_blob(oid)?;
-                let id_str =
-                    String::from_utf8(blob_data).map_err(|e| GitError::InvalidObjectData {
-                        message: format!("Invalid UTF-8 in issue ID blob: {}", e),
-                    })?;
-                let id = id_str
-                    .trim()
-                    .parse::<u64>()
-                    .map_err(|e| GitError::InvalidObjectData {
-                        message: format!("Invalid issue ID format: {}", e),
-                    })?;
-                Ok(id)
-            }
+        match self.read_ref(&self.next_issue_id_ref())? {
+            Some(oid) => self.read_issue_id_blob(oid),
             None => {
                 // No meta ref exists, start from 1
                 Ok(1)
@@ -534,17 +676,76 @@ impl GitRepository {
         }
     }
 
+    /// Get the next issue ID of a remote and the object ID its counter points
+    /// at, or `None` if it has no counter yet
+    ///
+    /// The counter blob is fetched without touching any local ref.
+    pub fn remote_next_issue_id(&self, remote_name: &str) -> GitResult<Option<(u64, String)>> {
+        let meta_ref = self.next_issue_id_ref();
+        let listing = self
+            .run_git_output("ls-remote", ["ls-remote", remote_name, &meta_ref], None)
+            .map_err(|err| authentication_error(remote_name, err))?;
+        let Some(hex) = listing.split_whitespace().next() else {
+            return Ok(None);
+        };
+        let oid =
+            gix::ObjectId::from_hex(hex.as_bytes()).map_err(|e| GitError::InvalidObjectData {
+                message: format!("Invalid object ID '{}' from remote: {}", hex, e),
+            })?;
+
+        if !self.repo.has_object(oid) {
+            self.run_git(
+                "fetch",
+                [
+                    "fetch",
+                    "--quiet",
+                    "--no-write-fetch-head",
+                    remote_name,
+                    &meta_ref,
+                ],
+            )
+            .map_err(|err| authentication_error(remote_name, err))?;
+        }
+        Ok(Some((self.read_issue_id_blob(oid)?, hex.to_string())))
+    }
+
+    /// Parse the blob the issue ID counter points at
+    fn read_issue_id_blob(&self, oid: gix::ObjectId) -> GitResult<u64> {
+        let blob_data = self.read_blob(oid)?;
+        let id_str = String::from_utf8(blob_data).map_err(|e| GitError::InvalidObjectData {
+            message: format!("Invalid UTF-8 in issue ID blob: {}", e),
+        })?;
+        id_str
+            .trim()
+            .parse::<u64>()
+            .map_err(|e| GitError::InvalidObjectData {
+                message: format!("Invalid issue ID format: {}", e),
+            })
+    }
+
     /// Increment and return the next issue ID
     pub fn increment_issue_id(&mut self) -> GitResult<u64> {
         let current_id = self.get_next_issue_id()?;
-        let next_id = current_id + 1;
+        self.set_next_issue_id(current_id + 1)?;
+        Ok(current_id)
+    }
+
+    /// Make sure the counter will never hand out `issue_id` or anything below it
+    pub fn advance_issue_id_past(&mut self, issue_id: u64) -> GitResult<()> {
+        if self.get_next_issue_id()? <= issue_id {
+            self.set_next_issue_id(issue_id + 1)?;
+        }
+        Ok(())
+    }
 
+    /// Store the next issue ID in the meta reference
+    fn set_next_issue_id(&mut self, next_id: u64) -> GitResult<()> {
         // Store the next issue ID in a blob
         let next_id_bytes = next_id.to_string().into_bytes();
         let blob_oid = self.write_blob(&next_id_bytes)?;
 
         // Update the meta reference
-        let meta_ref = format!("{}/meta/next-issue-id", self.refs_namespace);
+        let meta_ref = self.next_issue_id_ref();
 
         match self.read_ref(&meta_ref)? {
             Some(old_oid) => {
@@ -557,7 +758,7 @@ impl GitRepository {
             }
         }
 
-        Ok(current_id)
+        Ok(())
     }
 
     /// Get the reference name for an issue
@@ -573,17 +774,31 @@ impl GitRepository {
             .map(|v| v.to_string())
     }
 
+    /// Get a boolean configuration value
+    pub fn get_config_bool(&self, key: &str) -> Option<bool> {
+        self.repo.config_snapshot().boolean(key)
+    }
+
+    /// Get an integer configuration value, honouring git's k/m/g suffixes
+    pub fn get_config_integer(&self, key: &str) -> Option<i64> {
+        self.repo.config_snapshot().integer(key)
+    }
+
     /// Get the repository path
-    #[allow(unused)]
     pub fn path(&self) -> &Path {
         self.repo.path()
     }
 
+    /// Get the work tree, or `None` for a bare repository
+    pub fn workdir(&self) -> Option<&Path> {
+        self.repo.workdir()
+    }
+
     /// Get default push remote using git's standard resolution
     pub fn get_default_push_remote(&self) -> GitResult<String> {
         // Try to get current branch first
-        if let Ok(Some(head_ref)) = self.repo.head_ref() {
-            if let Some(branch_name) =
+        if let Ok(Some(head_ref)) = self.repo.head_ref()
+            && let Some(branch_name) =
                 head_ref
                     .name()
                     .category_and_short_name()
@@ -594,23 +809,22 @@ impl GitRepository {
                             None
                         }
                     })
-            {
-                // 1. Check branch.<current-branch>.pushRemote
-                let push_remote_key = format!("branch.{}.pushRemote", branch_name);
-                if let Some(remote) = self.get_config(&push_remote_key) {
-                    return Ok(remote);
-                }
+        {
+            // 1. Check branch.<current-branch>.pushRemote
+            let push_remote_key = format!("branch.{}.pushRemote", branch_name);
+            if let Some(remote) = self.get_config(&push_remote_key) {
+                return Ok(remote);
+            }
 
-                // 2. Check remote.pushDefault
-                if let Some(remote) = self.get_config("remote.pushDefault") {
-                    return Ok(remote);
-                }
+            // 2. Check remote.pushDefault
+            if let Some(remote) = self.get_config("remote.pushDefault") {
+                return Ok(remote);
+            }
 
-                // 3. Check branch.<current-branch>.remote
-                let remote_key = format!("branch.{}.remote", branch_name);
-                if let Some(remote) = self.get_config(&remote_key) {
-                    return Ok(remote);
-                }
+            // 3. Check branch.<current-branch>.remote
+            let remote_key = format!("branch.{}.remote", branch_name);
+            if let Some(remote) = self.get_config(&remote_key) {
+                return Ok(remote);
             }
         }
 
@@ -625,65 +839,286 @@ impl GitRepository {
         Ok(self.get_config(&url_key).is_some())
     }
 
-    /// List all remotes
-    pub fn list_remotes(&self) -> GitResult<Vec<String>> {
-        let mut remotes = Vec::new();
-
-        // Get all config entries and filter for remote.*.url
-        let config = self.repo.config_snapshot();
-        // This is a simplified approach - in a full implementation,
-        // we'd iterate through all config keys to find remote.*.url patterns
-        for remote_name in &["origin", "upstream"] {
-            let url_key = format!("remote.{}.url", remote_name);
-            if config.string(&url_key).is_some() {
-                remotes.push(remote_name.to_string());
-            }
-        }
+    /// Take the advisory write lock at `.git/git-issue.lock`, released when dropped
+    ///
+    /// Waits with backoff while another process holds the lock.
+    pub fn lock_for_writing(&self) -> GitResult<WriteLock> {
+        let resource = self.lock_resource();
+        let marker = gix::lock::Marker::acquire_to_hold_resource(
+            &resource,
+            gix::lock::acquire::Fail::AfterDurationWithBackoff(WRITE_LOCK_TIMEOUT),
+            None,
+        )
+        .map_err(|e| GitError::RepositoryLocked {
+            message: format!(
+                "{} (if no other git-issue is running, remove {}.lock)",
+                e,
+                resource.display()
+            ),
+        })?;
 
-        Ok(remotes)
+        self.write_locked.store(true, Ordering::SeqCst);
+        Ok(WriteLock {
+            _marker: marker,
+            held: Arc::clone(&self.write_locked),
+        })
+    }
+
+    fn lock_resource(&self) -> std::path::PathBuf {
+        self.path().join("git-issue")
+    }
+
+    /// List all configured remotes
+    pub fn list_remotes(&self) -> GitResult<Vec<String>> {
+        Ok(self
+            .repo
+            .remote_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect())
     }
 
-    /// Fetch specific refs from a remote
+    /// Get the remote's OID for each of `refs` that it has
+    ///
+    /// Objects the repository lacks are fetched, so the local and remote refs can
+    /// be compared afterwards.
     pub fn fetch_refs_from_remote(
         &self,
-        _remote_name: &str,
-        _refs: &[String],
+        remote_name: &str,
+        refs: &[String],
     ) -> GitResult<std::collections::HashMap<String, String>> {
-        // Placeholder implementation - in a real implementation, this would:
-        // 1. Connect to the remote
-        // 2. Fetch the specified refs
-        // 3. Return a map of ref_name -> commit_oid
+        let pattern = format!("{}/*", self.refs_namespace);
+        let listing = self
+            .run_git_output("ls-remote", ["ls-remote", remote_name, &pattern], None)
+            .map_err(|err| authentication_error(remote_name, err))?;
+
+        let mut remote_refs = std::collections::HashMap::new();
+        let mut missing = Vec::new();
+        for line in listing.lines() {
+            let Some((hex, ref_name)) = line.split_once('\t') else {
+                continue;
+            };
+            if !refs.iter().any(|wanted| wanted == ref_name) {
+                continue;
+            }
+            let oid = gix::ObjectId::from_hex(hex.as_bytes()).map_err(|e| {
+                GitError::InvalidObjectData {
+                    message: format!("Invalid object ID '{}' from remote: {}", hex, e),
+                }
+            })?;
+            if !self.repo.has_object(oid) {
+                missing.push(ref_name.to_string());
+            }
+            remote_refs.insert(ref_name.to_string(), hex.to_string());
+        }
+
+        // Bring in the remote objects, without touching any local ref, so ancestry can be checked
+        if !missing.is_empty() {
+            let mut args = vec!["fetch", "--quiet", "--no-write-fetch-head", remote_name];
+            args.extend(missing.iter().map(String::as_str));
+            self.run_git("fetch", args)
+                .map_err(|err| authentication_error(remote_name, err))?;
+        }
 
-        // For now, return empty map (simulating no remote refs)
-        Ok(std::collections::HashMap::new())
+        Ok(remote_refs)
     }
 
     /// Push a ref to a remote with optional force
+    ///
+    /// This goes through the git CLI so that the user's credential helpers,
+    /// `url.*.insteadOf` rewrites and SSH config apply as they would for
+    /// `git push`.
     pub fn push_ref_to_remote(
         &self,
-        _remote_name: &str,
-        _ref_name: &str,
-        _force: bool,
+        remote_name: &str,
+        ref_name: &str,
+        force: bool,
     ) -> GitResult<()> {
-        // Placeholder implementation - in a real implementation, this would:
-        // 1. Connect to the remote
-        // 2. Push the ref with the specified force settings
-        // 3. Handle force-with-lease logic
+        let refspec = format!("{}{}:{}", if force { "+" } else { "" }, ref_name, ref_name);
+        self.run_git("push", ["push", "--quiet", remote_name, &refspec])
+            .map_err(|err| authentication_error(remote_name, err))
+    }
 
-        // For now, just return success
-        Ok(())
+    /// Force push a ref, but only if the remote ref is still at `expected_oid`
+    ///
+    /// With `expected_oid` of `None` the remote ref must not exist yet. A remote
+    /// ref that moved in the meantime fails with
+    /// `GitError::ConcurrentReferenceUpdate`.
+    pub fn push_ref_with_lease(
+        &self,
+        remote_name: &str,
+        ref_name: &str,
+        expected_oid: Option<&str>,
+    ) -> GitResult<()> {
+        let lease = format!(
+            "--force-with-lease={}:{}",
+            ref_name,
+            expected_oid.unwrap_or_default()
+        );
+        let refspec = format!("{}:{}", ref_name, ref_name);
+        self.run_git("push", ["push", "--quiet", &lease, remote_name, &refspec])
+            .map_err(|err| match err {
+                GitError::OperationFailed { message, .. } if message.contains("stale info") => {
+                    GitError::ConcurrentReferenceUpdate {
+                        ref_name: ref_name.to_string(),
+                    }
+                }
+                err => authentication_error(remote_name, err),
+            })
+    }
+
+    /// Write a git bundle containing `refs` and everything reachable from them
+    pub fn create_bundle(&self, output: &Path, refs: &[String]) -> GitResult<()> {
+        let mut args = vec![
+            OsStr::new("bundle"),
+            OsStr::new("create"),
+            output.as_os_str(),
+        ];
+        args.extend(refs.iter().map(OsStr::new));
+        self.run_git("bundle create", args)
+    }
+
+    /// Fetch refs from a git bundle or remote URL into this repository
+    ///
+    /// Refs are only fast-forwarded; a ref that has diverged from the source
+    /// makes the whole fetch fail.
+    pub fn fetch_from(&self, source: impl AsRef<OsStr>, refspec: &str) -> GitResult<()> {
+        self.run_git(
+            "fetch",
+            [
+                OsStr::new("fetch"),
+                OsStr::new("--quiet"),
+                // A source starting with '-' must not be read as an option
+                OsStr::new("--end-of-options"),
+                source.as_ref(),
+                OsStr::new(refspec),
+            ],
+        )
+    }
+
+    /// Count loose and packed objects
+    pub fn object_counts(&self) -> GitResult<ObjectCounts> {
+        let output = self.run_git_output("count-objects", ["count-objects", "-v"], None)?;
+
+        let mut counts = ObjectCounts::default();
+        for line in output.lines() {
+            let Some((key, value)) = line.split_once(": ") else {
+                continue;
+            };
+            let field = match key {
+                "count" => &mut counts.loose,
+                "in-pack" => &mut counts.packed,
+                "packs" => &mut counts.packs,
+                _ => continue,
+            };
+            *field = value.trim().parse().unwrap_or_default();
+        }
+        Ok(counts)
+    }
+
+    /// Pack the loose objects reachable from `refs` and remove them as loose objects
+    ///
+    /// Objects that are already packed are left where they are, so repeated
+    /// calls only pack what was written since.
+    pub fn repack_refs(&self, refs: &[String]) -> GitResult<()> {
+        let pack_base = self.path().join("objects").join("pack").join("pack");
+        let input = refs
+            .iter()
+            .map(|name| format!("{}\n", name))
+            .collect::<String>();
+        self.run_git_output(
+            "pack-objects",
+            [
+                OsStr::new("pack-objects"),
+                OsStr::new("--revs"),
+                OsStr::new("--incremental"),
+                OsStr::new("--quiet"),
+                pack_base.as_os_str(),
+            ],
+            Some(input.as_bytes()),
+        )?;
+        self.run_git("prune-packed", ["prune-packed", "--quiet"])
+    }
+
+    /// Run the git CLI against this repository for operations gix does not cover
+    fn run_git<I, S>(&self, operation: &str, args: I) -> GitResult<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.run_git_output(operation, args, None).map(|_| ())
     }
 
-    /// Compare local and remote refs to determine their relationship
-    pub fn compare_refs(&self, _local_oid: &str, _remote_oid: &str) -> GitResult<(u32, u32)> {
-        // Placeholder implementation - in a real implementation, this would:
-        // 1. Use git's merge-base to find common ancestor
-        // 2. Count commits unique to local (ahead count)
-        // 3. Count commits unique to remote (behind count)
-        // 4. Return (local_commits, remote_commits)
+    /// Run the git CLI with optional standard input, returning its standard output
+    fn run_git_output<I, S>(
+        &self,
+        operation: &str,
+        args: I,
+        input: Option<&[u8]>,
+    ) -> GitResult<String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let failed = |message: String| GitError::OperationFailed {
+            operation: operation.to_string(),
+            message,
+        };
+
+        // Fail instead of waiting on a credential prompt nobody can answer
+        let mut child = Command::new("git")
+            .arg("--git-dir")
+            .arg(self.path())
+            .args(args)
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(if input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| failed(e.to_string()))?;
+
+        if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+            stdin.write_all(input).map_err(|e| failed(e.to_string()))?;
+        }
+        let output = child
+            .wait_with_output()
+            .map_err(|e| failed(e.to_string()))?;
+
+        if !output.status.success() {
+            return Err(failed(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
 
-        // For now, return (1, 0) indicating local is 1 commit ahead
-        Ok((1, 0))
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Count the commits only in `local_oid` and only in `remote_oid`
+    ///
+    /// Returns `(local_commits, remote_commits)`; both objects must be commits
+    /// available locally.
+    pub fn compare_refs(&self, local_oid: &str, remote_oid: &str) -> GitResult<(u32, u32)> {
+        let range = format!("{}...{}", local_oid, remote_oid);
+        let output = self.run_git_output(
+            "rev-list",
+            ["rev-list", "--left-right", "--count", &range],
+            None,
+        )?;
+        let counts: Vec<u32> = output
+            .split_whitespace()
+            .filter_map(|count| count.parse().ok())
+            .collect();
+        match counts[..] {
+            [local_commits, remote_commits] => Ok((local_commits, remote_commits)),
+            _ => Err(GitError::OperationFailed {
+                operation: "rev-list".to_string(),
+                message: format!("Unexpected output '{}'", output.trim()),
+            }),
+        }
     }
 }
 
@@ -990,4 +1425,130 @@ mod tests {
         );
         assert_eq!(reconstructed_issue.created_by, author);
     }
+
+    #[test]
+    fn test_authentication_failures_are_explained() {
+        let denied = GitError::OperationFailed {
+            operation: "push".to_string(),
+            message: "fatal: could not read Username for 'https://example.com': \
+                      terminal prompts disabled"
+                .to_string(),
+        };
+        let err = authentication_error("origin", denied);
+        assert!(
+            matches!(&err, GitError::AuthenticationFailed { remote, .. } if remote == "origin")
+        );
+        assert!(
+            err.to_string()
+                .starts_with("authentication failed for origin; check your credential helper")
+        );
+
+        let rejected = GitError::OperationFailed {
+            operation: "push".to_string(),
+            message: "! [rejected] (non-fast-forward)".to_string(),
+        };
+        assert!(matches!(
+            authentication_error("origin", rejected),
+            GitError::OperationFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_push_ref_to_file_remote() {
+        let (temp_dir, mut repo) = setup_temp_repo();
+        let remote_dir = tempfile::TempDir::new().expect("Failed to create remote directory");
+        let status = std::process::Command::new("git")
+            .args(["init", "--bare", "--quiet"])
+            .current_dir(remote_dir.path())
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+        let url = format!("file://{}", remote_dir.path().display());
+        let status = std::process::Command::new("git")
+            .args(["remote", "add", "origin", &url])
+            .current_dir(temp_dir.path())
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+
+        let blob = repo.write_blob(b"pushed").unwrap();
+        repo.create_ref("refs/git-issue/test", blob).unwrap();
+        repo.push_ref_to_remote("origin", "refs/git-issue/test", false)
+            .expect("Push to a local remote should succeed");
+
+        let remote = GitRepository::open(remote_dir.path()).unwrap();
+        assert_eq!(remote.read_ref("refs/git-issue/test").unwrap(), Some(blob));
+    }
+
+    #[test]
+    fn test_list_refs_sorted_by_name() {
+        let (temp_dir, mut repo) = setup_temp_repo();
+        let oid = repo.write_blob(b"sorted").expect("Should write blob");
+
+        for name in ["refs/test/b", "refs/test/d"] {
+            repo.create_ref(name, oid).expect("Should create reference");
+        }
+        // Packed refs are iterated separately from loose ones
+        let status = Command::new("git")
+            .args(["pack-refs", "--all"])
+            .current_dir(temp_dir.path())
+            .status()
+            .expect("Failed to run git pack-refs");
+        assert!(status.success());
+        for name in ["refs/test/c", "refs/test/a", "refs/test/e"] {
+            repo.create_ref(name, oid).expect("Should create reference");
+        }
+
+        let names: Vec<String> = repo
+            .list_refs("refs/test/")
+            .expect("Should list references")
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "refs/test/a",
+                "refs/test/b",
+                "refs/test/c",
+                "refs/test/d",
+                "refs/test/e"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_refs_counts_commits_on_each_side() {
+        let (_temp_dir, mut repo) = setup_temp_repo();
+        let author = create_test_identity();
+        let event = IssueEvent::created("Title".to_string(), String::new(), author.clone());
+        let blob = repo
+            .write_blob(serde_json::to_string(&event).unwrap().as_bytes())
+            .unwrap();
+        let tree = repo
+            .write_tree(vec![TreeEntry {
+                name: "event.json".to_string(),
+                oid: blob,
+                mode: 0o100644,
+            }])
+            .unwrap();
+        let commit = |repo: &mut GitRepository, parents, message| {
+            repo.write_commit(tree, parents, &author, message).unwrap()
+        };
+        let base = commit(&mut repo, vec![], "base");
+        let local = commit(&mut repo, vec![base], "local");
+        let remote = commit(&mut repo, vec![base], "remote");
+        let remote_next = commit(&mut repo, vec![remote], "remote next");
+
+        let oid = |oid: gix::ObjectId| oid.to_string();
+        assert_eq!(repo.compare_refs(&oid(local), &oid(base)).unwrap(), (1, 0));
+        assert_eq!(
+            repo.compare_refs(&oid(base), &oid(remote_next)).unwrap(),
+            (0, 2)
+        );
+        assert_eq!(
+            repo.compare_refs(&oid(local), &oid(remote_next)).unwrap(),
+            (1, 2)
+        );
+    }
 }
@@ -0,0 +1,229 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::common::{Issue, IssueEvent, IssueId, IssueStatus};
+use crate::storage::index::IndexEntry;
+
+/// Local-only ref holding the search index blob
+pub const SEARCH_INDEX_REF: &str = "refs/git-issue/meta/search-index";
+
+/// How a search query is matched against issue text
+#[derive(Debug, Clone)]
+pub enum SearchPattern {
+    /// Case-insensitive substring match
+    Text(String),
+    /// Regular expression match
+    Regex(Regex),
+}
+
+impl SearchPattern {
+    /// A case-insensitive substring pattern
+    pub fn text(query: &str) -> Self {
+        SearchPattern::Text(query.to_lowercase())
+    }
+
+    /// Whether the pattern matches `text`
+    pub fn is_match(&self, text: &str) -> bool {
+        match self {
+            SearchPattern::Text(query) => text.to_lowercase().contains(query),
+            SearchPattern::Regex(regex) => regex.is_match(text),
+        }
+    }
+}
+
+/// A full-text search over titles, descriptions and comment bodies
+#[derive(Debug, Clone)]
+pub struct SearchQuery {
+    pub pattern: SearchPattern,
+    /// Only search issues with one of these statuses, or all issues if `None`
+    pub statuses: Option<Vec<IssueStatus>>,
+    /// Only search issues that have every one of these labels
+    pub labels: Vec<String>,
+}
+
+impl SearchQuery {
+    /// Search all issues for `pattern`
+    pub fn new(pattern: SearchPattern) -> Self {
+        Self {
+            pattern,
+            statuses: None,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Whether an issue passes the status and label filters, judged from its index entry alone
+    pub fn keeps(&self, entry: &IndexEntry) -> bool {
+        self.statuses
+            .as_ref()
+            .is_none_or(|statuses| statuses.contains(&entry.status))
+            && self.labels.iter().all(|label| entry.labels.contains(label))
+    }
+
+    /// Whether the pattern matches the issue's title, description or any comment
+    pub fn matches(&self, issue: &Issue) -> bool {
+        self.pattern.is_match(&issue.title)
+            || self.pattern.is_match(&issue.description)
+            || issue
+                .comments
+                .iter()
+                .any(|comment| self.pattern.is_match(&comment.content))
+    }
+}
+
+/// Inverted index from lowercase words to the issues whose text contains them
+///
+/// Words are only ever added as events are appended, so an edited title or
+/// description leaves its old words behind until the issue is reindexed. That
+/// only costs a wasted reconstruction: candidates are always checked against
+/// the issue itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchIndex {
+    /// Head commit each issue was indexed at
+    heads: BTreeMap<IssueId, String>,
+    /// Issues containing each word
+    words: BTreeMap<String, BTreeSet<IssueId>>,
+}
+
+impl SearchIndex {
+    /// The head commit `issue_id` was indexed at, if it is indexed
+    pub fn head(&self, issue_id: IssueId) -> Option<&str> {
+        self.heads.get(&issue_id).map(String::as_str)
+    }
+
+    /// IDs of all indexed issues
+    pub fn issue_ids(&self) -> impl Iterator<Item = IssueId> + '_ {
+        self.heads.keys().copied()
+    }
+
+    /// Index the words an event adds, moving the issue's head to `head_oid`
+    pub fn apply(&mut self, issue_id: IssueId, event: &IssueEvent, head_oid: gix::ObjectId) {
+        for text in event_text(event) {
+            for word in words(text) {
+                self.words.entry(word).or_default().insert(issue_id);
+            }
+        }
+        self.heads.insert(issue_id, head_oid.to_string());
+    }
+
+    /// Reindex an issue from its full event history
+    pub fn reindex(&mut self, issue_id: IssueId, events: &[IssueEvent], head_oid: gix::ObjectId) {
+        self.remove(issue_id);
+        for event in events {
+            self.apply(issue_id, event, head_oid);
+        }
+    }
+
+    /// Drop an issue from the index
+    pub fn remove(&mut self, issue_id: IssueId) {
+        self.heads.remove(&issue_id);
+        self.words.retain(|_, issues| {
+            issues.remove(&issue_id);
+            !issues.is_empty()
+        });
+    }
+
+    /// Issues that may match `pattern`, or `None` if the index cannot narrow it down
+    ///
+    /// Every word of a plain-text query must appear within some indexed word,
+    /// since the query may start or end partway through one.
+    pub fn candidates(&self, pattern: &SearchPattern) -> Option<BTreeSet<IssueId>> {
+        let SearchPattern::Text(query) = pattern else {
+            return None;
+        };
+
+        let mut candidates: Option<BTreeSet<IssueId>> = None;
+        for query_word in words(query) {
+            let matching: BTreeSet<IssueId> = self
+                .words
+                .iter()
+                .filter(|(word, _)| word.contains(&query_word))
+                .flat_map(|(_, issues)| issues.iter().copied())
+                .collect();
+            candidates = Some(match candidates {
+                Some(candidates) => &candidates & &matching,
+                None => matching,
+            });
+        }
+        candidates
+    }
+}
+
+/// Searchable text introduced by an event
+fn event_text(event: &IssueEvent) -> Vec<&str> {
+    match event {
+        IssueEvent::Created {
+            title, description, ..
+        } => vec![title, description],
+        IssueEvent::TitleChanged { new_title, .. } => vec![new_title],
+        IssueEvent::DescriptionChanged {
+            new_description, ..
+        } => vec![new_description],
+        IssueEvent::CommentAdded { content, .. } => vec![content],
+        _ => Vec::new(),
+    }
+}
+
+/// Split text into lowercase alphanumeric words
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::IssueStore;
+    use crate::storage::test_helpers::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_store_search() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let mut store = IssueStore::init(temp_dir.path()).expect("Failed to init store");
+        let author = create_test_identity();
+        let crash = store
+            .create_issue(
+                "Crash on start".to_string(),
+                "Segfault in parser".to_string(),
+                author.clone(),
+            )
+            .unwrap();
+        let docs = store
+            .create_issue("Update docs".to_string(), String::new(), author.clone())
+            .unwrap();
+        store
+            .add_comment(
+                docs,
+                "The PARSER section is stale".to_string(),
+                author.clone(),
+            )
+            .unwrap();
+        store
+            .add_label(docs, "docs".to_string(), author.clone())
+            .unwrap();
+        store
+            .update_issue_status(crash, IssueStatus::Done, author)
+            .unwrap();
+
+        let ids = |store: &mut IssueStore, query: &SearchQuery| -> Vec<_> {
+            let issues = store.search(query).expect("Search should succeed");
+            issues.iter().map(|issue| issue.id).collect()
+        };
+
+        // Plain text is case-insensitive and covers descriptions and comments
+        let mut query = SearchQuery::new(SearchPattern::text("parser"));
+        assert_eq!(ids(&mut store, &query), vec![crash, docs]);
+
+        query.statuses = Some(vec![IssueStatus::Todo]);
+        assert_eq!(ids(&mut store, &query), vec![docs]);
+
+        query.statuses = None;
+        query.labels = vec!["docs".to_string()];
+        assert_eq!(ids(&mut store, &query), vec![docs]);
+
+        let query = SearchQuery::new(SearchPattern::Regex(Regex::new("^Crash").unwrap()));
+        assert_eq!(ids(&mut store, &query), vec![crash]);
+    }
+}
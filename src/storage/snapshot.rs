@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use super::repo::GitRepository;
+use crate::common::{Issue, IssueId};
+
+/// Namespace of the local-only snapshot refs, one per issue
+pub const SNAPSHOT_REF_PREFIX: &str = "refs/git-issue/meta/snapshots/";
+
+/// Events replayed past the last snapshot before a new one is taken, unless
+/// `storage.snapshotInterval` says otherwise
+pub const DEFAULT_SNAPSHOT_INTERVAL: usize = 32;
+
+/// Current `Snapshot::version`, bumped whenever `Issue` gains a field derived from events
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// An issue as reconstructed up to and including the commit `head`
+///
+/// Unlike the issue cache, a snapshot stays useful after new events: replay
+/// starts from it and only reads the commits written since. A snapshot whose
+/// head is no longer in the issue's history is ignored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// Layout of `issue`; snapshots of another version are ignored and retaken
+    #[serde(default)]
+    pub version: u32,
+    pub head: String,
+    pub issue: Issue,
+}
+
+impl Snapshot {
+    pub fn ref_name(issue_id: IssueId) -> String {
+        format!("{}{}", SNAPSHOT_REF_PREFIX, issue_id)
+    }
+
+    /// The latest snapshot of an issue, if there is a readable one
+    pub fn load(repo: &GitRepository, issue_id: IssueId) -> Option<(gix::ObjectId, Issue)> {
+        let oid = repo.read_ref(&Self::ref_name(issue_id)).ok()??;
+        let snapshot: Snapshot = serde_json::from_slice(&repo.read_blob(oid).ok()?).ok()?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return None;
+        }
+        Some((snapshot.head.parse().ok()?, snapshot.issue))
+    }
+
+    /// Record `issue` as reconstructed up to `head`
+    ///
+    /// Failures are logged and otherwise ignored, since the issue can always be
+    /// rebuilt from its events.
+    pub fn save(repo: &GitRepository, issue_id: IssueId, head: gix::ObjectId, issue: &Issue) {
+        let snapshot = Snapshot {
+            version: SNAPSHOT_VERSION,
+            head: head.to_string(),
+            issue: issue.clone(),
+        };
+        let result = serde_json::to_vec(&snapshot)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                repo.write_cache_ref(&Self::ref_name(issue_id), &content)
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::debug!("Failed to snapshot issue #{}: {}", issue_id, e);
+        }
+    }
+}
+
+/// Number of replayed events after which to take a snapshot, or `None` if disabled
+///
+/// `storage.snapshotInterval = 0` turns snapshots off.
+pub fn configured_interval(repo: &GitRepository) -> Option<usize> {
+    match repo.get_config_integer("storage.snapshotInterval") {
+        Some(interval) => usize::try_from(interval).ok().filter(|&n| n > 0),
+        None => Some(DEFAULT_SNAPSHOT_INTERVAL),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::IssueStatus;
+    use crate::storage::IssueStore;
+    use crate::storage::test_helpers::*;
+
+    #[test]
+    fn test_get_issue_replays_from_snapshot() {
+        let (temp_dir, repo) = setup_temp_repo();
+        let status = std::process::Command::new("git")
+            .args(["config", "storage.snapshotInterval", "3"])
+            .current_dir(temp_dir.path())
+            .status()
+            .expect("Failed to run git");
+        assert!(status.success());
+        let author = create_test_identity();
+
+        let mut store = IssueStore::open(temp_dir.path()).unwrap();
+        let issue_id = store
+            .create_issue("Snapshotted".to_string(), String::new(), author.clone())
+            .unwrap();
+        for status in [
+            IssueStatus::InProgress,
+            IssueStatus::Done,
+            IssueStatus::Todo,
+        ] {
+            store
+                .update_issue_status(issue_id, status, author.clone())
+                .unwrap();
+        }
+
+        // Mutations read the issue too, so a snapshot was taken along the way
+        store
+            .add_comment(issue_id, "After the snapshot".to_string(), author)
+            .unwrap();
+        let (snapshot_head, _) = Snapshot::load(&repo, issue_id).expect("Should have a snapshot");
+        let newer = store.events_since(issue_id, snapshot_head).unwrap().len();
+        assert!(newer < 3);
+
+        let store = IssueStore::open(temp_dir.path()).unwrap();
+        let issue = store.get_issue(issue_id).unwrap();
+        let events = store.get_issue_events(issue_id).unwrap();
+        assert_eq!(issue, Issue::from_events(issue_id, &events).unwrap());
+
+        // Replay starts from the snapshot, so a change to it shows through
+        let (_, mut snapshotted) = Snapshot::load(&repo, issue_id).unwrap();
+        snapshotted.title = "From the snapshot".to_string();
+        Snapshot::save(&repo, issue_id, snapshot_head, &snapshotted);
+        let issue = store.get_issue(issue_id).unwrap();
+        assert_eq!(issue.title, "From the snapshot");
+        assert_eq!(issue.comments.len(), 1);
+
+        // Snapshots are local state and are never synced
+        assert!(
+            store
+                .list_meta_refs()
+                .unwrap()
+                .iter()
+                .all(|ref_name| !ref_name.starts_with(SNAPSHOT_REF_PREFIX))
+        );
+    }
+
+    #[test]
+    fn test_snapshot_outside_history_is_ignored() {
+        let (temp_dir, repo) = setup_temp_repo();
+        let author = create_test_identity();
+
+        let mut store = IssueStore::open(temp_dir.path()).unwrap();
+        let issue_id = store
+            .create_issue("Real".to_string(), String::new(), author.clone())
+            .unwrap();
+        let other_id = store
+            .create_issue("Other".to_string(), String::new(), author)
+            .unwrap();
+
+        // A snapshot whose head belongs to another history, e.g. after a rewrite
+        let other_head = store.get_issue_head_commit(other_id).unwrap();
+        let other = store.get_issue(other_id).unwrap();
+        Snapshot::save(&repo, issue_id, other_head, &other);
+
+        assert_eq!(store.get_issue(issue_id).unwrap().title, "Real");
+    }
+
+    #[test]
+    fn test_snapshot_of_another_version_is_ignored() {
+        let (temp_dir, repo) = setup_temp_repo();
+        let author = create_test_identity();
+
+        let mut store = IssueStore::open(temp_dir.path()).unwrap();
+        let issue_id = store
+            .create_issue("Reopened".to_string(), String::new(), author.clone())
+            .unwrap();
+        for status in [IssueStatus::Done, IssueStatus::Todo] {
+            store
+                .update_issue_status(issue_id, status, author.clone())
+                .unwrap();
+        }
+
+        // A snapshot taken before issues counted their reopens
+        let head = store.get_issue_head_commit(issue_id).unwrap();
+        let mut issue = store.get_issue(issue_id).unwrap();
+        issue.reopen_count = 0;
+        let old = serde_json::json!({ "head": head.to_string(), "issue": issue });
+        repo.write_cache_ref(&Snapshot::ref_name(issue_id), old.to_string().as_bytes())
+            .unwrap();
+
+        assert!(Snapshot::load(&repo, issue_id).is_none());
+        assert_eq!(store.get_issue(issue_id).unwrap().reopen_count, 1);
+    }
+}